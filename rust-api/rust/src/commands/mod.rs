@@ -3,35 +3,228 @@
  *  Licensed under the MIT License. See License.txt in the project root for license information.
  *--------------------------------------------------------------------------------------------*/
 
+use std::cell::RefCell;
 use std::collections::HashMap;
-use once_cell::sync::Lazy;
+use std::rc::Rc;
 
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::common::Operation;
 use crate::host::api::commands;
+use crate::types::{ Error, TextEditorEdit };
+use crate::{ ContextValue, TextEdit, TextEditor };
+
+/// Takes the JSON encoded arguments and returns the JSON encoded result.
+type Handler = Rc<dyn Fn(&str) -> Result<String, Error>>;
 
-static mut HANDLERS: Lazy<HashMap<String, Box<dyn Fn()>>> = Lazy::new(|| HashMap::new());
+/// Takes the JSON encoded arguments and returns the edits to apply.
+type TextEditorHandler = Rc<dyn Fn(&TextEditor, &str) -> Result<Vec<TextEdit>, Error>>;
+
+thread_local! {
+	static HANDLERS: RefCell<HashMap<String, Handler>> = RefCell::new(HashMap::new());
+	static TEXT_EDITOR_HANDLERS: RefCell<HashMap<String, TextEditorHandler>> = RefCell::new(HashMap::new());
+	static CONTEXT: RefCell<HashMap<String, ContextValue>> = RefCell::new(HashMap::new());
+}
 
-pub fn register_command<F>(command: &str, callback: F) -> impl Fn() + 'static
+/// Registers a command that can be invoked from keybindings, menus or other
+/// extensions.
+///
+/// The arguments are deserialized from the JSON array the command is called
+/// with, so positional arguments map to a tuple like `(String, u32)`. For
+/// convenience, no arguments also deserialize into `()` and a single
+/// argument into its own type. Trailing arguments the handler does not take
+/// are ignored. The result is serialized to JSON and an error is shown to
+/// the user.
+pub fn register_command<Args, R, F>(command: &str, callback: F) -> impl Fn() + 'static
 where
-	F: Fn() + 'static,
+	Args: DeserializeOwned,
+	R: Serialize,
+	F: Fn(Args) -> Result<R, Error> + 'static,
 {
-	unsafe {
-		HANDLERS.insert(command.to_string(), Box::new(callback));
-	}
+	let handler: Handler = Rc::new(move |arguments| {
+		let result = callback(parse_arguments(arguments)?)?;
+		Ok(serde_json::to_string(&result)?)
+	});
+	HANDLERS.with_borrow_mut(|handlers| {
+		handlers.insert(command.to_string(), handler);
+	});
 	commands::register_command(command);
 	let unregister = command.to_string();
-	return move || {
-		unsafe {
-			HANDLERS.remove(&unregister);
+	move || {
+		let removed = HANDLERS.with_borrow_mut(|handlers| handlers.remove(&unregister).is_some());
+		if removed {
+			commands::unregister_command(&unregister);
 		}
+	}
+}
+
+/// Registers a command that runs on the active text editor. The edits the
+/// callback adds to the `TextEditorEdit` are applied in one atomic operation
+/// once it returns successfully. Arguments are handled as in
+/// `register_command`.
+pub fn register_text_editor_command<Args, F>(command: &str, callback: F) -> impl Fn() + 'static
+where
+	Args: DeserializeOwned,
+	F: Fn(&TextEditor, &mut TextEditorEdit, Args) -> Result<(), Error> + 'static,
+{
+	let handler: TextEditorHandler = Rc::new(move |editor, arguments| {
+		let mut edit = TextEditorEdit::new();
+		callback(editor, &mut edit, parse_arguments(arguments)?)?;
+		Ok(edit.into_edits())
+	});
+	TEXT_EDITOR_HANDLERS.with_borrow_mut(|handlers| {
+		handlers.insert(command.to_string(), handler);
+	});
+	commands::register_text_editor_command(command);
+	let unregister = command.to_string();
+	move || {
+		let removed = TEXT_EDITOR_HANDLERS.with_borrow_mut(|handlers| handlers.remove(&unregister).is_some());
+		if removed {
+			commands::unregister_command(&unregister);
+		}
+	}
+}
+
+fn parse_arguments<Args>(arguments: &str) -> Result<Args, Error>
+where
+	Args: DeserializeOwned,
+{
+	let value: Value = serde_json::from_str(arguments)?;
+	let Value::Array(values) = value else {
+		return Ok(serde_json::from_value(value)?);
 	};
+	// Like a JavaScript function, a handler may ignore trailing arguments,
+	// e.g. the resource VS Code passes to commands run from a menu. A handler
+	// that does not take a tuple gets the first argument, or null if it takes
+	// none.
+	let prefixes = (0..=values.len()).rev().map(|count| Value::Array(values[..count].to_vec()));
+	let first = values.first().cloned().into_iter();
+	let mut error = None;
+	for candidate in prefixes.chain(first).chain([Value::Null]) {
+		match serde_json::from_value(candidate) {
+			Ok(arguments) => return Ok(arguments),
+			Err(cause) => {
+				error.get_or_insert(cause);
+			}
+		}
+	}
+	Err(error.expect("the full argument list is always tried").into())
 }
 
-pub fn execute_command(command: &str) {
-	let handler;
-	unsafe {
-		handler = HANDLERS.get(command);
+/// Executes the command with the given id, e.g. a built-in command like
+/// `editor.action.formatDocument` or one contributed by another extension.
+/// The arguments must serialize to a JSON array, e.g. a tuple or a `Vec`.
+/// Commands without a result resolve to `Value::Null`.
+pub async fn execute<Args>(command: &str, arguments: &Args) -> Result<Value, Error>
+where
+	Args: Serialize + ?Sized,
+{
+	let arguments = serde_json::to_value(arguments)?;
+	if !arguments.is_array() {
+		return Err(Error::new("Command arguments must serialize to a JSON array"));
 	}
-	if handler.is_some() {
-		handler.unwrap()();
+	let arguments = arguments.to_string();
+	let result = Operation::start(|operation| commands::execute_command(operation, command, &arguments)).await;
+	parse_result(result)
+}
+
+/// Returns the ids of all available commands. Internal commands, those
+/// starting with an underscore, are left out if `filter_internal` is set.
+pub async fn get_commands(filter_internal: bool) -> Result<Vec<String>, Error> {
+	let result = Operation::start(|operation| commands::get_commands(operation, filter_internal)).await;
+	Ok(serde_json::from_value(parse_result(result)?)?)
+}
+
+/// Sets a context key that can be used in when clauses, e.g. to enable menus
+/// or keybindings. The last value set for a key is remembered and setting it
+/// again is skipped. Changes made to the key outside of this extension are
+/// therefore not seen.
+pub fn set_context<V>(key: &str, value: V)
+where
+	V: Into<ContextValue>,
+{
+	let value = value.into();
+	let changed = CONTEXT.with_borrow_mut(|context| {
+		if context.get(key).is_some_and(|current| is_same_context_value(current, &value)) {
+			return false;
+		}
+		context.insert(key.to_string(), value.clone());
+		true
+	});
+	if changed {
+		commands::set_context(key, &value);
 	}
-}
\ No newline at end of file
+}
+
+fn is_same_context_value(a: &ContextValue, b: &ContextValue) -> bool {
+	match (a, b) {
+		(ContextValue::Boolean(a), ContextValue::Boolean(b)) => a == b,
+		// Compare the bits, NaN is never equal to itself.
+		(ContextValue::Number(a), ContextValue::Number(b)) => a.to_bits() == b.to_bits(),
+		(ContextValue::Text(a), ContextValue::Text(b)) => a == b,
+		(ContextValue::TextList(a), ContextValue::TextList(b)) => a == b,
+		_ => false
+	}
+}
+
+fn parse_result(result: Result<String, String>) -> Result<Value, Error> {
+	match result {
+		Ok(value) => Ok(serde_json::from_str(&value)?),
+		Err(message) => Err(Error::new(&message))
+	}
+}
+
+pub(crate) fn execute_command(command: &str, arguments: &str) -> Result<String, Error> {
+	let handler = HANDLERS.with_borrow(|handlers| handlers.get(command).cloned());
+	match handler {
+		Some(handler) => handler(arguments),
+		None => Err(Error::new(&format!("Command '{}' not found", command)))
+	}
+}
+
+pub(crate) fn execute_text_editor_command(command: &str, editor: &TextEditor, arguments: &str) -> Result<Vec<TextEdit>, Error> {
+	let handler = TEXT_EDITOR_HANDLERS.with_borrow(|handlers| handlers.get(command).cloned());
+	match handler {
+		Some(handler) => handler(editor, arguments),
+		None => Err(Error::new(&format!("Command '{}' not found", command)))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn arguments_match_the_handler() {
+		let (text, count): (String, u32) = parse_arguments(r#"["a", 2]"#).unwrap();
+		assert_eq!((text.as_str(), count), ("a", 2));
+		let values: Vec<u32> = parse_arguments("[1, 2, 3]").unwrap();
+		assert_eq!(values, vec![1, 2, 3]);
+	}
+
+	#[test]
+	fn trailing_arguments_are_ignored() {
+		let uri = r#"{ "scheme": "file", "path": "/a" }"#;
+		let () = parse_arguments(&format!("[{uri}]")).unwrap();
+		let (text,): (String,) = parse_arguments(&format!(r#"["a", {uri}]"#)).unwrap();
+		assert_eq!(text, "a");
+		let text: String = parse_arguments(&format!(r#"["a", {uri}]"#)).unwrap();
+		assert_eq!(text, "a");
+	}
+
+	#[test]
+	fn missing_arguments_are_errors() {
+		assert!(parse_arguments::<(String, u32)>(r#"["a"]"#).is_err());
+		assert!(parse_arguments::<String>("[]").is_err());
+		assert!(parse_arguments::<u32>(r#"["a"]"#).is_err());
+	}
+
+	#[test]
+	fn same_context_values_are_detected() {
+		assert!(is_same_context_value(&ContextValue::Number(f64::NAN), &ContextValue::Number(f64::NAN)));
+		assert!(is_same_context_value(&ContextValue::Number(1.5), &ContextValue::Number(1.5)));
+		assert!(!is_same_context_value(&ContextValue::Number(1.0), &ContextValue::Boolean(true)));
+	}
+}
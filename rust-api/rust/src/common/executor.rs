@@ -0,0 +1,127 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use std::cell::{ Cell, RefCell };
+use std::collections::{ HashMap, VecDeque };
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{ Context, Wake, Waker };
+
+type Task = Pin<Box<dyn Future<Output = ()>>>;
+
+thread_local! {
+	static NEXT_TASK_ID: Cell<u32> = const { Cell::new(1) };
+	static TASKS: RefCell<HashMap<u32, Task>> = RefCell::new(HashMap::new());
+	static READY: RefCell<VecDeque<u32>> = const { RefCell::new(VecDeque::new()) };
+	static RUNNING: Cell<bool> = const { Cell::new(false) };
+}
+
+// The guest runs on a single thread. Waking queues the task and runs the
+// queued tasks unless they are already running, in which case the running
+// loop polls it.
+struct TaskWaker {
+	id: u32
+}
+
+impl Wake for TaskWaker {
+	fn wake(self: Arc<Self>) {
+		READY.with_borrow_mut(|ready| ready.push_back(self.id));
+		run_tasks();
+	}
+}
+
+/// Runs the future on the guest's executor. It is polled right away until
+/// it waits, e.g. for a host operation, and continues once it is woken.
+pub fn spawn_local<F>(future: F)
+where
+	F: Future<Output = ()> + 'static,
+{
+	let id = NEXT_TASK_ID.with(|next| {
+		let id = next.get();
+		next.set(id + 1);
+		id
+	});
+	TASKS.with_borrow_mut(|tasks| {
+		tasks.insert(id, Box::pin(future));
+	});
+	READY.with_borrow_mut(|ready| ready.push_back(id));
+	run_tasks();
+}
+
+/// Polls the tasks that are ready until none is left. Tasks that become
+/// ready while the tasks run, e.g. by spawning, are polled by the running
+/// loop.
+pub fn run_tasks() {
+	if RUNNING.replace(true) {
+		return;
+	}
+	while let Some(id) = READY.with_borrow_mut(|ready| ready.pop_front()) {
+		// Tasks are taken out while polled so that they can spawn new ones.
+		let Some(mut task) = TASKS.with_borrow_mut(|tasks| tasks.remove(&id)) else {
+			continue;
+		};
+		let waker = Waker::from(Arc::new(TaskWaker { id }));
+		if task.as_mut().poll(&mut Context::from_waker(&waker)).is_pending() {
+			TASKS.with_borrow_mut(|tasks| {
+				tasks.insert(id, task);
+			});
+		}
+	}
+	RUNNING.set(false);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::rc::Rc;
+	use std::task::Poll;
+
+	/// Pending until it is woken from outside of the executor.
+	struct Signal {
+		waker: Rc<RefCell<Option<Waker>>>
+	}
+
+	impl Future for Signal {
+		type Output = ();
+
+		fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<()> {
+			let mut waker = self.waker.borrow_mut();
+			if waker.is_none() {
+				*waker = Some(context.waker().clone());
+				Poll::Pending
+			} else {
+				Poll::Ready(())
+			}
+		}
+	}
+
+	#[test]
+	fn woken_tasks_run_right_away() {
+		let waker = Rc::new(RefCell::new(None));
+		let done = Rc::new(Cell::new(false));
+		let signal = Signal { waker: waker.clone() };
+		let task_done = done.clone();
+		spawn_local(async move {
+			signal.await;
+			task_done.set(true);
+		});
+		assert!(!done.get());
+		let woken = waker.borrow().clone().unwrap();
+		woken.wake();
+		assert!(done.get());
+	}
+
+	#[test]
+	fn tasks_spawned_by_tasks_run() {
+		let done = Rc::new(Cell::new(false));
+		let task_done = done.clone();
+		spawn_local(async move {
+			spawn_local(async move {
+				task_done.set(true);
+			});
+		});
+		assert!(done.get());
+	}
+}
@@ -2,54 +2,109 @@
  *  Copyright (c) Microsoft Corporation. All rights reserved.
  *  Licensed under the MIT License. See License.txt in the project root for license information.
  *--------------------------------------------------------------------------------------------*/
-use std::cell::RefCell;
+use std::cell::{ Cell, RefCell };
+use std::collections::HashMap;
 use std::rc::Rc;
 use std::fmt::Debug;
 
 use indexmap::IndexMap;
 
+mod executor;
+pub use executor::*;
+mod operations;
+pub use operations::*;
+
+type Listener<T> = Rc<dyn Fn(&T)>;
+type Listeners<T> = Rc<RefCell<IndexMap<u32, Listener<T>>>>;
+
 pub struct EventEmitter<T> where T: Debug + 'static {
-	next_id: u32,
+	next_id: Cell<u32>,
 	hook: fn(),
 	unhook: fn(),
-    listeners: Rc<RefCell<IndexMap<u32, Box<dyn Fn(&T)>>>>
+	listeners: Listeners<T>
 }
 
 impl<T> EventEmitter<T> where T: Debug + 'static {
-    pub fn new(hook: fn(), unhook: fn()) -> Self {
-        EventEmitter {
-			next_id: 1,
+	pub fn new(hook: fn(), unhook: fn()) -> Self {
+		EventEmitter {
+			next_id: Cell::new(1),
 			hook,
-			unhook: unhook,
-            listeners: Rc::new(RefCell::new(IndexMap::new()))
-        }
-    }
+			unhook,
+			listeners: Rc::new(RefCell::new(IndexMap::new()))
+		}
+	}
 
-    pub fn on<F>(&mut self, listener: F) -> impl Fn() + 'static
+	pub fn on<F>(&self, listener: F) -> impl Fn() + 'static
 	where
 		F: Fn(&T) + 'static,
 	{
-		if self.listeners.borrow().len() == 0 {
+		if self.listeners.borrow().is_empty() {
 			(self.hook)();
 		}
-        let id = self.next_id;
-		self.next_id += 1;
-        self.listeners.borrow_mut().insert(id, Box::new(listener));
+		let id = self.next_id.get();
+		self.next_id.set(id + 1);
+		self.listeners.borrow_mut().insert(id, Rc::new(listener));
 
-        let listeners = self.listeners.clone();
-		let unhook = self.unhook.clone();
+		let listeners = self.listeners.clone();
+		let unhook = self.unhook;
 
-        return move || {
-            listeners.borrow_mut().shift_remove(&id);
-			if listeners.borrow().len() == 0 {
+		move || {
+			let removed = listeners.borrow_mut().shift_remove(&id).is_some();
+			if removed && listeners.borrow().is_empty() {
 				(unhook)();
 			}
-        };
-    }
-
-    pub fn fire(&self, event: &T) {
-        for listener in self.listeners.borrow().values() {
-            listener(event);
-        }
-    }
-}
\ No newline at end of file
+		}
+	}
+
+	pub fn fire(&self, event: &T) {
+		// Listeners may be added or removed while the event is dispatched.
+		let listeners: Vec<Listener<T>> = self.listeners.borrow().values().cloned().collect();
+		for listener in listeners {
+			listener(event);
+		}
+	}
+}
+
+thread_local! {
+	static NEXT_PROVIDER_ID: Cell<u32> = const { Cell::new(1) };
+}
+
+/// Returns a new id for a registration with the host. Registrations without
+/// guest-side callbacks, like language configurations, share the provider
+/// ids so that the host can dispose all of them the same way.
+pub fn next_provider_id() -> u32 {
+	NEXT_PROVIDER_ID.with(|next| {
+		let id = next.get();
+		next.set(id + 1);
+		id
+	})
+}
+
+/// Keeps the providers of one kind registered with the host. Provider ids
+/// are unique across all kinds so that the host can unregister them without
+/// knowing their kind.
+pub struct Providers<P> where P: ?Sized {
+	providers: RefCell<HashMap<u32, Rc<P>>>
+}
+
+impl<P> Providers<P> where P: ?Sized {
+	pub fn new() -> Self {
+		Providers {
+			providers: RefCell::new(HashMap::new())
+		}
+	}
+
+	pub fn add(&self, provider: Rc<P>) -> u32 {
+		let id = next_provider_id();
+		self.providers.borrow_mut().insert(id, provider);
+		id
+	}
+
+	pub fn remove(&self, id: u32) -> bool {
+		self.providers.borrow_mut().remove(&id).is_some()
+	}
+
+	pub fn get(&self, id: u32) -> Option<Rc<P>> {
+		self.providers.borrow().get(&id).cloned()
+	}
+}
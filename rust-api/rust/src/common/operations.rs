@@ -0,0 +1,88 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use std::cell::{ Cell, RefCell };
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{ Context, Poll, Waker };
+
+type Outcome = Result<String, String>;
+
+#[derive(Default)]
+struct State {
+	outcome: Option<Outcome>,
+	waker: Option<Waker>
+}
+
+thread_local! {
+	static NEXT_OPERATION_ID: Cell<u32> = const { Cell::new(1) };
+	static OPERATIONS: RefCell<HashMap<u32, Rc<RefCell<State>>>> = RefCell::new(HashMap::new());
+}
+
+/// An asynchronous host operation, e.g. a promise returned by a VS Code API.
+/// The host reports the JSON encoded outcome with `complete-operation` using
+/// the id the operation was started with.
+pub struct Operation {
+	id: u32,
+	state: Rc<RefCell<State>>
+}
+
+impl Operation {
+	pub fn start<F>(start: F) -> Self
+	where
+		F: FnOnce(u32),
+	{
+		let id = NEXT_OPERATION_ID.with(|next| {
+			let id = next.get();
+			next.set(id + 1);
+			id
+		});
+		let state = Rc::new(RefCell::new(State::default()));
+		OPERATIONS.with_borrow_mut(|operations| {
+			operations.insert(id, state.clone());
+		});
+		start(id);
+		Operation { id, state }
+	}
+}
+
+impl Future for Operation {
+	type Output = Outcome;
+
+	fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		let mut state = self.state.borrow_mut();
+		match state.outcome.take() {
+			Some(outcome) => Poll::Ready(outcome),
+			None => {
+				state.waker = Some(cx.waker().clone());
+				Poll::Pending
+			}
+		}
+	}
+}
+
+impl Drop for Operation {
+	fn drop(&mut self) {
+		// An outcome reported for a dropped operation is ignored.
+		OPERATIONS.with_borrow_mut(|operations| {
+			operations.remove(&self.id);
+		});
+	}
+}
+
+pub fn complete_operation(id: u32, outcome: Outcome) {
+	let state = OPERATIONS.with_borrow_mut(|operations| operations.remove(&id));
+	if let Some(state) = state {
+		let waker = {
+			let mut state = state.borrow_mut();
+			state.outcome = Some(outcome);
+			state.waker.take()
+		};
+		if let Some(waker) = waker {
+			waker.wake();
+		}
+	}
+}
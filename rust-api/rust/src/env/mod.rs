@@ -0,0 +1,81 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+
+use crate::common::Operation;
+use crate::host::api::{ env, types };
+use crate::types::Error;
+use crate::Uri;
+
+pub type UiKind = types::UiKind;
+
+#[allow(non_upper_case_globals)]
+pub const app_name: fn() -> String = env::app_name;
+
+#[allow(non_upper_case_globals)]
+pub const app_root: fn() -> String = env::app_root;
+
+#[allow(non_upper_case_globals)]
+pub const app_host: fn() -> String = env::app_host;
+
+#[allow(non_upper_case_globals)]
+pub const ui_kind: fn() -> UiKind = env::get_ui_kind;
+
+#[allow(non_upper_case_globals)]
+pub const language: fn() -> String = env::language;
+
+#[allow(non_upper_case_globals)]
+pub const machine_id: fn() -> String = env::machine_id;
+
+#[allow(non_upper_case_globals)]
+pub const session_id: fn() -> String = env::session_id;
+
+#[allow(non_upper_case_globals)]
+pub const remote_name: fn() -> Option<String> = env::remote_name;
+
+#[allow(non_upper_case_globals)]
+pub const shell: fn() -> String = env::shell;
+
+#[allow(non_upper_case_globals)]
+pub const uri_scheme: fn() -> String = env::uri_scheme;
+
+#[allow(non_upper_case_globals)]
+pub const is_telemetry_enabled: fn() -> bool = env::is_telemetry_enabled;
+
+/// The system clipboard.
+pub struct Clipboard;
+
+impl Clipboard {
+	pub async fn read_text(&self) -> Result<String, Error> {
+		let value = complete(Operation::start(env::clipboard_read_text).await)?;
+		Ok(serde_json::from_str(&value)?)
+	}
+
+	pub async fn write_text(&self, value: &str) -> Result<(), Error> {
+		complete(Operation::start(|operation| env::clipboard_write_text(operation, value)).await)?;
+		Ok(())
+	}
+}
+
+pub fn clipboard() -> Clipboard {
+	Clipboard
+}
+
+/// Opens the uri externally, e.g. a web page in the default browser. Returns
+/// whether it was opened.
+pub async fn open_external(uri: &Uri) -> Result<bool, Error> {
+	let value = complete(Operation::start(|operation| env::open_external(operation, uri)).await)?;
+	Ok(serde_json::from_str(&value)?)
+}
+
+/// Resolves the uri to one that can be reached from the machine the user
+/// works on, e.g. by forwarding a port when running remotely.
+pub async fn as_external_uri(uri: &Uri) -> Result<Uri, Error> {
+	let value = complete(Operation::start(|operation| env::as_external_uri(operation, uri)).await)?;
+	Ok(serde_json::from_str(&value)?)
+}
+
+fn complete(result: Result<String, String>) -> Result<String, Error> {
+	result.map_err(|message| Error::new(&message))
+}
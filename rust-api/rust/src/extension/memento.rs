@@ -0,0 +1,64 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::common::Operation;
+use crate::host::api::types;
+use crate::types::Error;
+
+/// A key value store that survives restarts. Values are stored as JSON.
+pub struct Memento {
+	memento: types::Memento
+}
+
+impl Memento {
+	pub(crate) fn new(memento: types::Memento) -> Self {
+		Memento { memento }
+	}
+
+	/// Returns the value stored for the key, `None` if there is none.
+	pub fn get<T>(&self, key: &str) -> Result<Option<T>, Error>
+	where
+		T: DeserializeOwned,
+	{
+		match self.memento.get(key) {
+			Some(value) => Ok(Some(serde_json::from_str(&value)?)),
+			None => Ok(None)
+		}
+	}
+
+	pub fn keys(&self) -> Vec<String> {
+		self.memento.keys()
+	}
+
+	/// Stores the value for the key.
+	pub async fn update<T>(&self, key: &str, value: &T) -> Result<(), Error>
+	where
+		T: Serialize + ?Sized,
+	{
+		let value = serde_json::to_string(value)?;
+		self.run_update(key, Some(&value)).await
+	}
+
+	/// Removes the value stored for the key.
+	pub async fn remove(&self, key: &str) -> Result<(), Error> {
+		self.run_update(key, None).await
+	}
+
+	/// Sets the keys whose values are synchronized across machines when
+	/// Settings Sync is on. Only has an effect on the global state.
+	pub fn set_keys_for_sync(&self, keys: &[&str]) {
+		let keys: Vec<String> = keys.iter().map(|key| key.to_string()).collect();
+		self.memento.set_keys_for_sync(&keys);
+	}
+
+	async fn run_update(&self, key: &str, value: Option<&str>) -> Result<(), Error> {
+		match Operation::start(|operation| self.memento.update(operation, key, value)).await {
+			Ok(_) => Ok(()),
+			Err(message) => Err(Error::new(&message))
+		}
+	}
+}
@@ -0,0 +1,158 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::host::api::types;
+use crate::{ Disposables, Uri };
+
+mod memento;
+pub use memento::*;
+mod secrets;
+pub use secrets::*;
+
+pub type ExtensionMode = types::ExtensionMode;
+
+/// Implemented by the extension and exported with `extension!`.
+pub trait Extension {
+	/// Called when VS Code activates the extension.
+	fn activate(context: &ExtensionContext);
+
+	/// Called when VS Code deactivates the extension, before the context's
+	/// subscriptions are disposed. Does nothing by default.
+	fn deactivate() {
+	}
+}
+
+/// Exports the extension's type to VS Code, e.g.
+/// `vscode::extension!(MyExtension);` where `MyExtension` implements
+/// `vscode::Extension`. A component built without it lacks the activate
+/// export.
+#[macro_export]
+macro_rules! extension {
+	($extension:ty) => {
+		const _: () = {
+			type Implementation = $crate::Implementation<$extension>;
+			$crate::bindings::export!(Implementation with_types_in $crate::bindings);
+		};
+	};
+}
+
+struct Inner {
+	global_state: Memento,
+	workspace_state: Memento,
+	secrets: SecretStorage,
+	extension_uri: Uri,
+	extension_path: String,
+	extension_mode: ExtensionMode,
+	storage_uri: Option<Uri>,
+	global_storage_uri: Uri,
+	log_uri: Uri,
+	subscriptions: RefCell<Disposables>
+}
+
+/// The context an extension is activated with. It is cheap to clone, all
+/// clones share the same subscriptions.
+#[derive(Clone)]
+pub struct ExtensionContext {
+	inner: Rc<Inner>
+}
+
+impl ExtensionContext {
+	/// Adds a disposable that is disposed when the extension is deactivated.
+	pub fn push_subscription<F>(&self, disposable: F)
+	where
+		F: Fn() + 'static,
+	{
+		self.inner.subscriptions.borrow_mut().push(disposable);
+	}
+
+	/// State that is kept independent of the current workspace.
+	pub fn global_state(&self) -> &Memento {
+		&self.inner.global_state
+	}
+
+	/// State that is kept for the currently opened workspace.
+	pub fn workspace_state(&self) -> &Memento {
+		&self.inner.workspace_state
+	}
+
+	/// Storage for secrets like tokens or passwords.
+	pub fn secrets(&self) -> &SecretStorage {
+		&self.inner.secrets
+	}
+
+	pub fn extension_uri(&self) -> &Uri {
+		&self.inner.extension_uri
+	}
+
+	pub fn extension_path(&self) -> &str {
+		&self.inner.extension_path
+	}
+
+	pub fn extension_mode(&self) -> ExtensionMode {
+		self.inner.extension_mode
+	}
+
+	/// The workspace specific storage location. `None` if no workspace or
+	/// folder is open.
+	pub fn storage_uri(&self) -> Option<&Uri> {
+		self.inner.storage_uri.as_ref()
+	}
+
+	pub fn global_storage_uri(&self) -> &Uri {
+		&self.inner.global_storage_uri
+	}
+
+	pub fn log_uri(&self) -> &Uri {
+		&self.inner.log_uri
+	}
+}
+
+thread_local! {
+	static CONTEXT: RefCell<Option<ExtensionContext>> = const { RefCell::new(None) };
+}
+
+pub(crate) fn with_context<F>(callback: F)
+where
+	F: FnOnce(&ExtensionContext),
+{
+	let context = CONTEXT.with_borrow(|context| context.clone());
+	if let Some(context) = context {
+		callback(&context);
+	}
+}
+
+pub(crate) fn activate(context: types::ExtensionContext, activate: fn(&ExtensionContext)) {
+	let context = ExtensionContext {
+		inner: Rc::new(Inner {
+			global_state: Memento::new(context.global_state),
+			workspace_state: Memento::new(context.workspace_state),
+			secrets: SecretStorage::new(context.secrets),
+			extension_uri: context.extension_uri,
+			extension_path: context.extension_path,
+			extension_mode: context.extension_mode,
+			storage_uri: context.storage_uri,
+			global_storage_uri: context.global_storage_uri,
+			log_uri: context.log_uri,
+			subscriptions: RefCell::new(Disposables::new())
+		})
+	};
+	CONTEXT.set(Some(context.clone()));
+	activate(&context);
+}
+
+pub(crate) fn deactivate(deactivate: fn()) {
+	deactivate();
+	// The context is cleared after disposing, listeners like the secrets'
+	// change event reach the host through it when they are removed.
+	let context = CONTEXT.with_borrow(|context| context.clone());
+	if let Some(context) = context {
+		// Disposing may touch the subscriptions again, so don't keep them borrowed.
+		let mut subscriptions = context.inner.subscriptions.take();
+		subscriptions.dispose();
+	}
+	CONTEXT.set(None);
+}
@@ -0,0 +1,63 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use crate::common::{ EventEmitter, Operation };
+use crate::host::api::types::{ self, SecretStorageChangeEvent };
+use crate::types::Error;
+
+thread_local! {
+	static ON_DID_CHANGE: EventEmitter<SecretStorageChangeEvent> = EventEmitter::new(register_on_did_change, unregister_on_did_change);
+}
+
+// There is only one secret storage, the one of the active extension context.
+fn register_on_did_change() {
+	super::with_context(|context| context.secrets().storage.register_on_did_change());
+}
+
+fn unregister_on_did_change() {
+	super::with_context(|context| context.secrets().storage.unregister_on_did_change());
+}
+
+/// Stores secrets, like tokens, encrypted in the keychain of the operating
+/// system. The values are not synchronized across machines.
+pub struct SecretStorage {
+	storage: types::SecretStorage
+}
+
+impl SecretStorage {
+	pub(crate) fn new(storage: types::SecretStorage) -> Self {
+		SecretStorage { storage }
+	}
+
+	/// Returns the secret stored for the key, `None` if there is none.
+	pub async fn get(&self, key: &str) -> Result<Option<String>, Error> {
+		let value = Operation::start(|operation| self.storage.get(operation, key)).await;
+		match value {
+			Ok(value) => Ok(serde_json::from_str(&value)?),
+			Err(message) => Err(Error::new(&message))
+		}
+	}
+
+	pub async fn store(&self, key: &str, value: &str) -> Result<(), Error> {
+		let result = Operation::start(|operation| self.storage.store(operation, key, value)).await;
+		result.map(|_| ()).map_err(|message| Error::new(&message))
+	}
+
+	pub async fn delete(&self, key: &str) -> Result<(), Error> {
+		let result = Operation::start(|operation| self.storage.delete(operation, key)).await;
+		result.map(|_| ()).map_err(|message| Error::new(&message))
+	}
+
+	/// Fires when a secret is stored or deleted, also by another window.
+	pub fn on_did_change<F>(&self, listener: F) -> impl Fn() + 'static
+	where
+		F: Fn(&SecretStorageChangeEvent) + 'static,
+	{
+		ON_DID_CHANGE.with(|emitter| emitter.on(listener))
+	}
+}
+
+pub(crate) fn fire_did_change_secret(event: &SecretStorageChangeEvent) {
+	ON_DID_CHANGE.with(|emitter| emitter.fire(event))
+}
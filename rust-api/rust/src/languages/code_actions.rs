@@ -0,0 +1,45 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use std::rc::Rc;
+
+use crate::host::api::types::{
+	CancellationToken, CodeAction, CodeActionContext, CodeActionProviderMetadata, DocumentSelector, Range, TextDocument
+};
+use crate::host::api::languages;
+use crate::common::Providers;
+use super::register_provider;
+
+pub trait CodeActionProvider {
+	fn provide_code_actions(&self, document: &TextDocument, range: &Range, context: &CodeActionContext, token: &CancellationToken) -> Option<Vec<CodeAction>>;
+
+	/// Fills in the expensive parts of a code action, like its edit, when it
+	/// gets selected. The default returns the action unchanged.
+	fn resolve_code_action(&self, action: CodeAction, _token: &CancellationToken) -> CodeAction {
+		action
+	}
+}
+
+thread_local! {
+	static CODE_ACTION_PROVIDERS: Providers<dyn CodeActionProvider> = Providers::new();
+}
+
+pub fn register_code_actions_provider<P>(selector: &DocumentSelector, provider: P, metadata: Option<&CodeActionProviderMetadata>) -> impl Fn() + 'static
+where
+	P: CodeActionProvider + 'static,
+{
+	register_provider(&CODE_ACTION_PROVIDERS, Rc::new(provider), None, &|id| languages::register_code_actions_provider(id, selector, metadata))
+}
+
+pub(crate) fn provide_code_actions(provider: u32, document: &TextDocument, range: &Range, context: &CodeActionContext, token: &CancellationToken) -> Option<Vec<CodeAction>> {
+	let provider = CODE_ACTION_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.provide_code_actions(document, range, context, token)
+}
+
+pub(crate) fn resolve_code_action(provider: u32, action: CodeAction, token: &CancellationToken) -> CodeAction {
+	match CODE_ACTION_PROVIDERS.with(|providers| providers.get(provider)) {
+		Some(provider) => provider.resolve_code_action(action, token),
+		None => action
+	}
+}
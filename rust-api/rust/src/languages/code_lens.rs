@@ -0,0 +1,46 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use std::rc::Rc;
+
+use crate::host::api::types::{ CancellationToken, CodeLens, DocumentSelector, TextDocument };
+use crate::host::api::languages;
+use crate::common::Providers;
+
+use super::{ register_provider, ChangeEmitter };
+
+pub trait CodeLensProvider {
+	fn provide_code_lenses(&self, document: &TextDocument, token: &CancellationToken) -> Option<Vec<CodeLens>>;
+
+	/// Fills in the command of a code lens once it becomes visible. The
+	/// default returns the lens unchanged.
+	fn resolve_code_lens(&self, lens: CodeLens, _token: &CancellationToken) -> CodeLens {
+		lens
+	}
+}
+
+thread_local! {
+	static CODE_LENS_PROVIDERS: Providers<dyn CodeLensProvider> = Providers::new();
+}
+
+/// Registers a code lens provider. Firing `on_did_change_code_lenses` makes
+/// the editor request the code lenses again.
+pub fn register_code_lens_provider<P>(selector: &DocumentSelector, provider: P, on_did_change_code_lenses: Option<&ChangeEmitter>) -> impl Fn() + 'static
+where
+	P: CodeLensProvider + 'static,
+{
+	register_provider(&CODE_LENS_PROVIDERS, Rc::new(provider), on_did_change_code_lenses, &|id| languages::register_code_lens_provider(id, selector))
+}
+
+pub(crate) fn provide_code_lenses(provider: u32, document: &TextDocument, token: &CancellationToken) -> Option<Vec<CodeLens>> {
+	let provider = CODE_LENS_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.provide_code_lenses(document, token)
+}
+
+pub(crate) fn resolve_code_lens(provider: u32, lens: CodeLens, token: &CancellationToken) -> CodeLens {
+	match CODE_LENS_PROVIDERS.with(|providers| providers.get(provider)) {
+		Some(provider) => provider.resolve_code_lens(lens, token),
+		None => lens
+	}
+}
@@ -0,0 +1,41 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use std::rc::Rc;
+
+use crate::host::api::types::{
+	CancellationToken, Color, ColorInformation, ColorPresentation, DocumentSelector, Range, TextDocument
+};
+use crate::host::api::languages;
+use crate::common::Providers;
+use super::register_provider;
+
+pub trait DocumentColorProvider {
+	fn provide_document_colors(&self, document: &TextDocument, token: &CancellationToken) -> Option<Vec<ColorInformation>>;
+
+	/// Returns the ways `color` can be written at `range`, e.g. as hex or as
+	/// an `rgb()` function. The editor shows them in the color picker.
+	fn provide_color_presentations(&self, color: &Color, document: &TextDocument, range: &Range, token: &CancellationToken) -> Option<Vec<ColorPresentation>>;
+}
+
+thread_local! {
+	static COLOR_PROVIDERS: Providers<dyn DocumentColorProvider> = Providers::new();
+}
+
+pub fn register_color_provider<P>(selector: &DocumentSelector, provider: P) -> impl Fn() + 'static
+where
+	P: DocumentColorProvider + 'static,
+{
+	register_provider(&COLOR_PROVIDERS, Rc::new(provider), None, &|id| languages::register_color_provider(id, selector))
+}
+
+pub(crate) fn provide_document_colors(provider: u32, document: &TextDocument, token: &CancellationToken) -> Option<Vec<ColorInformation>> {
+	let provider = COLOR_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.provide_document_colors(document, token)
+}
+
+pub(crate) fn provide_color_presentations(provider: u32, color: &Color, document: &TextDocument, range: &Range, token: &CancellationToken) -> Option<Vec<ColorPresentation>> {
+	let provider = COLOR_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.provide_color_presentations(color, document, range, token)
+}
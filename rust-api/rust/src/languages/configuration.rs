@@ -0,0 +1,40 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use std::cell::Cell;
+
+use crate::host::api::types::{ LanguageConfiguration, TextDocument, Uri };
+use crate::host::api::{ languages, workspace };
+use crate::common::{ next_provider_id, Operation };
+use crate::types::Error;
+
+/// Returns the identifiers of all known languages.
+#[allow(non_upper_case_globals)]
+pub const get_languages: fn() -> Vec<String> = languages::get_languages;
+
+/// Changes the language of a document. The editor closes and reopens the
+/// document with the new language and the reopened document is returned.
+/// Fails if the language is unknown.
+pub async fn set_text_document_language(document: &TextDocument, language_id: &str) -> Result<TextDocument, Error> {
+	let result = Operation::start(|operation| languages::set_text_document_language(operation, document, language_id)).await;
+	let value = result.map_err(|message| Error::new(&message))?;
+	// The host passes back the uri of the reopened document.
+	let uri: Uri = serde_json::from_str(&value)?;
+	workspace::text_documents().into_iter()
+		.find(|document| document.uri() == uri)
+		.ok_or_else(|| Error::new(&format!("The document {} is no longer open", uri)))
+}
+
+/// Sets the editing behavior, like comments, brackets and indentation, of a
+/// language. Calling the returned function removes the configuration again.
+pub fn set_language_configuration(language_id: &str, configuration: &LanguageConfiguration) -> impl Fn() + 'static {
+	let id = next_provider_id();
+	languages::set_language_configuration(id, language_id, configuration);
+	let registered = Cell::new(true);
+	move || {
+		if registered.replace(false) {
+			languages::unregister_provider(id);
+		}
+	}
+}
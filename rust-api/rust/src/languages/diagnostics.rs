@@ -0,0 +1,29 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+
+use crate::host::api::types::{ Diagnostic, DiagnosticChangeEvent, DiagnosticCollection, Uri };
+use crate::host::api::languages;
+use crate::common::EventEmitter;
+
+#[allow(non_upper_case_globals)]
+pub const create_diagnostic_collection: fn(name: Option<&str>) -> DiagnosticCollection = languages::create_diagnostic_collection;
+
+#[allow(non_upper_case_globals)]
+pub const get_diagnostics: fn(uri: &Uri) -> Vec<Diagnostic> = languages::get_diagnostics;
+
+thread_local! {
+	static ON_DID_CHANGE_DIAGNOSTICS: EventEmitter<DiagnosticChangeEvent> = EventEmitter::new(languages::register_on_did_change_diagnostics, languages::unregister_on_did_change_diagnostics);
+}
+
+pub fn on_did_change_diagnostics<F>(listener: F) -> impl Fn() + 'static
+where
+	F: Fn(&DiagnosticChangeEvent) + 'static,
+{
+	ON_DID_CHANGE_DIAGNOSTICS.with(|emitter| emitter.on(listener))
+}
+
+pub(crate) fn fire_did_change_diagnostics(event: &DiagnosticChangeEvent) {
+	ON_DID_CHANGE_DIAGNOSTICS.with(|emitter| emitter.fire(event))
+}
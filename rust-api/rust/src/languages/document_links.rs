@@ -0,0 +1,43 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use std::rc::Rc;
+
+use crate::host::api::types::{ CancellationToken, DocumentLink, DocumentSelector, TextDocument };
+use crate::host::api::languages;
+use crate::common::Providers;
+use super::register_provider;
+
+pub trait DocumentLinkProvider {
+	fn provide_document_links(&self, document: &TextDocument, token: &CancellationToken) -> Option<Vec<DocumentLink>>;
+
+	/// Fills in the target of a link when it is clicked. The default returns
+	/// the link unchanged.
+	fn resolve_document_link(&self, link: DocumentLink, _token: &CancellationToken) -> DocumentLink {
+		link
+	}
+}
+
+thread_local! {
+	static DOCUMENT_LINK_PROVIDERS: Providers<dyn DocumentLinkProvider> = Providers::new();
+}
+
+pub fn register_document_link_provider<P>(selector: &DocumentSelector, provider: P) -> impl Fn() + 'static
+where
+	P: DocumentLinkProvider + 'static,
+{
+	register_provider(&DOCUMENT_LINK_PROVIDERS, Rc::new(provider), None, &|id| languages::register_document_link_provider(id, selector))
+}
+
+pub(crate) fn provide_document_links(provider: u32, document: &TextDocument, token: &CancellationToken) -> Option<Vec<DocumentLink>> {
+	let provider = DOCUMENT_LINK_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.provide_document_links(document, token)
+}
+
+pub(crate) fn resolve_document_link(provider: u32, link: DocumentLink, token: &CancellationToken) -> DocumentLink {
+	match DOCUMENT_LINK_PROVIDERS.with(|providers| providers.get(provider)) {
+		Some(provider) => provider.resolve_document_link(link, token),
+		None => link
+	}
+}
@@ -0,0 +1,33 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use std::rc::Rc;
+
+use crate::host::api::types::{ CancellationToken, DocumentSelector, FoldingRange, TextDocument };
+use crate::host::api::languages;
+use crate::common::Providers;
+
+use super::{ register_provider, ChangeEmitter };
+
+pub trait FoldingRangeProvider {
+	fn provide_folding_ranges(&self, document: &TextDocument, token: &CancellationToken) -> Option<Vec<FoldingRange>>;
+}
+
+thread_local! {
+	static FOLDING_RANGE_PROVIDERS: Providers<dyn FoldingRangeProvider> = Providers::new();
+}
+
+/// Registers a folding range provider. Firing `on_did_change_folding_ranges`
+/// makes the editor request the folding ranges again.
+pub fn register_folding_range_provider<P>(selector: &DocumentSelector, provider: P, on_did_change_folding_ranges: Option<&ChangeEmitter>) -> impl Fn() + 'static
+where
+	P: FoldingRangeProvider + 'static,
+{
+	register_provider(&FOLDING_RANGE_PROVIDERS, Rc::new(provider), on_did_change_folding_ranges, &|id| languages::register_folding_range_provider(id, selector))
+}
+
+pub(crate) fn provide_folding_ranges(provider: u32, document: &TextDocument, token: &CancellationToken) -> Option<Vec<FoldingRange>> {
+	let provider = FOLDING_RANGE_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.provide_folding_ranges(document, token)
+}
@@ -0,0 +1,76 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use std::rc::Rc;
+
+use crate::host::api::types::{
+	CancellationToken, DocumentSelector, FormattingOptions, Position, Range, TextDocument, TextEdit
+};
+use crate::host::api::languages;
+use crate::common::Providers;
+use super::register_provider;
+
+pub trait DocumentFormattingEditProvider {
+	fn provide_document_formatting_edits(&self, document: &TextDocument, options: &FormattingOptions, token: &CancellationToken) -> Vec<TextEdit>;
+}
+
+pub trait DocumentRangeFormattingEditProvider {
+	fn provide_document_range_formatting_edits(&self, document: &TextDocument, range: &Range, options: &FormattingOptions, token: &CancellationToken) -> Vec<TextEdit>;
+}
+
+pub trait OnTypeFormattingEditProvider {
+	fn provide_on_type_formatting_edits(&self, document: &TextDocument, position: &Position, ch: &str, options: &FormattingOptions, token: &CancellationToken) -> Vec<TextEdit>;
+}
+
+thread_local! {
+	static DOCUMENT_FORMATTING_EDIT_PROVIDERS: Providers<dyn DocumentFormattingEditProvider> = Providers::new();
+	static DOCUMENT_RANGE_FORMATTING_EDIT_PROVIDERS: Providers<dyn DocumentRangeFormattingEditProvider> = Providers::new();
+	static ON_TYPE_FORMATTING_EDIT_PROVIDERS: Providers<dyn OnTypeFormattingEditProvider> = Providers::new();
+}
+
+pub fn register_document_formatting_edit_provider<P>(selector: &DocumentSelector, provider: P) -> impl Fn() + 'static
+where
+	P: DocumentFormattingEditProvider + 'static,
+{
+	register_provider(&DOCUMENT_FORMATTING_EDIT_PROVIDERS, Rc::new(provider), None, &|id| languages::register_document_formatting_edit_provider(id, selector))
+}
+
+pub fn register_document_range_formatting_edit_provider<P>(selector: &DocumentSelector, provider: P) -> impl Fn() + 'static
+where
+	P: DocumentRangeFormattingEditProvider + 'static,
+{
+	register_provider(&DOCUMENT_RANGE_FORMATTING_EDIT_PROVIDERS, Rc::new(provider), None, &|id| languages::register_document_range_formatting_edit_provider(id, selector))
+}
+
+/// Registers a provider that formats while typing. The provider is asked for
+/// edits whenever the user types `first_trigger_character` or one of
+/// `more_trigger_character`.
+pub fn register_on_type_formatting_edit_provider<P>(selector: &DocumentSelector, provider: P, first_trigger_character: &str, more_trigger_character: &[&str]) -> impl Fn() + 'static
+where
+	P: OnTypeFormattingEditProvider + 'static,
+{
+	let more_trigger_character: Vec<String> = more_trigger_character.iter().map(|character| character.to_string()).collect();
+	register_provider(&ON_TYPE_FORMATTING_EDIT_PROVIDERS, Rc::new(provider), None, &|id| languages::register_on_type_formatting_edit_provider(id, selector, first_trigger_character, &more_trigger_character))
+}
+
+pub(crate) fn provide_document_formatting_edits(provider: u32, document: &TextDocument, options: &FormattingOptions, token: &CancellationToken) -> Vec<TextEdit> {
+	match DOCUMENT_FORMATTING_EDIT_PROVIDERS.with(|providers| providers.get(provider)) {
+		Some(provider) => provider.provide_document_formatting_edits(document, options, token),
+		None => Vec::new()
+	}
+}
+
+pub(crate) fn provide_document_range_formatting_edits(provider: u32, document: &TextDocument, range: &Range, options: &FormattingOptions, token: &CancellationToken) -> Vec<TextEdit> {
+	match DOCUMENT_RANGE_FORMATTING_EDIT_PROVIDERS.with(|providers| providers.get(provider)) {
+		Some(provider) => provider.provide_document_range_formatting_edits(document, range, options, token),
+		None => Vec::new()
+	}
+}
+
+pub(crate) fn provide_on_type_formatting_edits(provider: u32, document: &TextDocument, position: &Position, ch: &str, options: &FormattingOptions, token: &CancellationToken) -> Vec<TextEdit> {
+	match ON_TYPE_FORMATTING_EDIT_PROVIDERS.with(|providers| providers.get(provider)) {
+		Some(provider) => provider.provide_on_type_formatting_edits(document, position, ch, options, token),
+		None => Vec::new()
+	}
+}
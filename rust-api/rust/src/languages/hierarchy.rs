@@ -0,0 +1,210 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use std::cell::{ Cell, RefCell };
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::host::api::types::{
+	self as host_types, CancellationToken, DocumentSelector, HierarchyItem as HostHierarchyItem, Position, TextDocument
+};
+use crate::host::api::languages;
+use crate::common::Providers;
+use super::register_provider;
+use crate::types::{ CallHierarchyIncomingCall, CallHierarchyItem, CallHierarchyOutgoingCall, HierarchyItem, TypeHierarchyItem };
+
+pub trait CallHierarchyProvider {
+	/// The guest-side data attached to the items of this provider.
+	type Item: 'static;
+
+	fn prepare_call_hierarchy(&self, document: &TextDocument, position: &Position, token: &CancellationToken) -> Option<Vec<CallHierarchyItem<Self::Item>>>;
+	fn provide_call_hierarchy_incoming_calls(&self, item: &CallHierarchyItem<Self::Item>, token: &CancellationToken) -> Option<Vec<CallHierarchyIncomingCall<Self::Item>>>;
+	fn provide_call_hierarchy_outgoing_calls(&self, item: &CallHierarchyItem<Self::Item>, token: &CancellationToken) -> Option<Vec<CallHierarchyOutgoingCall<Self::Item>>>;
+}
+
+pub trait TypeHierarchyProvider {
+	/// The guest-side data attached to the items of this provider.
+	type Item: 'static;
+
+	fn prepare_type_hierarchy(&self, document: &TextDocument, position: &Position, token: &CancellationToken) -> Option<Vec<TypeHierarchyItem<Self::Item>>>;
+	fn provide_type_hierarchy_supertypes(&self, item: &TypeHierarchyItem<Self::Item>, token: &CancellationToken) -> Option<Vec<TypeHierarchyItem<Self::Item>>>;
+	fn provide_type_hierarchy_subtypes(&self, item: &TypeHierarchyItem<Self::Item>, token: &CancellationToken) -> Option<Vec<TypeHierarchyItem<Self::Item>>>;
+}
+
+/// The items handed out to the host, keyed by their handle. An item is kept
+/// until the host releases it, so that every hierarchy the editor still
+/// shows can be expanded.
+struct HierarchyItems<T> {
+	next_handle: Cell<u32>,
+	items: RefCell<HashMap<u32, Rc<HierarchyItem<T>>>>
+}
+
+impl<T> HierarchyItems<T> {
+	fn new() -> Self {
+		HierarchyItems {
+			next_handle: Cell::new(1),
+			items: RefCell::new(HashMap::new())
+		}
+	}
+
+	fn add(&self, item: HierarchyItem<T>) -> HostHierarchyItem {
+		let handle = self.next_handle.get();
+		self.next_handle.set(handle + 1);
+		let result = item.to_host(handle);
+		self.items.borrow_mut().insert(handle, Rc::new(item));
+		result
+	}
+
+	fn add_all(&self, items: Vec<HierarchyItem<T>>) -> Vec<HostHierarchyItem> {
+		items.into_iter().map(|item| self.add(item)).collect()
+	}
+
+	fn get(&self, handle: u32) -> Option<Rc<HierarchyItem<T>>> {
+		self.items.borrow().get(&handle).cloned()
+	}
+
+	fn remove(&self, handle: u32) {
+		self.items.borrow_mut().remove(&handle);
+	}
+}
+
+/// Hides the item type of a call hierarchy provider so that providers with
+/// different item types can live in the same registry.
+trait CallHierarchyHandler {
+	fn prepare(&self, document: &TextDocument, position: &Position, token: &CancellationToken) -> Option<Vec<HostHierarchyItem>>;
+	fn incoming_calls(&self, item: u32, token: &CancellationToken) -> Option<Vec<host_types::CallHierarchyIncomingCall>>;
+	fn outgoing_calls(&self, item: u32, token: &CancellationToken) -> Option<Vec<host_types::CallHierarchyOutgoingCall>>;
+	fn release(&self, item: u32);
+}
+
+struct CallHierarchyAdapter<P> where P: CallHierarchyProvider {
+	provider: P,
+	items: HierarchyItems<P::Item>
+}
+
+impl<P> CallHierarchyHandler for CallHierarchyAdapter<P> where P: CallHierarchyProvider {
+	fn prepare(&self, document: &TextDocument, position: &Position, token: &CancellationToken) -> Option<Vec<HostHierarchyItem>> {
+		let items = self.provider.prepare_call_hierarchy(document, position, token)?;
+		Some(self.items.add_all(items))
+	}
+
+	fn incoming_calls(&self, item: u32, token: &CancellationToken) -> Option<Vec<host_types::CallHierarchyIncomingCall>> {
+		let item = self.items.get(item)?;
+		let calls = self.provider.provide_call_hierarchy_incoming_calls(&item, token)?;
+		Some(calls.into_iter().map(|call| host_types::CallHierarchyIncomingCall {
+			from: self.items.add(call.from),
+			from_ranges: call.from_ranges
+		}).collect())
+	}
+
+	fn outgoing_calls(&self, item: u32, token: &CancellationToken) -> Option<Vec<host_types::CallHierarchyOutgoingCall>> {
+		let item = self.items.get(item)?;
+		let calls = self.provider.provide_call_hierarchy_outgoing_calls(&item, token)?;
+		Some(calls.into_iter().map(|call| host_types::CallHierarchyOutgoingCall {
+			to: self.items.add(call.to),
+			from_ranges: call.from_ranges
+		}).collect())
+	}
+
+	fn release(&self, item: u32) {
+		self.items.remove(item);
+	}
+}
+
+/// Hides the item type of a type hierarchy provider, see `CallHierarchyHandler`.
+trait TypeHierarchyHandler {
+	fn prepare(&self, document: &TextDocument, position: &Position, token: &CancellationToken) -> Option<Vec<HostHierarchyItem>>;
+	fn supertypes(&self, item: u32, token: &CancellationToken) -> Option<Vec<HostHierarchyItem>>;
+	fn subtypes(&self, item: u32, token: &CancellationToken) -> Option<Vec<HostHierarchyItem>>;
+	fn release(&self, item: u32);
+}
+
+struct TypeHierarchyAdapter<P> where P: TypeHierarchyProvider {
+	provider: P,
+	items: HierarchyItems<P::Item>
+}
+
+impl<P> TypeHierarchyHandler for TypeHierarchyAdapter<P> where P: TypeHierarchyProvider {
+	fn prepare(&self, document: &TextDocument, position: &Position, token: &CancellationToken) -> Option<Vec<HostHierarchyItem>> {
+		let items = self.provider.prepare_type_hierarchy(document, position, token)?;
+		Some(self.items.add_all(items))
+	}
+
+	fn supertypes(&self, item: u32, token: &CancellationToken) -> Option<Vec<HostHierarchyItem>> {
+		let item = self.items.get(item)?;
+		let items = self.provider.provide_type_hierarchy_supertypes(&item, token)?;
+		Some(self.items.add_all(items))
+	}
+
+	fn subtypes(&self, item: u32, token: &CancellationToken) -> Option<Vec<HostHierarchyItem>> {
+		let item = self.items.get(item)?;
+		let items = self.provider.provide_type_hierarchy_subtypes(&item, token)?;
+		Some(self.items.add_all(items))
+	}
+
+	fn release(&self, item: u32) {
+		self.items.remove(item);
+	}
+}
+
+thread_local! {
+	static CALL_HIERARCHY_PROVIDERS: Providers<dyn CallHierarchyHandler> = Providers::new();
+	static TYPE_HIERARCHY_PROVIDERS: Providers<dyn TypeHierarchyHandler> = Providers::new();
+}
+
+pub fn register_call_hierarchy_provider<P>(selector: &DocumentSelector, provider: P) -> impl Fn() + 'static
+where
+	P: CallHierarchyProvider + 'static,
+{
+	let adapter = CallHierarchyAdapter { provider, items: HierarchyItems::new() };
+	register_provider(&CALL_HIERARCHY_PROVIDERS, Rc::new(adapter), None, &|id| languages::register_call_hierarchy_provider(id, selector))
+}
+
+pub fn register_type_hierarchy_provider<P>(selector: &DocumentSelector, provider: P) -> impl Fn() + 'static
+where
+	P: TypeHierarchyProvider + 'static,
+{
+	let adapter = TypeHierarchyAdapter { provider, items: HierarchyItems::new() };
+	register_provider(&TYPE_HIERARCHY_PROVIDERS, Rc::new(adapter), None, &|id| languages::register_type_hierarchy_provider(id, selector))
+}
+
+pub(crate) fn prepare_call_hierarchy(provider: u32, document: &TextDocument, position: &Position, token: &CancellationToken) -> Option<Vec<HostHierarchyItem>> {
+	let provider = CALL_HIERARCHY_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.prepare(document, position, token)
+}
+
+pub(crate) fn provide_call_hierarchy_incoming_calls(provider: u32, item: u32, token: &CancellationToken) -> Option<Vec<host_types::CallHierarchyIncomingCall>> {
+	let provider = CALL_HIERARCHY_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.incoming_calls(item, token)
+}
+
+pub(crate) fn provide_call_hierarchy_outgoing_calls(provider: u32, item: u32, token: &CancellationToken) -> Option<Vec<host_types::CallHierarchyOutgoingCall>> {
+	let provider = CALL_HIERARCHY_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.outgoing_calls(item, token)
+}
+
+pub(crate) fn prepare_type_hierarchy(provider: u32, document: &TextDocument, position: &Position, token: &CancellationToken) -> Option<Vec<HostHierarchyItem>> {
+	let provider = TYPE_HIERARCHY_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.prepare(document, position, token)
+}
+
+pub(crate) fn provide_type_hierarchy_supertypes(provider: u32, item: u32, token: &CancellationToken) -> Option<Vec<HostHierarchyItem>> {
+	let provider = TYPE_HIERARCHY_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.supertypes(item, token)
+}
+
+pub(crate) fn provide_type_hierarchy_subtypes(provider: u32, item: u32, token: &CancellationToken) -> Option<Vec<HostHierarchyItem>> {
+	let provider = TYPE_HIERARCHY_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.subtypes(item, token)
+}
+
+/// Provider ids are unique across kinds, so the item belongs to whichever
+/// kind of hierarchy provider has the id.
+pub(crate) fn release_hierarchy_item(provider: u32, item: u32) {
+	if let Some(provider) = CALL_HIERARCHY_PROVIDERS.with(|providers| providers.get(provider)) {
+		provider.release(item);
+	} else if let Some(provider) = TYPE_HIERARCHY_PROVIDERS.with(|providers| providers.get(provider)) {
+		provider.release(item);
+	}
+}
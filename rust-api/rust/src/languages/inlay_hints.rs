@@ -0,0 +1,46 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use std::rc::Rc;
+
+use crate::host::api::types::{ CancellationToken, DocumentSelector, InlayHint, Range, TextDocument };
+use crate::host::api::languages;
+use crate::common::Providers;
+
+use super::{ register_provider, ChangeEmitter };
+
+pub trait InlayHintsProvider {
+	fn provide_inlay_hints(&self, document: &TextDocument, range: &Range, token: &CancellationToken) -> Option<Vec<InlayHint>>;
+
+	/// Fills in the tooltip, text edits or label parts of a hint when it is
+	/// hovered. The default returns the hint unchanged.
+	fn resolve_inlay_hint(&self, hint: InlayHint, _token: &CancellationToken) -> InlayHint {
+		hint
+	}
+}
+
+thread_local! {
+	static INLAY_HINTS_PROVIDERS: Providers<dyn InlayHintsProvider> = Providers::new();
+}
+
+/// Registers an inlay hints provider. Firing `on_did_change_inlay_hints`
+/// makes the editor request the hints again.
+pub fn register_inlay_hints_provider<P>(selector: &DocumentSelector, provider: P, on_did_change_inlay_hints: Option<&ChangeEmitter>) -> impl Fn() + 'static
+where
+	P: InlayHintsProvider + 'static,
+{
+	register_provider(&INLAY_HINTS_PROVIDERS, Rc::new(provider), on_did_change_inlay_hints, &|id| languages::register_inlay_hints_provider(id, selector))
+}
+
+pub(crate) fn provide_inlay_hints(provider: u32, document: &TextDocument, range: &Range, token: &CancellationToken) -> Option<Vec<InlayHint>> {
+	let provider = INLAY_HINTS_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.provide_inlay_hints(document, range, token)
+}
+
+pub(crate) fn resolve_inlay_hint(provider: u32, hint: InlayHint, token: &CancellationToken) -> InlayHint {
+	match INLAY_HINTS_PROVIDERS.with(|providers| providers.get(provider)) {
+		Some(provider) => provider.resolve_inlay_hint(hint, token),
+		None => hint
+	}
+}
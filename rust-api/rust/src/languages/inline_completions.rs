@@ -0,0 +1,34 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use std::rc::Rc;
+
+use crate::host::api::types::{
+	CancellationToken, DocumentSelector, InlineCompletionContext, InlineCompletionItem, Position, TextDocument
+};
+use crate::host::api::languages;
+use crate::common::Providers;
+use super::register_provider;
+
+pub trait InlineCompletionItemProvider {
+	/// Computes the ghost text items at `position`. If the suggest widget is
+	/// open, `context.selected_completion_info` describes the selected item.
+	fn provide_inline_completion_items(&self, document: &TextDocument, position: &Position, context: &InlineCompletionContext, token: &CancellationToken) -> Option<Vec<InlineCompletionItem>>;
+}
+
+thread_local! {
+	static INLINE_COMPLETION_ITEM_PROVIDERS: Providers<dyn InlineCompletionItemProvider> = Providers::new();
+}
+
+pub fn register_inline_completion_item_provider<P>(selector: &DocumentSelector, provider: P) -> impl Fn() + 'static
+where
+	P: InlineCompletionItemProvider + 'static,
+{
+	register_provider(&INLINE_COMPLETION_ITEM_PROVIDERS, Rc::new(provider), None, &|id| languages::register_inline_completion_item_provider(id, selector))
+}
+
+pub(crate) fn provide_inline_completion_items(provider: u32, document: &TextDocument, position: &Position, context: &InlineCompletionContext, token: &CancellationToken) -> Option<Vec<InlineCompletionItem>> {
+	let provider = INLINE_COMPLETION_ITEM_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.provide_inline_completion_items(document, position, context, token)
+}
@@ -0,0 +1,32 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use std::rc::Rc;
+
+use crate::host::api::types::{ CancellationToken, DocumentSelector, LinkedEditingRanges, Position, TextDocument };
+use crate::host::api::languages;
+use crate::common::Providers;
+use super::register_provider;
+
+pub trait LinkedEditingRangeProvider {
+	/// Returns the ranges that are edited together with the one at
+	/// `position`, e.g. the start and end tag of an element.
+	fn provide_linked_editing_ranges(&self, document: &TextDocument, position: &Position, token: &CancellationToken) -> Option<LinkedEditingRanges>;
+}
+
+thread_local! {
+	static LINKED_EDITING_RANGE_PROVIDERS: Providers<dyn LinkedEditingRangeProvider> = Providers::new();
+}
+
+pub fn register_linked_editing_range_provider<P>(selector: &DocumentSelector, provider: P) -> impl Fn() + 'static
+where
+	P: LinkedEditingRangeProvider + 'static,
+{
+	register_provider(&LINKED_EDITING_RANGE_PROVIDERS, Rc::new(provider), None, &|id| languages::register_linked_editing_range_provider(id, selector))
+}
+
+pub(crate) fn provide_linked_editing_ranges(provider: u32, document: &TextDocument, position: &Position, token: &CancellationToken) -> Option<LinkedEditingRanges> {
+	let provider = LINKED_EDITING_RANGE_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.provide_linked_editing_ranges(document, position, token)
+}
@@ -3,8 +3,106 @@
  *  Licensed under the MIT License. See License.txt in the project root for license information.
  *--------------------------------------------------------------------------------------------*/
 
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::thread::LocalKey;
+
 use crate::host::api::types::{ DocumentSelector, TextDocument };
 use crate::host::api::languages;
+use crate::common::Providers;
+
+mod diagnostics;
+mod code_actions;
+mod formatting;
+mod semantic_tokens;
+mod symbols;
+mod navigation;
+mod references;
+mod rename;
+mod folding;
+mod selection_ranges;
+mod inlay_hints;
+mod code_lens;
+mod signature_help;
+mod hierarchy;
+mod colors;
+mod document_links;
+mod linked_editing;
+mod inline_completions;
+mod configuration;
+mod status;
+
+pub use diagnostics::*;
+pub use code_actions::*;
+pub use formatting::*;
+pub use semantic_tokens::*;
+pub use symbols::*;
+pub use navigation::*;
+pub use references::*;
+pub use rename::*;
+pub use folding::*;
+pub use selection_ranges::*;
+pub use inlay_hints::*;
+pub use code_lens::*;
+pub use signature_help::*;
+pub use hierarchy::*;
+pub use colors::*;
+pub use document_links::*;
+pub use linked_editing::*;
+pub use inline_completions::*;
+pub use configuration::*;
+pub use status::*;
 
 #[allow(non_upper_case_globals)]
-pub const match_selector: fn(selector: &DocumentSelector, document: TextDocument) -> u32 = languages::match_selector;
\ No newline at end of file
+pub const match_selector: fn(selector: &DocumentSelector, document: TextDocument) -> u32 = languages::match_selector;
+
+/// Tells the editor that the results of the providers registered with this
+/// emitter have changed, so that it requests them again.
+#[derive(Clone, Default)]
+pub struct ChangeEmitter {
+	providers: Rc<RefCell<Vec<u32>>>
+}
+
+impl ChangeEmitter {
+	pub fn new() -> Self {
+		ChangeEmitter::default()
+	}
+
+	pub fn fire(&self) {
+		let providers = self.providers.borrow().clone();
+		for provider in providers {
+			languages::fire_did_change_provider(provider);
+		}
+	}
+
+	pub(crate) fn add(&self, provider: u32) {
+		self.providers.borrow_mut().push(provider);
+	}
+
+	pub(crate) fn remove(&self, provider: u32) {
+		self.providers.borrow_mut().retain(|id| *id != provider);
+	}
+}
+
+/// Adds the provider, registers it with the host by calling `register` with
+/// its id and returns the function that unregisters it again. Firing
+/// `on_did_change` makes the editor request the provider's results again.
+pub(crate) fn register_provider<P>(providers: &'static LocalKey<Providers<P>>, provider: Rc<P>, on_did_change: Option<&ChangeEmitter>, register: &dyn Fn(u32)) -> impl Fn() + 'static
+where
+	P: ?Sized + 'static,
+{
+	let id = providers.with(|providers| providers.add(provider));
+	register(id);
+	let emitter = on_did_change.cloned();
+	if let Some(emitter) = &emitter {
+		emitter.add(id);
+	}
+	move || {
+		if providers.with(|providers| providers.remove(id)) {
+			if let Some(emitter) = &emitter {
+				emitter.remove(id);
+			}
+			languages::unregister_provider(id);
+		}
+	}
+}
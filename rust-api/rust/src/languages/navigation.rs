@@ -0,0 +1,81 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use std::rc::Rc;
+
+use crate::host::api::types::{ CancellationToken, Definition, DocumentSelector, Position, TextDocument };
+use crate::host::api::languages;
+use crate::common::Providers;
+use super::register_provider;
+
+pub trait DefinitionProvider {
+	fn provide_definition(&self, document: &TextDocument, position: &Position, token: &CancellationToken) -> Option<Definition>;
+}
+
+pub trait DeclarationProvider {
+	fn provide_declaration(&self, document: &TextDocument, position: &Position, token: &CancellationToken) -> Option<Definition>;
+}
+
+pub trait TypeDefinitionProvider {
+	fn provide_type_definition(&self, document: &TextDocument, position: &Position, token: &CancellationToken) -> Option<Definition>;
+}
+
+pub trait ImplementationProvider {
+	fn provide_implementation(&self, document: &TextDocument, position: &Position, token: &CancellationToken) -> Option<Definition>;
+}
+
+thread_local! {
+	static DEFINITION_PROVIDERS: Providers<dyn DefinitionProvider> = Providers::new();
+	static DECLARATION_PROVIDERS: Providers<dyn DeclarationProvider> = Providers::new();
+	static TYPE_DEFINITION_PROVIDERS: Providers<dyn TypeDefinitionProvider> = Providers::new();
+	static IMPLEMENTATION_PROVIDERS: Providers<dyn ImplementationProvider> = Providers::new();
+}
+
+pub fn register_definition_provider<P>(selector: &DocumentSelector, provider: P) -> impl Fn() + 'static
+where
+	P: DefinitionProvider + 'static,
+{
+	register_provider(&DEFINITION_PROVIDERS, Rc::new(provider), None, &|id| languages::register_definition_provider(id, selector))
+}
+
+pub fn register_declaration_provider<P>(selector: &DocumentSelector, provider: P) -> impl Fn() + 'static
+where
+	P: DeclarationProvider + 'static,
+{
+	register_provider(&DECLARATION_PROVIDERS, Rc::new(provider), None, &|id| languages::register_declaration_provider(id, selector))
+}
+
+pub fn register_type_definition_provider<P>(selector: &DocumentSelector, provider: P) -> impl Fn() + 'static
+where
+	P: TypeDefinitionProvider + 'static,
+{
+	register_provider(&TYPE_DEFINITION_PROVIDERS, Rc::new(provider), None, &|id| languages::register_type_definition_provider(id, selector))
+}
+
+pub fn register_implementation_provider<P>(selector: &DocumentSelector, provider: P) -> impl Fn() + 'static
+where
+	P: ImplementationProvider + 'static,
+{
+	register_provider(&IMPLEMENTATION_PROVIDERS, Rc::new(provider), None, &|id| languages::register_implementation_provider(id, selector))
+}
+
+pub(crate) fn provide_definition(provider: u32, document: &TextDocument, position: &Position, token: &CancellationToken) -> Option<Definition> {
+	let provider = DEFINITION_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.provide_definition(document, position, token)
+}
+
+pub(crate) fn provide_declaration(provider: u32, document: &TextDocument, position: &Position, token: &CancellationToken) -> Option<Definition> {
+	let provider = DECLARATION_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.provide_declaration(document, position, token)
+}
+
+pub(crate) fn provide_type_definition(provider: u32, document: &TextDocument, position: &Position, token: &CancellationToken) -> Option<Definition> {
+	let provider = TYPE_DEFINITION_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.provide_type_definition(document, position, token)
+}
+
+pub(crate) fn provide_implementation(provider: u32, document: &TextDocument, position: &Position, token: &CancellationToken) -> Option<Definition> {
+	let provider = IMPLEMENTATION_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.provide_implementation(document, position, token)
+}
@@ -0,0 +1,49 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use std::rc::Rc;
+
+use crate::host::api::types::{
+	CancellationToken, DocumentHighlight, DocumentSelector, Location, Position, ReferenceContext, TextDocument
+};
+use crate::host::api::languages;
+use crate::common::Providers;
+use super::register_provider;
+
+pub trait ReferenceProvider {
+	fn provide_references(&self, document: &TextDocument, position: &Position, context: &ReferenceContext, token: &CancellationToken) -> Option<Vec<Location>>;
+}
+
+pub trait DocumentHighlightProvider {
+	fn provide_document_highlights(&self, document: &TextDocument, position: &Position, token: &CancellationToken) -> Option<Vec<DocumentHighlight>>;
+}
+
+thread_local! {
+	static REFERENCE_PROVIDERS: Providers<dyn ReferenceProvider> = Providers::new();
+	static DOCUMENT_HIGHLIGHT_PROVIDERS: Providers<dyn DocumentHighlightProvider> = Providers::new();
+}
+
+pub fn register_reference_provider<P>(selector: &DocumentSelector, provider: P) -> impl Fn() + 'static
+where
+	P: ReferenceProvider + 'static,
+{
+	register_provider(&REFERENCE_PROVIDERS, Rc::new(provider), None, &|id| languages::register_reference_provider(id, selector))
+}
+
+pub fn register_document_highlight_provider<P>(selector: &DocumentSelector, provider: P) -> impl Fn() + 'static
+where
+	P: DocumentHighlightProvider + 'static,
+{
+	register_provider(&DOCUMENT_HIGHLIGHT_PROVIDERS, Rc::new(provider), None, &|id| languages::register_document_highlight_provider(id, selector))
+}
+
+pub(crate) fn provide_references(provider: u32, document: &TextDocument, position: &Position, context: &ReferenceContext, token: &CancellationToken) -> Option<Vec<Location>> {
+	let provider = REFERENCE_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.provide_references(document, position, context, token)
+}
+
+pub(crate) fn provide_document_highlights(provider: u32, document: &TextDocument, position: &Position, token: &CancellationToken) -> Option<Vec<DocumentHighlight>> {
+	let provider = DOCUMENT_HIGHLIGHT_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.provide_document_highlights(document, position, token)
+}
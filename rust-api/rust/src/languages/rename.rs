@@ -0,0 +1,44 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use std::rc::Rc;
+
+use crate::host::api::types::{
+	CancellationToken, DocumentSelector, Position, PrepareRenameResult, TextDocument, WorkspaceEdit
+};
+use crate::host::api::languages;
+use crate::common::Providers;
+use super::register_provider;
+
+pub trait RenameProvider {
+	fn provide_rename_edits(&self, document: &TextDocument, position: &Position, new_name: &str, token: &CancellationToken) -> Option<WorkspaceEdit>;
+
+	/// Checks whether a rename is possible at `position` before the user
+	/// enters a new name. Returning `None` lets the editor use the word at
+	/// the position, which is also the default.
+	fn prepare_rename(&self, _document: &TextDocument, _position: &Position, _token: &CancellationToken) -> Option<PrepareRenameResult> {
+		None
+	}
+}
+
+thread_local! {
+	static RENAME_PROVIDERS: Providers<dyn RenameProvider> = Providers::new();
+}
+
+pub fn register_rename_provider<P>(selector: &DocumentSelector, provider: P) -> impl Fn() + 'static
+where
+	P: RenameProvider + 'static,
+{
+	register_provider(&RENAME_PROVIDERS, Rc::new(provider), None, &|id| languages::register_rename_provider(id, selector))
+}
+
+pub(crate) fn prepare_rename(provider: u32, document: &TextDocument, position: &Position, token: &CancellationToken) -> Option<PrepareRenameResult> {
+	let provider = RENAME_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.prepare_rename(document, position, token)
+}
+
+pub(crate) fn provide_rename_edits(provider: u32, document: &TextDocument, position: &Position, new_name: &str, token: &CancellationToken) -> Option<WorkspaceEdit> {
+	let provider = RENAME_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.provide_rename_edits(document, position, new_name, token)
+}
@@ -0,0 +1,32 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use std::rc::Rc;
+
+use crate::host::api::types::{ CancellationToken, DocumentSelector, Position, TextDocument };
+use crate::host::api::languages;
+use crate::common::Providers;
+use super::register_provider;
+use crate::types::SelectionRange;
+
+pub trait SelectionRangeProvider {
+	/// Returns one selection range for each of the given positions.
+	fn provide_selection_ranges(&self, document: &TextDocument, positions: &[Position], token: &CancellationToken) -> Option<Vec<SelectionRange>>;
+}
+
+thread_local! {
+	static SELECTION_RANGE_PROVIDERS: Providers<dyn SelectionRangeProvider> = Providers::new();
+}
+
+pub fn register_selection_range_provider<P>(selector: &DocumentSelector, provider: P) -> impl Fn() + 'static
+where
+	P: SelectionRangeProvider + 'static,
+{
+	register_provider(&SELECTION_RANGE_PROVIDERS, Rc::new(provider), None, &|id| languages::register_selection_range_provider(id, selector))
+}
+
+pub(crate) fn provide_selection_ranges(provider: u32, document: &TextDocument, positions: &[Position], token: &CancellationToken) -> Option<Vec<SelectionRange>> {
+	let provider = SELECTION_RANGE_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.provide_selection_ranges(document, positions, token)
+}
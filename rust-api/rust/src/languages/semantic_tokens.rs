@@ -0,0 +1,60 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use std::rc::Rc;
+
+use crate::host::api::types::{
+	CancellationToken, DocumentSelector, Range, SemanticTokens, SemanticTokensLegend, SemanticTokensResult, TextDocument
+};
+use crate::host::api::languages;
+use crate::common::Providers;
+use super::register_provider;
+
+pub trait DocumentSemanticTokensProvider {
+	fn provide_document_semantic_tokens(&self, document: &TextDocument, token: &CancellationToken) -> Option<SemanticTokens>;
+
+	/// Computes the tokens relative to the ones last returned with
+	/// `previous_result_id`. The default recomputes all tokens.
+	fn provide_document_semantic_tokens_edits(&self, document: &TextDocument, _previous_result_id: &str, token: &CancellationToken) -> Option<SemanticTokensResult> {
+		self.provide_document_semantic_tokens(document, token).map(SemanticTokensResult::Full)
+	}
+}
+
+pub trait DocumentRangeSemanticTokensProvider {
+	fn provide_document_range_semantic_tokens(&self, document: &TextDocument, range: &Range, token: &CancellationToken) -> Option<SemanticTokens>;
+}
+
+thread_local! {
+	static DOCUMENT_SEMANTIC_TOKENS_PROVIDERS: Providers<dyn DocumentSemanticTokensProvider> = Providers::new();
+	static DOCUMENT_RANGE_SEMANTIC_TOKENS_PROVIDERS: Providers<dyn DocumentRangeSemanticTokensProvider> = Providers::new();
+}
+
+pub fn register_document_semantic_tokens_provider<P>(selector: &DocumentSelector, provider: P, legend: &SemanticTokensLegend) -> impl Fn() + 'static
+where
+	P: DocumentSemanticTokensProvider + 'static,
+{
+	register_provider(&DOCUMENT_SEMANTIC_TOKENS_PROVIDERS, Rc::new(provider), None, &|id| languages::register_document_semantic_tokens_provider(id, selector, legend))
+}
+
+pub fn register_document_range_semantic_tokens_provider<P>(selector: &DocumentSelector, provider: P, legend: &SemanticTokensLegend) -> impl Fn() + 'static
+where
+	P: DocumentRangeSemanticTokensProvider + 'static,
+{
+	register_provider(&DOCUMENT_RANGE_SEMANTIC_TOKENS_PROVIDERS, Rc::new(provider), None, &|id| languages::register_document_range_semantic_tokens_provider(id, selector, legend))
+}
+
+pub(crate) fn provide_document_semantic_tokens(provider: u32, document: &TextDocument, token: &CancellationToken) -> Option<SemanticTokens> {
+	let provider = DOCUMENT_SEMANTIC_TOKENS_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.provide_document_semantic_tokens(document, token)
+}
+
+pub(crate) fn provide_document_semantic_tokens_edits(provider: u32, document: &TextDocument, previous_result_id: &str, token: &CancellationToken) -> Option<SemanticTokensResult> {
+	let provider = DOCUMENT_SEMANTIC_TOKENS_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.provide_document_semantic_tokens_edits(document, previous_result_id, token)
+}
+
+pub(crate) fn provide_document_range_semantic_tokens(provider: u32, document: &TextDocument, range: &Range, token: &CancellationToken) -> Option<SemanticTokens> {
+	let provider = DOCUMENT_RANGE_SEMANTIC_TOKENS_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.provide_document_range_semantic_tokens(document, range, token)
+}
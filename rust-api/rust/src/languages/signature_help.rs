@@ -0,0 +1,34 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use std::rc::Rc;
+
+use crate::host::api::types::{
+	CancellationToken, DocumentSelector, Position, SignatureHelp, SignatureHelpContext, SignatureHelpProviderMetadata, TextDocument
+};
+use crate::host::api::languages;
+use crate::common::Providers;
+use super::register_provider;
+
+pub trait SignatureHelpProvider {
+	/// Computes the signature help at `position`. While signature help is
+	/// showing, `context.active_signature_help` holds the previous result.
+	fn provide_signature_help(&self, document: &TextDocument, position: &Position, context: &SignatureHelpContext, token: &CancellationToken) -> Option<SignatureHelp>;
+}
+
+thread_local! {
+	static SIGNATURE_HELP_PROVIDERS: Providers<dyn SignatureHelpProvider> = Providers::new();
+}
+
+pub fn register_signature_help_provider<P>(selector: &DocumentSelector, provider: P, metadata: &SignatureHelpProviderMetadata) -> impl Fn() + 'static
+where
+	P: SignatureHelpProvider + 'static,
+{
+	register_provider(&SIGNATURE_HELP_PROVIDERS, Rc::new(provider), None, &|id| languages::register_signature_help_provider(id, selector, metadata))
+}
+
+pub(crate) fn provide_signature_help(provider: u32, document: &TextDocument, position: &Position, context: &SignatureHelpContext, token: &CancellationToken) -> Option<SignatureHelp> {
+	let provider = SIGNATURE_HELP_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.provide_signature_help(document, position, context, token)
+}
@@ -0,0 +1,12 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+
+use crate::host::api::types::{ DocumentSelector, LanguageStatusItem };
+use crate::host::api::languages;
+
+/// Creates a status item that is shown next to the language mode when the
+/// active editor matches `selector`. Dropping the item removes it.
+#[allow(non_upper_case_globals)]
+pub const create_language_status_item: fn(id: &str, selector: &DocumentSelector) -> LanguageStatusItem = languages::create_language_status_item;
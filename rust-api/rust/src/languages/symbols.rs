@@ -0,0 +1,61 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use std::rc::Rc;
+
+use crate::host::api::types::{ CancellationToken, DocumentSelector, SymbolInformation, TextDocument };
+use crate::host::api::languages;
+use crate::common::Providers;
+use super::register_provider;
+use crate::types::DocumentSymbol;
+
+pub trait DocumentSymbolProvider {
+	fn provide_document_symbols(&self, document: &TextDocument, token: &CancellationToken) -> Option<Vec<DocumentSymbol>>;
+}
+
+pub trait WorkspaceSymbolProvider {
+	fn provide_workspace_symbols(&self, query: &str, token: &CancellationToken) -> Option<Vec<SymbolInformation>>;
+
+	/// Fills in the location of a workspace symbol when it gets selected.
+	/// The default returns the symbol unchanged.
+	fn resolve_workspace_symbol(&self, symbol: SymbolInformation, _token: &CancellationToken) -> SymbolInformation {
+		symbol
+	}
+}
+
+thread_local! {
+	static DOCUMENT_SYMBOL_PROVIDERS: Providers<dyn DocumentSymbolProvider> = Providers::new();
+	static WORKSPACE_SYMBOL_PROVIDERS: Providers<dyn WorkspaceSymbolProvider> = Providers::new();
+}
+
+pub fn register_document_symbol_provider<P>(selector: &DocumentSelector, provider: P) -> impl Fn() + 'static
+where
+	P: DocumentSymbolProvider + 'static,
+{
+	register_provider(&DOCUMENT_SYMBOL_PROVIDERS, Rc::new(provider), None, &|id| languages::register_document_symbol_provider(id, selector))
+}
+
+pub fn register_workspace_symbol_provider<P>(provider: P) -> impl Fn() + 'static
+where
+	P: WorkspaceSymbolProvider + 'static,
+{
+	register_provider(&WORKSPACE_SYMBOL_PROVIDERS, Rc::new(provider), None, &|id| languages::register_workspace_symbol_provider(id))
+}
+
+pub(crate) fn provide_document_symbols(provider: u32, document: &TextDocument, token: &CancellationToken) -> Option<Vec<DocumentSymbol>> {
+	let provider = DOCUMENT_SYMBOL_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.provide_document_symbols(document, token)
+}
+
+pub(crate) fn provide_workspace_symbols(provider: u32, query: &str, token: &CancellationToken) -> Option<Vec<SymbolInformation>> {
+	let provider = WORKSPACE_SYMBOL_PROVIDERS.with(|providers| providers.get(provider))?;
+	provider.provide_workspace_symbols(query, token)
+}
+
+pub(crate) fn resolve_workspace_symbol(provider: u32, symbol: SymbolInformation, token: &CancellationToken) -> SymbolInformation {
+	match WORKSPACE_SYMBOL_PROVIDERS.with(|providers| providers.get(provider)) {
+		Some(provider) => provider.resolve_workspace_symbol(symbol, token),
+		None => symbol
+	}
+}
@@ -3,29 +3,135 @@
  *  Licensed under the MIT License. See License.txt in the project root for license information.
  *--------------------------------------------------------------------------------------------*/
 
-// Use a procedural macro to generate bindings for the world we specified in
-// `host.wit`
-wit_bindgen::generate!({
-	// the name of the world in the `*.wit` input file
-	world: "all"
-});
+// The generated bindings contain unsafe traits without a `# Safety` section.
+#![allow(clippy::missing_safety_doc)]
+
+#[doc(hidden)]
+pub mod bindings {
+	// Use a procedural macro to generate bindings for the world we specified in
+	// `host.wit`. The world is exported by the extension through the
+	// `extension!` macro.
+	wit_bindgen::generate!({
+		// the name of the world in the `*.wit` input file
+		world: "all",
+		default_bindings_module: "vscode::bindings",
+		pub_export_macro: true
+	});
+}
+
+use std::marker::PhantomData;
+
+pub use bindings::*;
 
 pub mod commands;
+pub mod env;
 pub mod window;
 pub mod workspace;
 pub mod languages;
 mod common;
+mod extension;
+mod types;
 
 pub type OutputChannel = host::api::types::OutputChannel;
 pub type TextDocument = host::api::types::TextDocument;
 pub type TextDocumentChangeEvent = host::api::types::TextDocumentChangeEvent;
+pub type TextEditor = host::api::types::TextEditor;
 pub type DocumentFilter = host::api::types::DocumentFilter;
 pub type DocumentSelector = host::api::types::DocumentSelector;
+pub type Uri = host::api::types::Uri;
+pub type Position = host::api::types::Position;
+pub type Range = host::api::types::Range;
+pub type Selection = host::api::types::Selection;
+pub type Location = host::api::types::Location;
+pub type LocationLink = host::api::types::LocationLink;
+pub type Definition = host::api::types::Definition;
+pub type ReferenceContext = host::api::types::ReferenceContext;
+pub type DocumentHighlight = host::api::types::DocumentHighlight;
+pub type DocumentHighlightKind = host::api::types::DocumentHighlightKind;
+pub type RenameLocation = host::api::types::RenameLocation;
+pub type PrepareRenameResult = host::api::types::PrepareRenameResult;
+pub type FoldingRange = host::api::types::FoldingRange;
+pub type FoldingRangeKind = host::api::types::FoldingRangeKind;
+pub type InlayHint = host::api::types::InlayHint;
+pub type InlayHintKind = host::api::types::InlayHintKind;
+pub type InlayHintLabel = host::api::types::InlayHintLabel;
+pub type InlayHintLabelPart = host::api::types::InlayHintLabelPart;
+pub type CodeLens = host::api::types::CodeLens;
+pub type ParameterLabel = host::api::types::ParameterLabel;
+pub type ParameterInformation = host::api::types::ParameterInformation;
+pub type SignatureInformation = host::api::types::SignatureInformation;
+pub type SignatureHelp = host::api::types::SignatureHelp;
+pub type SignatureHelpTriggerKind = host::api::types::SignatureHelpTriggerKind;
+pub type SignatureHelpContext = host::api::types::SignatureHelpContext;
+pub type SignatureHelpProviderMetadata = host::api::types::SignatureHelpProviderMetadata;
+pub type Color = host::api::types::Color;
+pub type ColorInformation = host::api::types::ColorInformation;
+pub type ColorPresentation = host::api::types::ColorPresentation;
+pub type DocumentLink = host::api::types::DocumentLink;
+pub type LinkedEditingRanges = host::api::types::LinkedEditingRanges;
+pub type InlineCompletionItem = host::api::types::InlineCompletionItem;
+pub type InlineCompletionTriggerKind = host::api::types::InlineCompletionTriggerKind;
+pub type SelectedCompletionInfo = host::api::types::SelectedCompletionInfo;
+pub type InlineCompletionContext = host::api::types::InlineCompletionContext;
+pub type CommentRule = host::api::types::CommentRule;
+pub type AutoClosingPair = host::api::types::AutoClosingPair;
+pub type IndentationRule = host::api::types::IndentationRule;
+pub type IndentAction = host::api::types::IndentAction;
+pub type EnterAction = host::api::types::EnterAction;
+pub type OnEnterRule = host::api::types::OnEnterRule;
+pub type LanguageConfiguration = host::api::types::LanguageConfiguration;
+pub type LanguageStatusItem = host::api::types::LanguageStatusItem;
+pub type LanguageStatusSeverity = host::api::types::LanguageStatusSeverity;
+pub type AccessibilityInformation = host::api::types::AccessibilityInformation;
+pub type Diagnostic = host::api::types::Diagnostic;
+pub type DiagnosticSeverity = host::api::types::DiagnosticSeverity;
+pub type DiagnosticTag = host::api::types::DiagnosticTag;
+pub type DiagnosticCode = host::api::types::DiagnosticCode;
+pub type DiagnosticCodeValue = host::api::types::DiagnosticCodeValue;
+pub type DiagnosticRelatedInformation = host::api::types::DiagnosticRelatedInformation;
+pub type DiagnosticCollection = host::api::types::DiagnosticCollection;
+pub type DiagnosticChangeEvent = host::api::types::DiagnosticChangeEvent;
+pub type SecretStorageChangeEvent = host::api::types::SecretStorageChangeEvent;
+pub type CancellationToken = host::api::types::CancellationToken;
+pub type TextEdit = host::api::types::TextEdit;
+pub type WorkspaceEdit = host::api::types::WorkspaceEdit;
+pub type WorkspaceEditEntry = host::api::types::WorkspaceEditEntry;
+pub type Command = host::api::types::Command;
+pub type ContextValue = host::api::types::ContextValue;
+pub type CodeAction = host::api::types::CodeAction;
+pub type CodeActionContext = host::api::types::CodeActionContext;
+pub type CodeActionTriggerKind = host::api::types::CodeActionTriggerKind;
+pub type CodeActionProviderMetadata = host::api::types::CodeActionProviderMetadata;
+pub type FormattingOptions = host::api::types::FormattingOptions;
+pub type FormattingOption = host::api::types::FormattingOption;
+pub type FormattingOptionValue = host::api::types::FormattingOptionValue;
+pub type SemanticTokensLegend = host::api::types::SemanticTokensLegend;
+pub type SemanticTokens = host::api::types::SemanticTokens;
+pub type SemanticTokensEdit = host::api::types::SemanticTokensEdit;
+pub type SemanticTokensEdits = host::api::types::SemanticTokensEdits;
+pub type SemanticTokensResult = host::api::types::SemanticTokensResult;
+pub type SymbolKind = host::api::types::SymbolKind;
+pub type SymbolTag = host::api::types::SymbolTag;
+pub type SymbolInformation = host::api::types::SymbolInformation;
+
+pub use types::{
+	CallHierarchyIncomingCall, CallHierarchyItem, CallHierarchyOutgoingCall, CodeActionKind, DocumentSymbol, Error, HierarchyItem, SelectionRange,
+	SemanticTokensBuilder, TextEditorEdit, TypeHierarchyItem, UriChange
+};
+
+pub use common::spawn_local;
+pub use extension::{ Extension, ExtensionContext, ExtensionMode, Memento, SecretStorage };
 
 pub struct Disposables {
 	disposables: Vec<Box<dyn Fn()>>
 }
 
+impl Default for Disposables {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 impl Disposables {
 	pub fn new() -> Self {
 		Disposables {
@@ -47,15 +153,159 @@ impl Disposables {
 	}
 }
 
-struct Implementation;
+/// Exports the world for the extension `E`, see `extension!`.
+#[doc(hidden)]
+pub struct Implementation<E> {
+	extension: PhantomData<E>
+}
 
-impl exports::host::api::callbacks::Guest for Implementation {
-	fn execute_command(command: String) {
-		commands::execute_command(&command);
-  	}
-	fn did_change_text_document(event: host::api::types::TextDocumentChangeEvent) {
-		workspace::fire_did_change_text_document(&event);
+impl<E> Guest for Implementation<E> where E: Extension {
+	fn activate(context: HostExtensionContext) {
+		extension::activate(context, E::activate);
+	}
+
+	fn deactivate() {
+		extension::deactivate(E::deactivate);
 	}
 }
 
-export!(Implementation);
\ No newline at end of file
+impl<E> exports::host::api::callbacks::Guest for Implementation<E> {
+	fn execute_command(command: String, arguments: String) -> Result<String, String> {
+		commands::execute_command(&command, &arguments).map_err(|error| error.to_string())
+	}
+
+	fn execute_text_editor_command(command: String, editor: TextEditor, arguments: String) -> Result<Vec<TextEdit>, String> {
+		commands::execute_text_editor_command(&command, &editor, &arguments).map_err(|error| error.to_string())
+	}
+
+	fn complete_operation(operation: u32, outcome: Result<String, String>) {
+		common::complete_operation(operation, outcome);
+	}
+	fn did_change_text_document(event: host::api::types::TextDocumentChangeEvent) {
+		workspace::fire_did_change_text_document(&event);
+	}
+	fn did_change_diagnostics(event: host::api::types::DiagnosticChangeEvent) {
+		languages::fire_did_change_diagnostics(&event);
+	}
+	fn did_change_secret(event: host::api::types::SecretStorageChangeEvent) {
+		extension::fire_did_change_secret(&event);
+	}
+	fn provide_code_actions(provider: u32, document: TextDocument, range: Range, context: CodeActionContext, token: CancellationToken) -> Option<Vec<CodeAction>> {
+		languages::provide_code_actions(provider, &document, &range, &context, &token)
+	}
+	fn resolve_code_action(provider: u32, action: CodeAction, token: CancellationToken) -> CodeAction {
+		languages::resolve_code_action(provider, action, &token)
+	}
+	fn provide_document_formatting_edits(provider: u32, document: TextDocument, options: FormattingOptions, token: CancellationToken) -> Vec<TextEdit> {
+		languages::provide_document_formatting_edits(provider, &document, &options, &token)
+	}
+	fn provide_document_range_formatting_edits(provider: u32, document: TextDocument, range: Range, options: FormattingOptions, token: CancellationToken) -> Vec<TextEdit> {
+		languages::provide_document_range_formatting_edits(provider, &document, &range, &options, &token)
+	}
+	fn provide_on_type_formatting_edits(provider: u32, document: TextDocument, position: Position, ch: String, options: FormattingOptions, token: CancellationToken) -> Vec<TextEdit> {
+		languages::provide_on_type_formatting_edits(provider, &document, &position, &ch, &options, &token)
+	}
+	fn provide_document_semantic_tokens(provider: u32, document: TextDocument, token: CancellationToken) -> Option<SemanticTokens> {
+		languages::provide_document_semantic_tokens(provider, &document, &token)
+	}
+	fn provide_document_semantic_tokens_edits(provider: u32, document: TextDocument, previous_result_id: String, token: CancellationToken) -> Option<SemanticTokensResult> {
+		languages::provide_document_semantic_tokens_edits(provider, &document, &previous_result_id, &token)
+	}
+	fn provide_document_range_semantic_tokens(provider: u32, document: TextDocument, range: Range, token: CancellationToken) -> Option<SemanticTokens> {
+		languages::provide_document_range_semantic_tokens(provider, &document, &range, &token)
+	}
+	fn provide_document_symbols(provider: u32, document: TextDocument, token: CancellationToken) -> Option<Vec<host::api::types::DocumentSymbolNode>> {
+		languages::provide_document_symbols(provider, &document, &token).map(DocumentSymbol::flatten)
+	}
+	fn provide_workspace_symbols(provider: u32, query: String, token: CancellationToken) -> Option<Vec<SymbolInformation>> {
+		languages::provide_workspace_symbols(provider, &query, &token)
+	}
+	fn resolve_workspace_symbol(provider: u32, symbol: SymbolInformation, token: CancellationToken) -> SymbolInformation {
+		languages::resolve_workspace_symbol(provider, symbol, &token)
+	}
+	fn provide_definition(provider: u32, document: TextDocument, position: Position, token: CancellationToken) -> Option<Definition> {
+		languages::provide_definition(provider, &document, &position, &token)
+	}
+	fn provide_declaration(provider: u32, document: TextDocument, position: Position, token: CancellationToken) -> Option<Definition> {
+		languages::provide_declaration(provider, &document, &position, &token)
+	}
+	fn provide_type_definition(provider: u32, document: TextDocument, position: Position, token: CancellationToken) -> Option<Definition> {
+		languages::provide_type_definition(provider, &document, &position, &token)
+	}
+	fn provide_implementation(provider: u32, document: TextDocument, position: Position, token: CancellationToken) -> Option<Definition> {
+		languages::provide_implementation(provider, &document, &position, &token)
+	}
+	fn provide_references(provider: u32, document: TextDocument, position: Position, context: ReferenceContext, token: CancellationToken) -> Option<Vec<Location>> {
+		languages::provide_references(provider, &document, &position, &context, &token)
+	}
+	fn provide_document_highlights(provider: u32, document: TextDocument, position: Position, token: CancellationToken) -> Option<Vec<DocumentHighlight>> {
+		languages::provide_document_highlights(provider, &document, &position, &token)
+	}
+	fn prepare_rename(provider: u32, document: TextDocument, position: Position, token: CancellationToken) -> Option<PrepareRenameResult> {
+		languages::prepare_rename(provider, &document, &position, &token)
+	}
+	fn provide_rename_edits(provider: u32, document: TextDocument, position: Position, new_name: String, token: CancellationToken) -> Option<WorkspaceEdit> {
+		languages::provide_rename_edits(provider, &document, &position, &new_name, &token)
+	}
+	fn provide_folding_ranges(provider: u32, document: TextDocument, token: CancellationToken) -> Option<Vec<FoldingRange>> {
+		languages::provide_folding_ranges(provider, &document, &token)
+	}
+	fn provide_selection_ranges(provider: u32, document: TextDocument, positions: Vec<Position>, token: CancellationToken) -> Option<Vec<Vec<Range>>> {
+		languages::provide_selection_ranges(provider, &document, &positions, &token)
+			.map(|selection_ranges| selection_ranges.into_iter().map(SelectionRange::flatten).collect())
+	}
+	fn provide_inlay_hints(provider: u32, document: TextDocument, range: Range, token: CancellationToken) -> Option<Vec<InlayHint>> {
+		languages::provide_inlay_hints(provider, &document, &range, &token)
+	}
+	fn resolve_inlay_hint(provider: u32, hint: InlayHint, token: CancellationToken) -> InlayHint {
+		languages::resolve_inlay_hint(provider, hint, &token)
+	}
+	fn provide_code_lenses(provider: u32, document: TextDocument, token: CancellationToken) -> Option<Vec<CodeLens>> {
+		languages::provide_code_lenses(provider, &document, &token)
+	}
+	fn resolve_code_lens(provider: u32, lens: CodeLens, token: CancellationToken) -> CodeLens {
+		languages::resolve_code_lens(provider, lens, &token)
+	}
+	fn provide_signature_help(provider: u32, document: TextDocument, position: Position, context: SignatureHelpContext, token: CancellationToken) -> Option<SignatureHelp> {
+		languages::provide_signature_help(provider, &document, &position, &context, &token)
+	}
+	fn prepare_call_hierarchy(provider: u32, document: TextDocument, position: Position, token: CancellationToken) -> Option<Vec<host::api::types::HierarchyItem>> {
+		languages::prepare_call_hierarchy(provider, &document, &position, &token)
+	}
+	fn provide_call_hierarchy_incoming_calls(provider: u32, item: u32, token: CancellationToken) -> Option<Vec<host::api::types::CallHierarchyIncomingCall>> {
+		languages::provide_call_hierarchy_incoming_calls(provider, item, &token)
+	}
+	fn provide_call_hierarchy_outgoing_calls(provider: u32, item: u32, token: CancellationToken) -> Option<Vec<host::api::types::CallHierarchyOutgoingCall>> {
+		languages::provide_call_hierarchy_outgoing_calls(provider, item, &token)
+	}
+	fn prepare_type_hierarchy(provider: u32, document: TextDocument, position: Position, token: CancellationToken) -> Option<Vec<host::api::types::HierarchyItem>> {
+		languages::prepare_type_hierarchy(provider, &document, &position, &token)
+	}
+	fn provide_type_hierarchy_supertypes(provider: u32, item: u32, token: CancellationToken) -> Option<Vec<host::api::types::HierarchyItem>> {
+		languages::provide_type_hierarchy_supertypes(provider, item, &token)
+	}
+	fn provide_type_hierarchy_subtypes(provider: u32, item: u32, token: CancellationToken) -> Option<Vec<host::api::types::HierarchyItem>> {
+		languages::provide_type_hierarchy_subtypes(provider, item, &token)
+	}
+	fn release_hierarchy_item(provider: u32, item: u32) {
+		languages::release_hierarchy_item(provider, item);
+	}
+	fn provide_document_colors(provider: u32, document: TextDocument, token: CancellationToken) -> Option<Vec<ColorInformation>> {
+		languages::provide_document_colors(provider, &document, &token)
+	}
+	fn provide_color_presentations(provider: u32, color: Color, document: TextDocument, range: Range, token: CancellationToken) -> Option<Vec<ColorPresentation>> {
+		languages::provide_color_presentations(provider, &color, &document, &range, &token)
+	}
+	fn provide_document_links(provider: u32, document: TextDocument, token: CancellationToken) -> Option<Vec<DocumentLink>> {
+		languages::provide_document_links(provider, &document, &token)
+	}
+	fn resolve_document_link(provider: u32, link: DocumentLink, token: CancellationToken) -> DocumentLink {
+		languages::resolve_document_link(provider, link, &token)
+	}
+	fn provide_linked_editing_ranges(provider: u32, document: TextDocument, position: Position, token: CancellationToken) -> Option<LinkedEditingRanges> {
+		languages::provide_linked_editing_ranges(provider, &document, &position, &token)
+	}
+	fn provide_inline_completion_items(provider: u32, document: TextDocument, position: Position, context: InlineCompletionContext, token: CancellationToken) -> Option<Vec<InlineCompletionItem>> {
+		languages::provide_inline_completion_items(provider, &document, &position, &context, &token)
+	}
+}
\ No newline at end of file
@@ -0,0 +1,982 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::Serialize;
+
+mod uri;
+pub use uri::*;
+
+use crate::host::api::types::{
+	AutoClosingPair, CodeAction, CodeActionContext, CodeLens, Color, ColorInformation, ColorPresentation, Command, CommentRule, ContextValue, Definition,
+	Diagnostic, DiagnosticCode, DiagnosticCodeValue, DiagnosticCollection, DiagnosticSeverity, DocumentHighlight, DocumentHighlightKind,
+	DocumentLink, DocumentSymbolNode, EnterAction, FoldingRange, FoldingRangeKind, FormattingOptionValue, FormattingOptions,
+	HierarchyItem as HostHierarchyItem, IndentAction, InlayHint, InlayHintKind, InlayHintLabel, InlayHintLabelPart, InlineCompletionItem,
+	LanguageConfiguration, LinkedEditingRanges, Location, LocationLink, OnEnterRule, ParameterInformation, ParameterLabel, Position,
+	PrepareRenameResult, Range, RenameLocation, Selection, SemanticTokens, SemanticTokensLegend, SignatureHelp, SignatureHelpProviderMetadata,
+	SignatureInformation, SymbolInformation, SymbolKind, SymbolTag, TextEdit, Uri, WorkspaceEdit, WorkspaceEditEntry
+};
+
+impl Position {
+	pub fn new(line: u32, character: u32) -> Self {
+		Position { line, character }
+	}
+}
+
+impl Range {
+	pub fn new(start: Position, end: Position) -> Self {
+		Range { start, end }
+	}
+
+	pub fn from_coordinates(start_line: u32, start_character: u32, end_line: u32, end_character: u32) -> Self {
+		Range {
+			start: Position::new(start_line, start_character),
+			end: Position::new(end_line, end_character)
+		}
+	}
+}
+
+impl Selection {
+	pub fn new(anchor: Position, active: Position) -> Self {
+		Selection { anchor, active }
+	}
+
+	/// Whether the active position is before the anchor.
+	pub fn is_reversed(&self) -> bool {
+		(self.active.line, self.active.character) < (self.anchor.line, self.anchor.character)
+	}
+
+	pub fn is_empty(&self) -> bool {
+		(self.anchor.line, self.anchor.character) == (self.active.line, self.active.character)
+	}
+
+	pub fn start(&self) -> Position {
+		if self.is_reversed() { self.active } else { self.anchor }
+	}
+
+	pub fn end(&self) -> Position {
+		if self.is_reversed() { self.anchor } else { self.active }
+	}
+
+	pub fn range(&self) -> Range {
+		Range::new(self.start(), self.end())
+	}
+}
+
+impl Location {
+	pub fn new(uri: &Uri, range: Range) -> Self {
+		Location { uri: uri.clone(), range }
+	}
+}
+
+impl LocationLink {
+	pub fn new(target_uri: &Uri, target_range: Range) -> Self {
+		LocationLink {
+			origin_selection_range: None,
+			target_uri: target_uri.clone(),
+			target_range,
+			target_selection_range: None
+		}
+	}
+}
+
+impl DocumentHighlight {
+	pub fn new(range: Range, kind: DocumentHighlightKind) -> Self {
+		DocumentHighlight { range, kind }
+	}
+}
+
+impl PrepareRenameResult {
+	/// Accepts the rename and lets the editor use the text of `range` as
+	/// the initial name.
+	pub fn range(range: Range) -> Self {
+		PrepareRenameResult::Accepted(RenameLocation { range, placeholder: None })
+	}
+
+	pub fn range_with_placeholder(range: Range, placeholder: &str) -> Self {
+		PrepareRenameResult::Accepted(RenameLocation { range, placeholder: Some(placeholder.to_string()) })
+	}
+
+	/// Rejects the rename. The message is shown to the user.
+	pub fn rejected(message: &str) -> Self {
+		PrepareRenameResult::Rejected(message.to_string())
+	}
+}
+
+impl From<Location> for Definition {
+	fn from(value: Location) -> Self {
+		Definition::Locations(vec![value])
+	}
+}
+
+impl From<Vec<Location>> for Definition {
+	fn from(value: Vec<Location>) -> Self {
+		Definition::Locations(value)
+	}
+}
+
+impl From<Vec<LocationLink>> for Definition {
+	fn from(value: Vec<LocationLink>) -> Self {
+		Definition::Links(value)
+	}
+}
+
+impl Diagnostic {
+	/// Creates a diagnostic without source, code, related information or tags.
+	pub fn new(range: Range, message: &str, severity: DiagnosticSeverity) -> Self {
+		Diagnostic {
+			range,
+			message: message.to_string(),
+			severity,
+			source: None,
+			code: None,
+			related_information: Vec::new(),
+			tags: Vec::new()
+		}
+	}
+}
+
+impl From<&str> for DiagnosticCode {
+	fn from(value: &str) -> Self {
+		DiagnosticCode { value: DiagnosticCodeValue::Text(value.to_string()), target: None }
+	}
+}
+
+impl From<i32> for DiagnosticCode {
+	fn from(value: i32) -> Self {
+		DiagnosticCode { value: DiagnosticCodeValue::Number(value), target: None }
+	}
+}
+
+impl DiagnosticCollection {
+	/// Iterates over all entries of the collection.
+	pub fn for_each<F>(&self, mut callback: F)
+	where
+		F: FnMut(&Uri, &[Diagnostic]),
+	{
+		for entry in self.entries() {
+			callback(&entry.uri, &entry.diagnostics);
+		}
+	}
+}
+
+impl TextEdit {
+	pub fn replace(range: Range, new_text: &str) -> Self {
+		TextEdit { range, new_text: new_text.to_string() }
+	}
+
+	pub fn insert(position: Position, new_text: &str) -> Self {
+		TextEdit::replace(Range::new(position, position), new_text)
+	}
+
+	pub fn delete(range: Range) -> Self {
+		TextEdit::replace(range, "")
+	}
+}
+
+/// Collects the edits of a text editor command. They are applied to the
+/// editor in one atomic operation once the command returns.
+pub struct TextEditorEdit {
+	edits: Vec<TextEdit>
+}
+
+impl TextEditorEdit {
+	pub(crate) fn new() -> Self {
+		TextEditorEdit { edits: Vec::new() }
+	}
+
+	pub fn replace(&mut self, range: Range, value: &str) {
+		self.edits.push(TextEdit::replace(range, value));
+	}
+
+	pub fn insert(&mut self, position: Position, value: &str) {
+		self.edits.push(TextEdit::insert(position, value));
+	}
+
+	pub fn delete(&mut self, range: Range) {
+		self.edits.push(TextEdit::delete(range));
+	}
+
+	pub(crate) fn into_edits(self) -> Vec<TextEdit> {
+		self.edits
+	}
+}
+
+impl Default for WorkspaceEdit {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl WorkspaceEdit {
+	pub fn new() -> Self {
+		WorkspaceEdit { entries: Vec::new() }
+	}
+
+	/// Sets the text edits for the given resource, replacing the edits that
+	/// were added for it before.
+	pub fn set(&mut self, uri: &Uri, edits: Vec<TextEdit>) {
+		match self.entries.iter_mut().find(|entry| entry.uri == *uri) {
+			Some(entry) => entry.edits = edits,
+			None => self.entries.push(WorkspaceEditEntry { uri: uri.clone(), edits })
+		}
+	}
+
+	pub fn replace(&mut self, uri: &Uri, range: Range, new_text: &str) {
+		self.push_edit(uri, TextEdit::replace(range, new_text));
+	}
+
+	pub fn insert(&mut self, uri: &Uri, position: Position, new_text: &str) {
+		self.push_edit(uri, TextEdit::insert(position, new_text));
+	}
+
+	pub fn delete(&mut self, uri: &Uri, range: Range) {
+		self.push_edit(uri, TextEdit::delete(range));
+	}
+
+	pub fn has(&self, uri: &Uri) -> bool {
+		self.entries.iter().any(|entry| entry.uri == *uri)
+	}
+
+	pub fn size(&self) -> usize {
+		self.entries.len()
+	}
+
+	/// Edits for the same resource are collected in one entry.
+	fn push_edit(&mut self, uri: &Uri, edit: TextEdit) {
+		match self.entries.iter_mut().find(|entry| entry.uri == *uri) {
+			Some(entry) => entry.edits.push(edit),
+			None => self.entries.push(WorkspaceEditEntry { uri: uri.clone(), edits: vec![edit] })
+		}
+	}
+}
+
+impl Command {
+	pub fn new(title: &str, command: &str) -> Self {
+		Command { title: title.to_string(), command: command.to_string(), tooltip: None, arguments: None }
+	}
+
+	/// Sets the arguments the command is executed with. They must serialize
+	/// to a JSON array, e.g. a tuple or a `Vec`.
+	pub fn set_arguments<T>(&mut self, arguments: &T) -> Result<(), Error>
+	where
+		T: Serialize,
+	{
+		let value = serde_json::to_value(arguments)?;
+		if !value.is_array() {
+			return Err(Error::new("Command arguments must serialize to a JSON array"));
+		}
+		self.arguments = Some(value.to_string());
+		Ok(())
+	}
+}
+
+impl From<bool> for ContextValue {
+	fn from(value: bool) -> Self {
+		ContextValue::Boolean(value)
+	}
+}
+
+impl From<f64> for ContextValue {
+	fn from(value: f64) -> Self {
+		ContextValue::Number(value)
+	}
+}
+
+impl From<i32> for ContextValue {
+	fn from(value: i32) -> Self {
+		ContextValue::Number(value.into())
+	}
+}
+
+impl From<u32> for ContextValue {
+	fn from(value: u32) -> Self {
+		ContextValue::Number(value.into())
+	}
+}
+
+impl From<&str> for ContextValue {
+	fn from(value: &str) -> Self {
+		ContextValue::Text(value.to_string())
+	}
+}
+
+impl From<String> for ContextValue {
+	fn from(value: String) -> Self {
+		ContextValue::Text(value)
+	}
+}
+
+impl From<&[&str]> for ContextValue {
+	fn from(value: &[&str]) -> Self {
+		ContextValue::TextList(value.iter().map(|value| value.to_string()).collect())
+	}
+}
+
+impl From<Vec<String>> for ContextValue {
+	fn from(value: Vec<String>) -> Self {
+		ContextValue::TextList(value)
+	}
+}
+
+/// An error reported back to VS Code, e.g. by a command. VS Code shows the
+/// message to the user.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error {
+	message: String
+}
+
+impl Error {
+	pub fn new(message: &str) -> Self {
+		Error { message: message.to_string() }
+	}
+
+	pub fn message(&self) -> &str {
+		&self.message
+	}
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.message)
+	}
+}
+
+impl std::error::Error for Error {}
+
+impl From<serde_json::Error> for Error {
+	fn from(value: serde_json::Error) -> Self {
+		Error { message: value.to_string() }
+	}
+}
+
+/// The kind of a code action. Kinds are a hierarchical list of identifiers
+/// separated by `.`, e.g. `"refactor.extract.function"`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CodeActionKind {
+	value: String
+}
+
+impl CodeActionKind {
+	const SEPARATOR: &'static str = ".";
+
+	pub fn new(value: &str) -> Self {
+		CodeActionKind { value: value.to_string() }
+	}
+
+	pub fn empty() -> Self {
+		CodeActionKind::new("")
+	}
+
+	pub fn quick_fix() -> Self {
+		CodeActionKind::new("quickfix")
+	}
+
+	pub fn refactor() -> Self {
+		CodeActionKind::new("refactor")
+	}
+
+	pub fn refactor_extract() -> Self {
+		CodeActionKind::new("refactor.extract")
+	}
+
+	pub fn refactor_inline() -> Self {
+		CodeActionKind::new("refactor.inline")
+	}
+
+	pub fn refactor_move() -> Self {
+		CodeActionKind::new("refactor.move")
+	}
+
+	pub fn refactor_rewrite() -> Self {
+		CodeActionKind::new("refactor.rewrite")
+	}
+
+	pub fn source() -> Self {
+		CodeActionKind::new("source")
+	}
+
+	pub fn source_organize_imports() -> Self {
+		CodeActionKind::new("source.organizeImports")
+	}
+
+	pub fn source_fix_all() -> Self {
+		CodeActionKind::new("source.fixAll")
+	}
+
+	pub fn notebook() -> Self {
+		CodeActionKind::new("notebook")
+	}
+
+	pub fn value(&self) -> &str {
+		&self.value
+	}
+
+	/// Creates a new kind by appending a more specific selector.
+	pub fn append(&self, part: &str) -> Self {
+		if self.value.is_empty() {
+			CodeActionKind::new(part)
+		} else {
+			CodeActionKind { value: format!("{}{}{}", self.value, Self::SEPARATOR, part) }
+		}
+	}
+
+	/// Checks if `other` is a sub-kind of this kind, e.g. `refactor.extract`
+	/// is contained in `refactor`.
+	pub fn contains(&self, other: &CodeActionKind) -> bool {
+		self.value.is_empty() || self.value == other.value || other.value.starts_with(&format!("{}{}", self.value, Self::SEPARATOR))
+	}
+
+	/// Checks if this kind intersects `other`, i.e. one contains the other.
+	pub fn intersects(&self, other: &CodeActionKind) -> bool {
+		self.contains(other) || other.contains(self)
+	}
+}
+
+impl From<&str> for CodeActionKind {
+	fn from(value: &str) -> Self {
+		CodeActionKind::new(value)
+	}
+}
+
+impl CodeAction {
+	pub fn new(title: &str, kind: Option<&CodeActionKind>) -> Self {
+		CodeAction {
+			title: title.to_string(),
+			kind: kind.map(|kind| kind.value().to_string()),
+			diagnostics: Vec::new(),
+			edit: None,
+			command: None,
+			is_preferred: false,
+			disabled: None
+		}
+	}
+
+	pub fn kind(&self) -> Option<CodeActionKind> {
+		self.kind.as_deref().map(CodeActionKind::new)
+	}
+}
+
+impl CodeActionContext {
+	/// The kind of actions requested. `None` means all kinds.
+	pub fn only(&self) -> Option<CodeActionKind> {
+		self.only.as_deref().map(CodeActionKind::new)
+	}
+}
+
+impl FormattingOptions {
+	/// Returns the value of an additional formatting property like
+	/// `trimTrailingWhitespace` or `insertFinalNewline`.
+	pub fn get(&self, key: &str) -> Option<&FormattingOptionValue> {
+		self.properties.iter().find(|property| property.key == key).map(|property| &property.value)
+	}
+
+	pub fn get_bool(&self, key: &str) -> Option<bool> {
+		match self.get(key) {
+			Some(FormattingOptionValue::Boolean(value)) => Some(*value),
+			_ => None
+		}
+	}
+}
+
+impl SemanticTokensLegend {
+	pub fn new(token_types: &[&str], token_modifiers: &[&str]) -> Self {
+		SemanticTokensLegend {
+			token_types: token_types.iter().map(|value| value.to_string()).collect(),
+			token_modifiers: token_modifiers.iter().map(|value| value.to_string()).collect()
+		}
+	}
+}
+
+impl SemanticTokens {
+	pub fn new(data: Vec<u32>, result_id: Option<&str>) -> Self {
+		SemanticTokens { result_id: result_id.map(|value| value.to_string()), data }
+	}
+}
+
+/// Collects tokens at absolute positions and encodes them in the relative
+/// format the editor expects. Each token takes five numbers: the line delta,
+/// the start character (relative to the previous token if on the same line),
+/// the length, the token type index and the token modifier bit set.
+pub struct SemanticTokensBuilder {
+	token_types: HashMap<String, u32>,
+	token_modifiers: HashMap<String, u32>,
+	tokens: Vec<[u32; 5]>,
+	sorted: bool
+}
+
+impl SemanticTokensBuilder {
+	/// Creates a builder. The legend is only needed to push tokens by name.
+	pub fn new(legend: Option<&SemanticTokensLegend>) -> Self {
+		let mut token_types = HashMap::new();
+		let mut token_modifiers = HashMap::new();
+		if let Some(legend) = legend {
+			for (index, token_type) in legend.token_types.iter().enumerate() {
+				token_types.insert(token_type.clone(), index as u32);
+			}
+			for (index, token_modifier) in legend.token_modifiers.iter().enumerate() {
+				token_modifiers.insert(token_modifier.clone(), index as u32);
+			}
+		}
+		SemanticTokensBuilder { token_types, token_modifiers, tokens: Vec::new(), sorted: true }
+	}
+
+	/// Adds a token at an absolute position. Tokens may be pushed in any
+	/// order; they are sorted when the result is built.
+	pub fn push(&mut self, line: u32, character: u32, length: u32, token_type: u32, token_modifiers: u32) {
+		if let Some(last) = self.tokens.last() {
+			if line < last[0] || (line == last[0] && character < last[1]) {
+				self.sorted = false;
+			}
+		}
+		self.tokens.push([line, character, length, token_type, token_modifiers]);
+	}
+
+	/// Adds a token using the names from the legend. Returns `false` and
+	/// skips the token if the range spans multiple lines, ends before it
+	/// starts or if the type or one of the modifiers is not part of the
+	/// legend.
+	pub fn push_range(&mut self, range: &Range, token_type: &str, token_modifiers: &[&str]) -> bool {
+		if range.start.line != range.end.line || range.end.character < range.start.character {
+			return false;
+		}
+		let Some(&token_type) = self.token_types.get(token_type) else {
+			return false;
+		};
+		let mut modifiers = 0;
+		for token_modifier in token_modifiers {
+			match self.token_modifiers.get(*token_modifier) {
+				Some(index) if *index < 32 => modifiers |= 1 << index,
+				_ => return false
+			}
+		}
+		self.push(range.start.line, range.start.character, range.end.character - range.start.character, token_type, modifiers);
+		true
+	}
+
+	pub fn build(&self, result_id: Option<&str>) -> SemanticTokens {
+		let mut tokens = self.tokens.clone();
+		if !self.sorted {
+			tokens.sort_by_key(|token| (token[0], token[1]));
+		}
+		let mut data = Vec::with_capacity(tokens.len() * 5);
+		let mut previous_line = 0;
+		let mut previous_character = 0;
+		for [line, character, length, token_type, token_modifiers] in tokens {
+			let delta_line = line - previous_line;
+			let delta_character = if delta_line == 0 { character - previous_character } else { character };
+			data.extend_from_slice(&[delta_line, delta_character, length, token_type, token_modifiers]);
+			previous_line = line;
+			previous_character = character;
+		}
+		SemanticTokens::new(data, result_id)
+	}
+}
+
+/// A symbol in a document, like a class or a function, together with the
+/// symbols it contains.
+#[derive(Clone, Debug)]
+pub struct DocumentSymbol {
+	pub name: String,
+	pub detail: String,
+	pub kind: SymbolKind,
+	pub tags: Vec<SymbolTag>,
+	/// The full range of the symbol, including leading comments and the body.
+	pub range: Range,
+	/// The range to select when the symbol is revealed, e.g. its name.
+	pub selection_range: Range,
+	pub children: Vec<DocumentSymbol>
+}
+
+impl DocumentSymbol {
+	pub fn new(name: &str, detail: &str, kind: SymbolKind, range: Range, selection_range: Range) -> Self {
+		DocumentSymbol {
+			name: name.to_string(),
+			detail: detail.to_string(),
+			kind,
+			tags: Vec::new(),
+			range,
+			selection_range,
+			children: Vec::new()
+		}
+	}
+
+	/// Flattens symbol trees into the list sent to the host.
+	pub(crate) fn flatten(symbols: Vec<DocumentSymbol>) -> Vec<DocumentSymbolNode> {
+		let mut result = Vec::new();
+		let mut stack: Vec<(DocumentSymbol, Option<u32>)> = symbols.into_iter().rev().map(|symbol| (symbol, None)).collect();
+		while let Some((symbol, parent)) = stack.pop() {
+			let index = result.len() as u32;
+			result.push(DocumentSymbolNode {
+				name: symbol.name,
+				detail: symbol.detail,
+				kind: symbol.kind,
+				tags: symbol.tags,
+				range: symbol.range,
+				selection_range: symbol.selection_range,
+				parent
+			});
+			stack.extend(symbol.children.into_iter().rev().map(|child| (child, Some(index))));
+		}
+		result
+	}
+}
+
+impl SymbolInformation {
+	pub fn new(name: &str, kind: SymbolKind, container_name: Option<&str>, location: Location) -> Self {
+		SymbolInformation {
+			name: name.to_string(),
+			container_name: container_name.map(|value| value.to_string()),
+			kind,
+			tags: Vec::new(),
+			location
+		}
+	}
+}
+
+impl FoldingRange {
+	/// Creates a folding range from `start` to `end`, both zero-based lines.
+	pub fn new(start: u32, end: u32, kind: Option<FoldingRangeKind>) -> Self {
+		FoldingRange { start, end, kind }
+	}
+}
+
+impl InlayHint {
+	/// Creates a hint without tooltip, padding or text edits.
+	pub fn new(position: Position, label: InlayHintLabel, kind: Option<InlayHintKind>) -> Self {
+		InlayHint {
+			position,
+			label,
+			tooltip: None,
+			kind,
+			padding_left: false,
+			padding_right: false,
+			text_edits: Vec::new()
+		}
+	}
+}
+
+impl InlayHintLabelPart {
+	pub fn new(value: &str) -> Self {
+		InlayHintLabelPart { value: value.to_string(), tooltip: None, location: None, command: None }
+	}
+}
+
+impl From<&str> for InlayHintLabel {
+	fn from(value: &str) -> Self {
+		InlayHintLabel::Text(value.to_string())
+	}
+}
+
+impl From<Vec<InlayHintLabelPart>> for InlayHintLabel {
+	fn from(value: Vec<InlayHintLabelPart>) -> Self {
+		InlayHintLabel::Parts(value)
+	}
+}
+
+impl CodeLens {
+	/// Creates a code lens. A lens without a command is resolved later.
+	pub fn new(range: Range, command: Option<Command>) -> Self {
+		CodeLens { range, command }
+	}
+}
+
+impl SignatureHelp {
+	pub fn new(signatures: Vec<SignatureInformation>, active_signature: u32, active_parameter: u32) -> Self {
+		SignatureHelp { signatures, active_signature, active_parameter }
+	}
+}
+
+impl SignatureInformation {
+	pub fn new(label: &str, documentation: Option<&str>, parameters: Vec<ParameterInformation>) -> Self {
+		SignatureInformation {
+			label: label.to_string(),
+			documentation: documentation.map(|value| value.to_string()),
+			parameters,
+			active_parameter: None
+		}
+	}
+}
+
+impl ParameterInformation {
+	/// Creates a parameter. The label is either a substring of the signature
+	/// label or the start and end offsets of the parameter in it.
+	pub fn new(label: impl Into<ParameterLabel>, documentation: Option<&str>) -> Self {
+		ParameterInformation {
+			label: label.into(),
+			documentation: documentation.map(|value| value.to_string())
+		}
+	}
+}
+
+impl From<&str> for ParameterLabel {
+	fn from(value: &str) -> Self {
+		ParameterLabel::Text(value.to_string())
+	}
+}
+
+impl From<(u32, u32)> for ParameterLabel {
+	fn from(value: (u32, u32)) -> Self {
+		ParameterLabel::Offsets(value)
+	}
+}
+
+impl SignatureHelpProviderMetadata {
+	pub fn new(trigger_characters: &[&str], retrigger_characters: &[&str]) -> Self {
+		SignatureHelpProviderMetadata {
+			trigger_characters: trigger_characters.iter().map(|value| value.to_string()).collect(),
+			retrigger_characters: retrigger_characters.iter().map(|value| value.to_string()).collect()
+		}
+	}
+}
+
+impl Color {
+	/// Creates a color from components in the range `[0, 1]`.
+	pub fn new(red: f32, green: f32, blue: f32, alpha: f32) -> Self {
+		Color { red, green, blue, alpha }
+	}
+}
+
+impl ColorInformation {
+	pub fn new(range: Range, color: Color) -> Self {
+		ColorInformation { range, color }
+	}
+}
+
+impl ColorPresentation {
+	pub fn new(label: &str) -> Self {
+		ColorPresentation { label: label.to_string(), text_edit: None, additional_text_edits: Vec::new() }
+	}
+}
+
+impl DocumentLink {
+	/// Creates a link. A link without a target is resolved later.
+	pub fn new(range: Range, target: Option<&Uri>) -> Self {
+		DocumentLink { range, target: target.cloned(), tooltip: None }
+	}
+}
+
+impl LinkedEditingRanges {
+	/// Creates linked editing ranges. The optional word pattern is a
+	/// JavaScript regular expression source that describes valid contents.
+	pub fn new(ranges: Vec<Range>, word_pattern: Option<&str>) -> Self {
+		LinkedEditingRanges { ranges, word_pattern: word_pattern.map(|value| value.to_string()) }
+	}
+}
+
+impl InlineCompletionItem {
+	/// Creates an item that inserts `insert_text` as ghost text. Without a
+	/// range the text replaces the word at the cursor.
+	pub fn new(insert_text: &str, range: Option<Range>) -> Self {
+		InlineCompletionItem { insert_text: insert_text.to_string(), filter_text: None, range, command: None }
+	}
+}
+
+impl Default for LanguageConfiguration {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl LanguageConfiguration {
+	/// Creates an empty configuration. Fill in the parts the language needs.
+	pub fn new() -> Self {
+		LanguageConfiguration {
+			comments: None,
+			brackets: Vec::new(),
+			auto_closing_pairs: Vec::new(),
+			word_pattern: None,
+			indentation_rules: None,
+			on_enter_rules: Vec::new()
+		}
+	}
+}
+
+impl CommentRule {
+	pub fn new(line_comment: Option<&str>, block_comment: Option<(&str, &str)>) -> Self {
+		CommentRule {
+			line_comment: line_comment.map(|value| value.to_string()),
+			block_comment: block_comment.map(|(start, end)| (start.to_string(), end.to_string()))
+		}
+	}
+}
+
+impl AutoClosingPair {
+	pub fn new(open: &str, close: &str) -> Self {
+		AutoClosingPair { open: open.to_string(), close: close.to_string(), not_in: Vec::new() }
+	}
+}
+
+impl EnterAction {
+	pub fn new(indent_action: IndentAction) -> Self {
+		EnterAction { indent_action, append_text: None, remove_text: None }
+	}
+}
+
+impl OnEnterRule {
+	/// Creates a rule that applies `action` when the text before the cursor
+	/// matches the `before_text` pattern.
+	pub fn new(before_text: &str, action: EnterAction) -> Self {
+		OnEnterRule { before_text: before_text.to_string(), after_text: None, previous_line_text: None, action }
+	}
+}
+
+/// An item in a call or type hierarchy. `data` stays on the guest side and
+/// identifies the item when the editor asks for its calls or related types.
+#[derive(Clone, Debug)]
+pub struct HierarchyItem<T> {
+	pub name: String,
+	pub kind: SymbolKind,
+	pub tags: Vec<SymbolTag>,
+	pub detail: Option<String>,
+	pub uri: Uri,
+	pub range: Range,
+	pub selection_range: Range,
+	pub data: T
+}
+
+pub type CallHierarchyItem<T> = HierarchyItem<T>;
+pub type TypeHierarchyItem<T> = HierarchyItem<T>;
+
+impl<T> HierarchyItem<T> {
+	pub fn new(name: &str, kind: SymbolKind, uri: &Uri, range: Range, selection_range: Range, data: T) -> Self {
+		HierarchyItem {
+			name: name.to_string(),
+			kind,
+			tags: Vec::new(),
+			detail: None,
+			uri: uri.clone(),
+			range,
+			selection_range,
+			data
+		}
+	}
+
+	pub(crate) fn to_host(&self, handle: u32) -> HostHierarchyItem {
+		HostHierarchyItem {
+			handle,
+			name: self.name.clone(),
+			kind: self.kind,
+			tags: self.tags.clone(),
+			detail: self.detail.clone(),
+			uri: self.uri.clone(),
+			range: self.range,
+			selection_range: self.selection_range
+		}
+	}
+}
+
+#[derive(Clone, Debug)]
+pub struct CallHierarchyIncomingCall<T> {
+	/// The item that makes the call.
+	pub from: CallHierarchyItem<T>,
+	/// The ranges of the calls inside `from`.
+	pub from_ranges: Vec<Range>
+}
+
+#[derive(Clone, Debug)]
+pub struct CallHierarchyOutgoingCall<T> {
+	/// The item that is called.
+	pub to: CallHierarchyItem<T>,
+	/// The ranges of the calls inside the item the calls were requested for.
+	pub from_ranges: Vec<Range>
+}
+
+/// A range around the cursor that the user can select, together with the
+/// range that contains it.
+#[derive(Clone, Debug)]
+pub struct SelectionRange {
+	pub range: Range,
+	pub parent: Option<Box<SelectionRange>>
+}
+
+impl SelectionRange {
+	pub fn new(range: Range, parent: Option<SelectionRange>) -> Self {
+		SelectionRange { range, parent: parent.map(Box::new) }
+	}
+
+	/// Flattens the chain into the list sent to the host.
+	pub(crate) fn flatten(self) -> Vec<Range> {
+		let mut result = Vec::new();
+		let mut current = Some(Box::new(self));
+		while let Some(selection_range) = current {
+			result.push(selection_range.range);
+			current = selection_range.parent;
+		}
+		result
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn legend() -> SemanticTokensLegend {
+		SemanticTokensLegend::new(&["keyword", "variable"], &["declaration", "readonly"])
+	}
+
+	#[test]
+	fn semantic_tokens_are_delta_encoded() {
+		let mut builder = SemanticTokensBuilder::new(None);
+		builder.push(1, 4, 3, 0, 0);
+		builder.push(1, 10, 5, 1, 2);
+		builder.push(3, 2, 4, 1, 0);
+		let tokens = builder.build(Some("1"));
+		assert_eq!(tokens.result_id.as_deref(), Some("1"));
+		assert_eq!(tokens.data, vec![
+			1, 4, 3, 0, 0,
+			0, 6, 5, 1, 2,
+			2, 2, 4, 1, 0
+		]);
+	}
+
+	#[test]
+	fn semantic_tokens_are_sorted_before_encoding() {
+		let mut builder = SemanticTokensBuilder::new(None);
+		builder.push(3, 2, 4, 1, 0);
+		builder.push(1, 10, 5, 1, 2);
+		builder.push(1, 4, 3, 0, 0);
+		assert_eq!(builder.build(None).data, vec![
+			1, 4, 3, 0, 0,
+			0, 6, 5, 1, 2,
+			2, 2, 4, 1, 0
+		]);
+	}
+
+	#[test]
+	fn semantic_token_ranges_use_the_legend() {
+		let legend = legend();
+		let mut builder = SemanticTokensBuilder::new(Some(&legend));
+		assert!(builder.push_range(&Range::from_coordinates(2, 4, 2, 9), "variable", &["declaration", "readonly"]));
+		assert_eq!(builder.build(None).data, vec![2, 4, 5, 1, 3]);
+	}
+
+	#[test]
+	fn semantic_token_ranges_are_rejected() {
+		let legend = legend();
+		let mut builder = SemanticTokensBuilder::new(Some(&legend));
+		assert!(!builder.push_range(&Range::from_coordinates(1, 0, 2, 3), "keyword", &[]));
+		assert!(!builder.push_range(&Range::from_coordinates(1, 5, 1, 2), "keyword", &[]));
+		assert!(!builder.push_range(&Range::from_coordinates(1, 0, 1, 2), "function", &[]));
+		assert!(!builder.push_range(&Range::from_coordinates(1, 0, 1, 2), "keyword", &["static"]));
+		assert!(!SemanticTokensBuilder::new(None).push_range(&Range::from_coordinates(1, 0, 1, 2), "keyword", &[]));
+		assert!(builder.build(None).data.is_empty());
+	}
+
+	#[test]
+	fn workspace_edit_set_replaces_edits() {
+		let uri = Uri::file("/a.txt");
+		let mut edit = WorkspaceEdit::new();
+		edit.insert(&uri, Position::new(0, 0), "a");
+		edit.delete(&uri, Range::from_coordinates(1, 0, 1, 2));
+		assert_eq!(edit.entries[0].edits.len(), 2);
+		edit.set(&uri, vec![TextEdit::insert(Position::new(2, 0), "b")]);
+		assert_eq!(edit.size(), 1);
+		assert_eq!(edit.entries[0].edits.len(), 1);
+		assert_eq!(edit.entries[0].edits[0].new_text, "b");
+	}
+}
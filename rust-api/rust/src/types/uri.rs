@@ -0,0 +1,506 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+use std::fmt;
+use std::hash::{ Hash, Hasher };
+
+use serde::{ Deserialize, Deserializer };
+
+use crate::host::api::types::Uri;
+use super::Error;
+
+// The implementation follows `vs/base/common/uri.ts` so that a uri converts
+// to the same string as its `vscode.Uri` counterpart. Paths are treated as
+// posix paths since the guest doesn't know the platform of the host.
+
+/// The parts of a uri to change with `Uri::with`. `None` keeps the part.
+#[derive(Clone, Debug, Default)]
+pub struct UriChange<'a> {
+	pub scheme: Option<&'a str>,
+	pub authority: Option<&'a str>,
+	pub path: Option<&'a str>,
+	pub query: Option<&'a str>,
+	pub fragment: Option<&'a str>
+}
+
+impl Uri {
+	/// Creates a uri from its components, like `vscode.Uri.from`.
+	pub fn from_components(scheme: &str, authority: &str, path: &str, query: &str, fragment: &str) -> Result<Self, Error> {
+		Uri::create(scheme, authority, path, query, fragment)
+	}
+
+	/// Parses a uri from its string representation, e.g. `http://www.example.com/some/path`.
+	/// A missing scheme defaults to `file`.
+	pub fn parse(value: &str) -> Result<Self, Error> {
+		let (scheme, rest) = match value.find([':', '/', '?', '#']) {
+			Some(index) if index > 0 && value[index..].starts_with(':') => (&value[..index], &value[index + 1..]),
+			_ => ("", value)
+		};
+		let (authority, rest) = match rest.strip_prefix("//") {
+			Some(rest) => {
+				let end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+				(&rest[..end], &rest[end..])
+			}
+			None => ("", rest)
+		};
+		let (rest, fragment) = match rest.find('#') {
+			Some(index) => (&rest[..index], &rest[index + 1..]),
+			None => (rest, "")
+		};
+		let (path, query) = match rest.find('?') {
+			Some(index) => (&rest[..index], &rest[index + 1..]),
+			None => (rest, "")
+		};
+		Uri::create(scheme, &percent_decode(authority), &percent_decode(path), &percent_decode(query), &percent_decode(fragment))
+	}
+
+	/// Creates a `file` uri from a file system path, e.g. `/home/user/file.txt`.
+	/// A path starting with `//` is a UNC path and its host becomes the
+	/// authority.
+	pub fn file(path: &str) -> Self {
+		let (authority, path) = match path.strip_prefix("//") {
+			Some(rest) => match rest.find('/') {
+				Some(index) => (&rest[..index], &rest[index..]),
+				None => (rest, "/")
+			},
+			None => ("", path)
+		};
+		Uri {
+			scheme: "file".to_string(),
+			authority: authority.to_string(),
+			path: resolve_path("file", path),
+			query: String::new(),
+			fragment: String::new()
+		}
+	}
+
+	/// Joins the path of the uri with the path fragments and normalizes the
+	/// result, e.g. `..` segments are resolved.
+	pub fn join_path(&self, fragments: &[&str]) -> Result<Self, Error> {
+		if self.path.is_empty() {
+			return Err(Error::new("[UriError]: cannot call joinPath on URI without path"));
+		}
+		let mut path = self.path.clone();
+		for fragment in fragments.iter().filter(|fragment| !fragment.is_empty()) {
+			path.push('/');
+			path.push_str(fragment);
+		}
+		self.with(UriChange { path: Some(&normalize_path(&path)), ..Default::default() })
+	}
+
+	/// Returns a uri with the given parts changed.
+	pub fn with(&self, change: UriChange) -> Result<Self, Error> {
+		Uri::create(
+			change.scheme.unwrap_or(&self.scheme),
+			change.authority.unwrap_or(&self.authority),
+			change.path.unwrap_or(&self.path),
+			change.query.unwrap_or(&self.query),
+			change.fragment.unwrap_or(&self.fragment)
+		)
+	}
+
+	/// The file system path of the uri. Unlike `path` it takes the authority
+	/// of UNC paths into account and lower cases drive letters.
+	pub fn fs_path(&self) -> String {
+		let path = self.path.as_bytes();
+		if !self.authority.is_empty() && self.path.len() > 1 && self.scheme == "file" {
+			format!("//{}{}", self.authority, self.path)
+		} else if path.len() >= 3 && path[0] == b'/' && path[1].is_ascii_alphabetic() && path[2] == b':' {
+			format!("{}{}", (path[1] as char).to_ascii_lowercase(), &self.path[2..])
+		} else {
+			self.path.clone()
+		}
+	}
+
+	/// Returns the string representation of the uri. Unless `skip_encoding`
+	/// is set all parts are percent encoded, matching `vscode.Uri#toString`.
+	pub fn to_string_with(&self, skip_encoding: bool) -> String {
+		let encode = |value: &str, is_path: bool, is_authority: bool| {
+			if skip_encoding { encode_minimal(value) } else { encode_component(value, is_path, is_authority) }
+		};
+		let mut result = String::new();
+		if !self.scheme.is_empty() {
+			result.push_str(&self.scheme);
+			result.push(':');
+		}
+		if !self.authority.is_empty() || self.scheme == "file" {
+			result.push_str("//");
+		}
+		if !self.authority.is_empty() {
+			let mut authority = self.authority.as_str();
+			if let Some(index) = authority.find('@') {
+				let user_info = &authority[..index];
+				authority = &authority[index + 1..];
+				match user_info.rfind(':') {
+					Some(index) => {
+						result.push_str(&encode(&user_info[..index], false, false));
+						result.push(':');
+						result.push_str(&encode(&user_info[index + 1..], false, true));
+					}
+					None => result.push_str(&encode(user_info, false, false))
+				}
+				result.push('@');
+			}
+			let authority = authority.to_lowercase();
+			match authority.rfind(':') {
+				Some(index) => {
+					result.push_str(&encode(&authority[..index], false, true));
+					result.push_str(&authority[index..]);
+				}
+				None => result.push_str(&encode(&authority, false, true))
+			}
+		}
+		if !self.path.is_empty() {
+			result.push_str(&encode(&lower_case_drive_letter(&self.path), true, false));
+		}
+		if !self.query.is_empty() {
+			result.push('?');
+			result.push_str(&encode(&self.query, false, false));
+		}
+		if !self.fragment.is_empty() {
+			result.push('#');
+			if skip_encoding {
+				result.push_str(&self.fragment);
+			} else {
+				result.push_str(&encode_component(&self.fragment, false, false));
+			}
+		}
+		result
+	}
+
+	/// An empty scheme defaults to `file`.
+	fn create(scheme: &str, authority: &str, path: &str, query: &str, fragment: &str) -> Result<Self, Error> {
+		let scheme = if scheme.is_empty() { "file" } else { scheme };
+		let uri = Uri {
+			scheme: scheme.to_string(),
+			authority: authority.to_string(),
+			path: resolve_path(scheme, path),
+			query: query.to_string(),
+			fragment: fragment.to_string()
+		};
+		uri.validate()?;
+		Ok(uri)
+	}
+
+	fn validate(&self) -> Result<(), Error> {
+		let mut chars = self.scheme.chars();
+		let valid_start = chars.next().is_some_and(|c| c.is_ascii_alphanumeric() || c == '_');
+		if !valid_start || !chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '+' | '.' | '-')) {
+			return Err(Error::new("[UriError]: Scheme contains illegal characters."));
+		}
+		if !self.path.is_empty() {
+			if !self.authority.is_empty() {
+				if !self.path.starts_with('/') {
+					return Err(Error::new("[UriError]: If a URI contains an authority component, then the path component must either be empty or begin with a slash (\"/\") character"));
+				}
+			} else if self.path.starts_with("//") {
+				return Err(Error::new("[UriError]: If a URI does not contain an authority component, then the path cannot begin with two slash characters (\"//\")"));
+			}
+		}
+		Ok(())
+	}
+}
+
+impl PartialEq for Uri {
+	fn eq(&self, other: &Self) -> bool {
+		self.scheme == other.scheme && self.authority == other.authority && self.path == other.path && self.query == other.query && self.fragment == other.fragment
+	}
+}
+
+impl Eq for Uri {}
+
+impl Hash for Uri {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.scheme.hash(state);
+		self.authority.hash(state);
+		self.path.hash(state);
+		self.query.hash(state);
+		self.fragment.hash(state);
+	}
+}
+
+impl fmt::Display for Uri {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.to_string_with(false))
+	}
+}
+
+/// The JSON form of a `vscode.Uri`, which leaves out empty parts.
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct UriComponents {
+	scheme: String,
+	authority: String,
+	path: String,
+	query: String,
+	fragment: String
+}
+
+/// Deserializes a `vscode.Uri` the host passed as JSON, e.g. the result of a
+/// command. Like `URI.revive` the parts are taken as they are.
+impl<'de> Deserialize<'de> for Uri {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let components = UriComponents::deserialize(deserializer)?;
+		Ok(Uri {
+			scheme: components.scheme,
+			authority: components.authority,
+			path: components.path,
+			query: components.query,
+			fragment: components.fragment
+		})
+	}
+}
+
+/// The paths of `http`, `https` and `file` uris are always absolute.
+fn resolve_path(scheme: &str, path: &str) -> String {
+	match scheme {
+		"https" | "http" | "file" if path.is_empty() => "/".to_string(),
+		"https" | "http" | "file" if !path.starts_with('/') => format!("/{}", path),
+		_ => path.to_string()
+	}
+}
+
+/// Lower cases windows drive letters in `/C:/path` or `C:/path`.
+fn lower_case_drive_letter(path: &str) -> String {
+	let bytes = path.as_bytes();
+	if bytes.len() >= 3 && bytes[0] == b'/' && bytes[2] == b':' {
+		if bytes[1].is_ascii_uppercase() {
+			return format!("/{}{}", (bytes[1] as char).to_ascii_lowercase(), &path[2..]);
+		}
+	} else if bytes.len() >= 2 && bytes[1] == b':' && bytes[0].is_ascii_uppercase() {
+		return format!("{}{}", (bytes[0] as char).to_ascii_lowercase(), &path[1..]);
+	}
+	path.to_string()
+}
+
+/// Like posix `path.normalize` from node.
+fn normalize_path(path: &str) -> String {
+	if path.is_empty() {
+		return ".".to_string();
+	}
+	let is_absolute = path.starts_with('/');
+	let trailing_separator = path.ends_with('/');
+	let mut segments: Vec<&str> = Vec::new();
+	for segment in path.split('/') {
+		match segment {
+			"" | "." => {}
+			".." => {
+				if segments.last().is_some_and(|last| *last != "..") {
+					segments.pop();
+				} else if !is_absolute {
+					segments.push("..");
+				}
+			}
+			_ => segments.push(segment)
+		}
+	}
+	let mut result = segments.join("/");
+	if result.is_empty() {
+		return match (is_absolute, trailing_separator) {
+			(true, _) => "/".to_string(),
+			(false, true) => "./".to_string(),
+			(false, false) => ".".to_string()
+		};
+	}
+	if trailing_separator {
+		result.push('/');
+	}
+	if is_absolute {
+		result.insert(0, '/');
+	}
+	result
+}
+
+fn encode_component(value: &str, is_path: bool, is_authority: bool) -> String {
+	let mut result = String::with_capacity(value.len());
+	for c in value.chars() {
+		let keep = c.is_ascii_alphanumeric()
+			|| matches!(c, '-' | '.' | '_' | '~')
+			|| (is_path && c == '/')
+			|| (is_authority && matches!(c, '[' | ']' | ':'));
+		if keep {
+			result.push(c);
+		} else {
+			let mut buffer = [0; 4];
+			for byte in c.encode_utf8(&mut buffer).bytes() {
+				result.push_str(&format!("%{:02X}", byte));
+			}
+		}
+	}
+	result
+}
+
+/// Only encodes the characters that would change the meaning of the uri.
+fn encode_minimal(value: &str) -> String {
+	value.replace('#', "%23").replace('?', "%3F")
+}
+
+/// Decodes runs of percent encoded bytes. A run that isn't valid UTF-8 keeps
+/// its first escape as is and the rest is decoded again, like
+/// `decodeURIComponentGraceful` in `uri.ts`.
+fn percent_decode(value: &str) -> String {
+	if !value.contains('%') {
+		return value.to_string();
+	}
+	let bytes = value.as_bytes();
+	let is_escape = |index: usize| index + 2 < bytes.len() && bytes[index] == b'%' && bytes[index + 1].is_ascii_alphanumeric() && bytes[index + 2].is_ascii_alphanumeric();
+	let mut result = String::with_capacity(value.len());
+	let mut index = 0;
+	while index < bytes.len() {
+		let start = index;
+		while is_escape(index) {
+			index += 3;
+		}
+		if index > start {
+			result.push_str(&decode_graceful(&value[start..index]));
+		} else {
+			let c = value[index..].chars().next().unwrap_or_default();
+			result.push(c);
+			index += c.len_utf8();
+		}
+	}
+	result
+}
+
+fn decode_graceful(run: &str) -> String {
+	let decoded = run.as_bytes().chunks(3)
+		.map(|chunk| std::str::from_utf8(&chunk[1..]).ok().and_then(|hex| u8::from_str_radix(hex, 16).ok()))
+		.collect::<Option<Vec<u8>>>()
+		.and_then(|bytes| String::from_utf8(bytes).ok());
+	match decoded {
+		Some(decoded) => decoded,
+		None if run.len() > 3 => format!("{}{}", &run[..3], decode_graceful(&run[3..])),
+		None => run.to_string()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_splits_the_components() {
+		let uri = Uri::parse("http://www.example.com/some/path?query=1#fragment").unwrap();
+		assert_eq!(uri.scheme, "http");
+		assert_eq!(uri.authority, "www.example.com");
+		assert_eq!(uri.path, "/some/path");
+		assert_eq!(uri.query, "query=1");
+		assert_eq!(uri.fragment, "fragment");
+
+		let uri = Uri::parse("untitled:Untitled-1").unwrap();
+		assert_eq!((uri.scheme.as_str(), uri.authority.as_str(), uri.path.as_str()), ("untitled", "", "Untitled-1"));
+	}
+
+	#[test]
+	fn parse_defaults_to_file() {
+		let uri = Uri::parse("some/path").unwrap();
+		assert_eq!(uri.scheme, "file");
+		assert_eq!(uri.path, "/some/path");
+	}
+
+	#[test]
+	fn parse_decodes_percent_escapes() {
+		assert_eq!(Uri::parse("untitled:a%20b%E2%82%ACc%41").unwrap().path, "a b€cA");
+		assert_eq!(Uri::parse("http://host/p?q=%3F#%23").unwrap().query, "q=?");
+		assert_eq!(Uri::parse("http://host/p?q=%3F#%23").unwrap().fragment, "#");
+	}
+
+	#[test]
+	fn parse_keeps_invalid_escapes() {
+		assert_eq!(Uri::parse("untitled:%E2%82%AC%zz%C3").unwrap().path, "%E2%82%AC%zz%C3");
+		assert_eq!(Uri::parse("untitled:%C3a").unwrap().path, "%C3a");
+		assert_eq!(Uri::parse("untitled:100%").unwrap().path, "100%");
+	}
+
+	#[test]
+	fn to_string_encodes_the_components() {
+		let uri = Uri::file("/home/user/my file#1.txt");
+		assert_eq!(uri.to_string_with(false), "file:///home/user/my%20file%231.txt");
+		assert_eq!(uri.to_string_with(true), "file:///home/user/my file%231.txt");
+		assert_eq!(uri.to_string_with(false), format!("{}", uri));
+
+		let uri = Uri::parse("https://User@Example.COM:8080/a?b=c d#e f").unwrap();
+		assert_eq!(uri.to_string_with(false), "https://User@example.com:8080/a?b%3Dc%20d#e%20f");
+		assert_eq!(uri.to_string_with(true), "https://User@example.com:8080/a?b=c d#e f");
+	}
+
+	#[test]
+	fn file_creates_absolute_paths() {
+		let uri = Uri::file("relative/file.txt");
+		assert_eq!(uri.path, "/relative/file.txt");
+		assert_eq!(uri.to_string_with(false), "file:///relative/file.txt");
+	}
+
+	#[test]
+	fn file_handles_unc_paths() {
+		let uri = Uri::file("//server/share/file.txt");
+		assert_eq!(uri.authority, "server");
+		assert_eq!(uri.path, "/share/file.txt");
+		assert_eq!(uri.fs_path(), "//server/share/file.txt");
+		assert_eq!(uri.to_string_with(false), "file://server/share/file.txt");
+
+		let uri = Uri::file("//server");
+		assert_eq!((uri.authority.as_str(), uri.path.as_str()), ("server", "/"));
+		assert_eq!(uri.fs_path(), "/");
+	}
+
+	#[test]
+	fn file_handles_drive_letters() {
+		let uri = Uri::file("/C:/Users/Me");
+		assert_eq!(uri.path, "/C:/Users/Me");
+		assert_eq!(uri.fs_path(), "c:/Users/Me");
+		assert_eq!(uri.to_string_with(false), "file:///c%3A/Users/Me");
+		assert_eq!(uri.to_string_with(true), "file:///c:/Users/Me");
+		assert_eq!(Uri::file("C:/Users/Me"), uri);
+	}
+
+	#[test]
+	fn fs_path_ignores_the_authority_of_other_schemes() {
+		let uri = Uri::parse("http://host/some/path").unwrap();
+		assert_eq!(uri.fs_path(), "/some/path");
+	}
+
+	#[test]
+	fn join_path_normalizes() {
+		let uri = Uri::parse("file:///a/b").unwrap();
+		assert_eq!(uri.join_path(&["../c", "d/"]).unwrap().path, "/a/c/d/");
+		assert_eq!(uri.join_path(&["", "./e", "../../.."]).unwrap().path, "/");
+		assert_eq!(Uri::parse("untitled:x").unwrap().join_path(&["..", "..", "y"]).unwrap().path, "../y");
+		assert!(Uri::parse("untitled:").unwrap().join_path(&["a"]).is_err());
+	}
+
+	#[test]
+	fn with_changes_components() {
+		let uri = Uri::parse("http://host/path").unwrap();
+		let changed = uri.with(UriChange { query: Some("a=b"), fragment: Some("top"), ..Default::default() }).unwrap();
+		assert_eq!(changed.to_string_with(true), "http://host/path?a=b#top");
+		assert_eq!(uri.with(UriChange::default()).unwrap(), uri);
+		assert_eq!(uri.with(UriChange { scheme: Some(""), ..Default::default() }).unwrap().scheme, "file");
+	}
+
+	#[test]
+	fn with_validates() {
+		let uri = Uri::parse("http://host/path").unwrap();
+		assert!(uri.with(UriChange { scheme: Some("a b"), ..Default::default() }).is_err());
+		assert!(uri.with(UriChange { scheme: Some("-x"), ..Default::default() }).is_err());
+		assert!(uri.with(UriChange { scheme: Some("vscode-remote"), path: Some("relative"), ..Default::default() }).is_err());
+		assert!(uri.with(UriChange { scheme: Some("vscode-remote"), authority: Some(""), path: Some("//path"), ..Default::default() }).is_err());
+		assert!(Uri::from_components("untitled", "", "//path", "", "").is_err());
+		assert!(Uri::from_components("untitled", "", "/path", "", "").is_ok());
+	}
+
+	#[test]
+	fn deserializes_from_json() {
+		let uri: Uri = serde_json::from_str(r#"{ "$mid": 1, "scheme": "https", "authority": "example.com", "path": "/a" }"#).unwrap();
+		assert_eq!(uri.to_string_with(false), "https://example.com/a");
+		let uri: Uri = serde_json::from_str(r#"{ "scheme": "http", "authority": "example.com" }"#).unwrap();
+		assert_eq!(uri.path, "");
+		let uri: Uri = serde_json::from_str(r#"{ "path": "a" }"#).unwrap();
+		assert_eq!(uri.scheme, "");
+		assert_eq!(uri.path, "a");
+	}
+}
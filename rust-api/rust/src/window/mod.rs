@@ -3,7 +3,46 @@
  *  Licensed under the MIT License. See License.txt in the project root for license information.
  *--------------------------------------------------------------------------------------------*/
 
+use crate::common::Operation;
 use crate::host::api::window;
+use crate::types::Error;
 
 #[allow(non_upper_case_globals)]
-pub const create_output_channel: fn(name: &str, language_id: Option<&str>) -> super::OutputChannel = window::create_output_channel;
\ No newline at end of file
+pub const create_output_channel: fn(name: &str, language_id: Option<&str>) -> super::OutputChannel = window::create_output_channel;
+
+/// Shows an information message with the items as buttons. Resolves to the
+/// selected item, `None` if the message was dismissed.
+pub async fn show_information_message(message: &str, items: &[&str]) -> Result<Option<String>, Error> {
+	let items = to_strings(items);
+	select(Operation::start(|operation| window::show_information_message(operation, message, &items))).await
+}
+
+/// Like `show_information_message` but for a warning.
+pub async fn show_warning_message(message: &str, items: &[&str]) -> Result<Option<String>, Error> {
+	let items = to_strings(items);
+	select(Operation::start(|operation| window::show_warning_message(operation, message, &items))).await
+}
+
+/// Like `show_information_message` but for an error.
+pub async fn show_error_message(message: &str, items: &[&str]) -> Result<Option<String>, Error> {
+	let items = to_strings(items);
+	select(Operation::start(|operation| window::show_error_message(operation, message, &items))).await
+}
+
+/// Shows a list of items to pick one from. Resolves to the picked item,
+/// `None` if the pick was cancelled.
+pub async fn show_quick_pick(items: &[&str]) -> Result<Option<String>, Error> {
+	let items = to_strings(items);
+	select(Operation::start(|operation| window::show_quick_pick(operation, &items))).await
+}
+
+fn to_strings(items: &[&str]) -> Vec<String> {
+	items.iter().map(|item| item.to_string()).collect()
+}
+
+async fn select(operation: Operation) -> Result<Option<String>, Error> {
+	match operation.await {
+		Ok(value) => Ok(serde_json::from_str(&value)?),
+		Err(message) => Err(Error::new(&message))
+	}
+}
@@ -2,8 +2,6 @@
  *  Copyright (c) Microsoft Corporation. All rights reserved.
  *  Licensed under the MIT License. See License.txt in the project root for license information.
  *--------------------------------------------------------------------------------------------*/
-use once_cell::sync::Lazy;
-
 use crate::host::api::{
 	types,
 	workspace
@@ -13,19 +11,17 @@ use crate::common::EventEmitter;
 #[allow(non_upper_case_globals)]
 pub const text_documents: fn() -> Vec<super::TextDocument> = workspace::text_documents;
 
-static mut ON_DID_CHANGE_TEXT_DOCUMENT: Lazy<EventEmitter<types::TextDocumentChangeEvent>> = Lazy::new(|| EventEmitter::new(workspace::register_on_did_change_text_document, workspace::unregister_on_did_change_text_document));
+thread_local! {
+	static ON_DID_CHANGE_TEXT_DOCUMENT: EventEmitter<types::TextDocumentChangeEvent> = EventEmitter::new(workspace::register_on_did_change_text_document, workspace::unregister_on_did_change_text_document);
+}
 
 pub fn on_did_change_text_document<F>(listener: F) -> impl Fn() + 'static
 where
 		F: Fn(&types::TextDocumentChangeEvent) + 'static,
 {
-	unsafe {
-		ON_DID_CHANGE_TEXT_DOCUMENT.on(listener)
-	}
+	ON_DID_CHANGE_TEXT_DOCUMENT.with(|emitter| emitter.on(listener))
 }
 
 pub fn fire_did_change_text_document(event: &types::TextDocumentChangeEvent) {
-	unsafe {
-		ON_DID_CHANGE_TEXT_DOCUMENT.fire(event)
-	}
-}
\ No newline at end of file
+	ON_DID_CHANGE_TEXT_DOCUMENT.with(|emitter| emitter.fire(event))
+}
@@ -2,11 +2,23 @@
  *  Copyright (c) Microsoft Corporation. All rights reserved.
  *  Licensed under the MIT License. See License.txt in the project root for license information.
  *--------------------------------------------------------------------------------------------*/
-import type { i32, own, ptr, u32 } from '@vscode/wasm-component-model';
+/* eslint-disable @typescript-eslint/no-empty-object-type, @typescript-eslint/no-unsafe-function-type, @typescript-eslint/no-wrapper-object-types */
 import * as $wcm from '@vscode/wasm-component-model';
+import type { u32, own, s32, float64, float32, result, i32, ptr, i64, f32 } from '@vscode/wasm-component-model';
 
 export namespace api {
 	export namespace Types {
+		/**
+		 * The components of a `vscode.Uri`. They are not encoded.
+		 */
+		export type Uri = {
+			scheme: string;
+			authority: string;
+			path: string;
+			query: string;
+			fragment: string;
+		};
+
 		export type Position = {
 			line: u32;
 			character: u32;
@@ -17,6 +29,38 @@ export namespace api {
 			end: Position;
 		};
 
+		export type Selection = {
+			anchor: Position;
+			active: Position;
+		};
+
+		export enum UiKind {
+			desktop = 'desktop',
+			web = 'web'
+		}
+
+		export enum ExtensionMode {
+			production = 'production',
+			development = 'development',
+			test = 'test'
+		}
+
+		export type SecretStorageChangeEvent = {
+			key: string;
+		};
+
+		export type ExtensionContext = {
+			globalState: own<Memento>;
+			workspaceState: own<Memento>;
+			secrets: own<SecretStorage>;
+			extensionUri: Uri;
+			extensionPath: string;
+			extensionMode: ExtensionMode;
+			storageUri?: Uri | undefined;
+			globalStorageUri: Uri;
+			logUri: Uri;
+		};
+
 		export type TextDocumentContentChangeEvent = {
 			range: Range;
 			rangeOffset: u32;
@@ -111,123 +155,1510 @@ export namespace api {
 		}
 		export type DocumentSelector = DocumentSelector.Many | DocumentSelector.Single;
 
-		export namespace TextDocument {
-			export interface Interface extends $wcm.Resource {
-				uri(): string;
+		export type Location = {
+			uri: Uri;
+			range: Range;
+		};
 
-				languageId(): string;
+		export type LocationLink = {
+			originSelectionRange?: Range | undefined;
+			targetUri: Uri;
+			targetRange: Range;
+			targetSelectionRange?: Range | undefined;
+		};
 
-				version(): u32;
+		export namespace Definition {
+			export const locations = 'locations' as const;
+			export type Locations = { readonly tag: typeof locations; readonly value: Location[] } & _common;
+			export function Locations(value: Location[]): Locations {
+				return new VariantImpl(locations, value) as Locations;
+			}
 
-				getText(): string;
+			export const links = 'links' as const;
+			export type Links = { readonly tag: typeof links; readonly value: LocationLink[] } & _common;
+			export function Links(value: LocationLink[]): Links {
+				return new VariantImpl(links, value) as Links;
+			}
+
+			export type _tt = typeof locations | typeof links;
+			export type _vt = Location[] | LocationLink[];
+			type _common = Omit<VariantImpl, 'tag' | 'value'>;
+			export function _ctor(t: _tt, v: _vt): Definition {
+				return new VariantImpl(t, v) as Definition;
+			}
+			class VariantImpl {
+				private readonly _tag: _tt;
+				private readonly _value: _vt;
+				constructor(t: _tt, value: _vt) {
+					this._tag = t;
+					this._value = value;
+				}
+				get tag(): _tt {
+					return this._tag;
+				}
+				get value(): _vt {
+					return this._value;
+				}
+				isLocations(): this is Locations {
+					return this._tag === Definition.locations;
+				}
+				isLinks(): this is Links {
+					return this._tag === Definition.links;
+				}
 			}
-			export type Statics = {
-			};
-			export type Class = Statics & {
-			};
 		}
-		export type TextDocument = TextDocument.Interface;
+		export type Definition = Definition.Locations | Definition.Links;
 
-		export namespace TextDocumentChangeEvent {
-			export interface Interface extends $wcm.Resource {
-				document(): own<TextDocument>;
+		export enum DiagnosticSeverity {
+			error = 'error',
+			warning = 'warning',
+			information = 'information',
+			hint = 'hint'
+		}
 
-				contentChanges(): TextDocumentContentChangeEvent[];
+		export enum DiagnosticTag {
+			unnecessary = 'unnecessary',
+			deprecated = 'deprecated'
+		}
 
-				reason(): TextDocumentChangeReason | undefined;
+		export namespace DiagnosticCodeValue {
+			export const text = 'text' as const;
+			export type Text = { readonly tag: typeof text; readonly value: string } & _common;
+			export function Text(value: string): Text {
+				return new VariantImpl(text, value) as Text;
+			}
+
+			export const number = 'number' as const;
+			export type Number = { readonly tag: typeof number; readonly value: s32 } & _common;
+			export function Number(value: s32): Number {
+				return new VariantImpl(number, value) as Number;
+			}
+
+			export type _tt = typeof text | typeof number;
+			export type _vt = string | s32;
+			type _common = Omit<VariantImpl, 'tag' | 'value'>;
+			export function _ctor(t: _tt, v: _vt): DiagnosticCodeValue {
+				return new VariantImpl(t, v) as DiagnosticCodeValue;
+			}
+			class VariantImpl {
+				private readonly _tag: _tt;
+				private readonly _value: _vt;
+				constructor(t: _tt, value: _vt) {
+					this._tag = t;
+					this._value = value;
+				}
+				get tag(): _tt {
+					return this._tag;
+				}
+				get value(): _vt {
+					return this._value;
+				}
+				isText(): this is Text {
+					return this._tag === DiagnosticCodeValue.text;
+				}
+				isNumber(): this is Number {
+					return this._tag === DiagnosticCodeValue.number;
+				}
 			}
-			export type Statics = {
-			};
-			export type Class = Statics & {
-			};
 		}
-		export type TextDocumentChangeEvent = TextDocumentChangeEvent.Interface;
+		export type DiagnosticCodeValue = DiagnosticCodeValue.Text | DiagnosticCodeValue.Number;
 
-		export namespace OutputChannel {
-			export interface Interface extends $wcm.Resource {
-				name(): string;
+		export type DiagnosticCode = {
+			value: DiagnosticCodeValue;
+			target?: Uri | undefined;
+		};
 
-				append(value: string): void;
+		export type DiagnosticRelatedInformation = {
+			location: Location;
+			message: string;
+		};
 
-				appendLine(value: string): void;
+		export type Diagnostic = {
+			range: Range;
+			message: string;
+			severity: DiagnosticSeverity;
+			source?: string | undefined;
+			code?: DiagnosticCode | undefined;
+			relatedInformation: DiagnosticRelatedInformation[];
+			tags: DiagnosticTag[];
+		};
 
-				clear(): void;
+		export type DiagnosticEntry = {
+			uri: Uri;
+			diagnostics: Diagnostic[];
+		};
 
-				show(): void;
+		export type DiagnosticChangeEvent = {
+			uris: Uri[];
+		};
+
+		export type TextEdit = {
+			range: Range;
+			newText: string;
+		};
+
+		export type WorkspaceEditEntry = {
+			uri: Uri;
+			edits: TextEdit[];
+		};
+
+		export type WorkspaceEdit = {
+			entries: WorkspaceEditEntry[];
+		};
+
+		export type Command = {
+			title: string;
+			command: string;
+			tooltip?: string | undefined;
+
+			/**
+			 * The JSON encoded array of arguments.
+			 */
+			arguments?: string | undefined;
+		};
+
+		export enum CodeActionTriggerKind {
+			invoke = 'invoke',
+			automatic = 'automatic'
+		}
+
+		export type CodeActionContext = {
+			diagnostics: Diagnostic[];
+			only?: string | undefined;
+			triggerKind: CodeActionTriggerKind;
+		};
+
+		export type CodeAction = {
+			title: string;
+			kind?: string | undefined;
+			diagnostics: Diagnostic[];
+			edit?: WorkspaceEdit | undefined;
+			command?: Command | undefined;
+			isPreferred: boolean;
+			disabled?: string | undefined;
+		};
+
+		export type CodeActionProviderMetadata = {
+			providedCodeActionKinds: string[];
+		};
+
+		export namespace FormattingOptionValue {
+			export const boolean = 'boolean' as const;
+			export type Boolean = { readonly tag: typeof boolean; readonly value: boolean } & _common;
+			export function Boolean(value: boolean): Boolean {
+				return new VariantImpl(boolean, value) as Boolean;
+			}
+
+			export const number = 'number' as const;
+			export type Number = { readonly tag: typeof number; readonly value: float64 } & _common;
+			export function Number(value: float64): Number {
+				return new VariantImpl(number, value) as Number;
+			}
+
+			export const text = 'text' as const;
+			export type Text = { readonly tag: typeof text; readonly value: string } & _common;
+			export function Text(value: string): Text {
+				return new VariantImpl(text, value) as Text;
+			}
+
+			export type _tt = typeof boolean | typeof number | typeof text;
+			export type _vt = boolean | float64 | string;
+			type _common = Omit<VariantImpl, 'tag' | 'value'>;
+			export function _ctor(t: _tt, v: _vt): FormattingOptionValue {
+				return new VariantImpl(t, v) as FormattingOptionValue;
+			}
+			class VariantImpl {
+				private readonly _tag: _tt;
+				private readonly _value: _vt;
+				constructor(t: _tt, value: _vt) {
+					this._tag = t;
+					this._value = value;
+				}
+				get tag(): _tt {
+					return this._tag;
+				}
+				get value(): _vt {
+					return this._value;
+				}
+				isBoolean(): this is Boolean {
+					return this._tag === FormattingOptionValue.boolean;
+				}
+				isNumber(): this is Number {
+					return this._tag === FormattingOptionValue.number;
+				}
+				isText(): this is Text {
+					return this._tag === FormattingOptionValue.text;
+				}
 			}
-			export type Statics = {
-			};
-			export type Class = Statics & {
-			};
 		}
-		export type OutputChannel = OutputChannel.Interface;
-	}
-	export type Types = {
-		TextDocument: Types.TextDocument.Class;
-		TextDocumentChangeEvent: Types.TextDocumentChangeEvent.Class;
-		OutputChannel: Types.OutputChannel.Class;
-	};
+		export type FormattingOptionValue = FormattingOptionValue.Boolean | FormattingOptionValue.Number | FormattingOptionValue.Text;
 
-	export namespace Commands {
-		export type registerCommand = (command: string) => void;
+		export namespace ContextValue {
+			export const boolean = 'boolean' as const;
+			export type Boolean = { readonly tag: typeof boolean; readonly value: boolean } & _common;
+			export function Boolean(value: boolean): Boolean {
+				return new VariantImpl(boolean, value) as Boolean;
+			}
 
-		export type unregisterCommand = (command: string) => void;
-	}
-	export type Commands = {
-		registerCommand: Commands.registerCommand;
-		unregisterCommand: Commands.unregisterCommand;
-	};
+			export const number = 'number' as const;
+			export type Number = { readonly tag: typeof number; readonly value: float64 } & _common;
+			export function Number(value: float64): Number {
+				return new VariantImpl(number, value) as Number;
+			}
 
-	export namespace Languages {
-		export type DocumentSelector = api.Types.DocumentSelector;
+			export const text = 'text' as const;
+			export type Text = { readonly tag: typeof text; readonly value: string } & _common;
+			export function Text(value: string): Text {
+				return new VariantImpl(text, value) as Text;
+			}
 
-		export type TextDocument = api.Types.TextDocument;
+			export const textList = 'textList' as const;
+			export type TextList = { readonly tag: typeof textList; readonly value: string[] } & _common;
+			export function TextList(value: string[]): TextList {
+				return new VariantImpl(textList, value) as TextList;
+			}
 
-		export type matchSelector = (selector: DocumentSelector, document: own<TextDocument>) => u32;
-	}
-	export type Languages = {
-		matchSelector: Languages.matchSelector;
-	};
+			export type _tt = typeof boolean | typeof number | typeof text | typeof textList;
+			export type _vt = boolean | float64 | string | string[];
+			type _common = Omit<VariantImpl, 'tag' | 'value'>;
+			export function _ctor(t: _tt, v: _vt): ContextValue {
+				return new VariantImpl(t, v) as ContextValue;
+			}
+			class VariantImpl {
+				private readonly _tag: _tt;
+				private readonly _value: _vt;
+				constructor(t: _tt, value: _vt) {
+					this._tag = t;
+					this._value = value;
+				}
+				get tag(): _tt {
+					return this._tag;
+				}
+				get value(): _vt {
+					return this._value;
+				}
+				isBoolean(): this is Boolean {
+					return this._tag === ContextValue.boolean;
+				}
+				isNumber(): this is Number {
+					return this._tag === ContextValue.number;
+				}
+				isText(): this is Text {
+					return this._tag === ContextValue.text;
+				}
+				isTextList(): this is TextList {
+					return this._tag === ContextValue.textList;
+				}
+			}
+		}
+		export type ContextValue = ContextValue.Boolean | ContextValue.Number | ContextValue.Text | ContextValue.TextList;
 
-	export namespace Window {
-		export type OutputChannel = api.Types.OutputChannel;
+		export type FormattingOption = {
+			key: string;
+			value: FormattingOptionValue;
+		};
 
-		export type createOutputChannel = (name: string, languageId: string | undefined) => own<OutputChannel>;
-	}
-	export type Window = {
-		createOutputChannel: Window.createOutputChannel;
-	};
+		export type FormattingOptions = {
+			tabSize: u32;
+			insertSpaces: boolean;
+			properties: FormattingOption[];
+		};
 
-	export namespace Workspace {
-		export type TextDocument = api.Types.TextDocument;
+		export type SemanticTokensLegend = {
+			tokenTypes: string[];
+			tokenModifiers: string[];
+		};
+
+		export type SemanticTokens = {
+			resultId?: string | undefined;
+			data: Uint32Array;
+		};
+
+		export type SemanticTokensEdit = {
+			start: u32;
+			deleteCount: u32;
+			data?: Uint32Array | undefined;
+		};
+
+		export type SemanticTokensEdits = {
+			resultId?: string | undefined;
+			edits: SemanticTokensEdit[];
+		};
+
+		export namespace SemanticTokensResult {
+			export const full = 'full' as const;
+			export type Full = { readonly tag: typeof full; readonly value: SemanticTokens } & _common;
+			export function Full(value: SemanticTokens): Full {
+				return new VariantImpl(full, value) as Full;
+			}
+
+			export const edits = 'edits' as const;
+			export type Edits = { readonly tag: typeof edits; readonly value: SemanticTokensEdits } & _common;
+			export function Edits(value: SemanticTokensEdits): Edits {
+				return new VariantImpl(edits, value) as Edits;
+			}
+
+			export type _tt = typeof full | typeof edits;
+			export type _vt = SemanticTokens | SemanticTokensEdits;
+			type _common = Omit<VariantImpl, 'tag' | 'value'>;
+			export function _ctor(t: _tt, v: _vt): SemanticTokensResult {
+				return new VariantImpl(t, v) as SemanticTokensResult;
+			}
+			class VariantImpl {
+				private readonly _tag: _tt;
+				private readonly _value: _vt;
+				constructor(t: _tt, value: _vt) {
+					this._tag = t;
+					this._value = value;
+				}
+				get tag(): _tt {
+					return this._tag;
+				}
+				get value(): _vt {
+					return this._value;
+				}
+				isFull(): this is Full {
+					return this._tag === SemanticTokensResult.full;
+				}
+				isEdits(): this is Edits {
+					return this._tag === SemanticTokensResult.edits;
+				}
+			}
+		}
+		export type SemanticTokensResult = SemanticTokensResult.Full | SemanticTokensResult.Edits;
+
+		export enum SymbolKind {
+			file = 'file',
+			module = 'module',
+			namespace = 'namespace',
+			package = 'package',
+			class = 'class',
+			method = 'method',
+			property = 'property',
+			field = 'field',
+			constructor = 'constructor',
+			enum = 'enum',
+			interface = 'interface',
+			function = 'function',
+			variable = 'variable',
+			constant = 'constant',
+			string = 'string',
+			number = 'number',
+			boolean = 'boolean',
+			array = 'array',
+			object = 'object',
+			key = 'key',
+			null = 'null',
+			enumMember = 'enumMember',
+			struct = 'struct',
+			event = 'event',
+			operator = 'operator',
+			typeParameter = 'typeParameter'
+		}
+
+		export enum SymbolTag {
+			deprecated = 'deprecated'
+		}
+
+		/**
+		 * A document symbol in a flattened symbol tree. Parents always come
+		 * before their children.
+		 */
+		export type DocumentSymbolNode = {
+			name: string;
+			detail: string;
+			kind: SymbolKind;
+			tags: SymbolTag[];
+			range: Range;
+			selectionRange: Range;
+			parent?: u32 | undefined;
+		};
+
+		export type SymbolInformation = {
+			name: string;
+			containerName?: string | undefined;
+			kind: SymbolKind;
+			tags: SymbolTag[];
+			location: Location;
+		};
+
+		export type ReferenceContext = {
+			includeDeclaration: boolean;
+		};
+
+		export enum DocumentHighlightKind {
+			text = 'text',
+			read = 'read',
+			write = 'write'
+		}
+
+		export type DocumentHighlight = {
+			range: Range;
+			kind: DocumentHighlightKind;
+		};
+
+		export type RenameLocation = {
+			range: Range;
+			placeholder?: string | undefined;
+		};
+
+		export namespace PrepareRenameResult {
+			export const accepted = 'accepted' as const;
+			export type Accepted = { readonly tag: typeof accepted; readonly value: RenameLocation } & _common;
+			export function Accepted(value: RenameLocation): Accepted {
+				return new VariantImpl(accepted, value) as Accepted;
+			}
+
+			export const rejected = 'rejected' as const;
+			export type Rejected = { readonly tag: typeof rejected; readonly value: string } & _common;
+			export function Rejected(value: string): Rejected {
+				return new VariantImpl(rejected, value) as Rejected;
+			}
+
+			export type _tt = typeof accepted | typeof rejected;
+			export type _vt = RenameLocation | string;
+			type _common = Omit<VariantImpl, 'tag' | 'value'>;
+			export function _ctor(t: _tt, v: _vt): PrepareRenameResult {
+				return new VariantImpl(t, v) as PrepareRenameResult;
+			}
+			class VariantImpl {
+				private readonly _tag: _tt;
+				private readonly _value: _vt;
+				constructor(t: _tt, value: _vt) {
+					this._tag = t;
+					this._value = value;
+				}
+				get tag(): _tt {
+					return this._tag;
+				}
+				get value(): _vt {
+					return this._value;
+				}
+				isAccepted(): this is Accepted {
+					return this._tag === PrepareRenameResult.accepted;
+				}
+				isRejected(): this is Rejected {
+					return this._tag === PrepareRenameResult.rejected;
+				}
+			}
+		}
+		export type PrepareRenameResult = PrepareRenameResult.Accepted | PrepareRenameResult.Rejected;
+
+		export enum FoldingRangeKind {
+			comment = 'comment',
+			imports = 'imports',
+			region = 'region'
+		}
+
+		export type FoldingRange = {
+			start: u32;
+			end: u32;
+			kind?: FoldingRangeKind | undefined;
+		};
+
+		export enum InlayHintKind {
+			type = 'type',
+			parameter = 'parameter'
+		}
+
+		export type InlayHintLabelPart = {
+			value: string;
+			tooltip?: string | undefined;
+			location?: Location | undefined;
+			command?: Command | undefined;
+		};
+
+		export namespace InlayHintLabel {
+			export const text = 'text' as const;
+			export type Text = { readonly tag: typeof text; readonly value: string } & _common;
+			export function Text(value: string): Text {
+				return new VariantImpl(text, value) as Text;
+			}
+
+			export const parts = 'parts' as const;
+			export type Parts = { readonly tag: typeof parts; readonly value: InlayHintLabelPart[] } & _common;
+			export function Parts(value: InlayHintLabelPart[]): Parts {
+				return new VariantImpl(parts, value) as Parts;
+			}
+
+			export type _tt = typeof text | typeof parts;
+			export type _vt = string | InlayHintLabelPart[];
+			type _common = Omit<VariantImpl, 'tag' | 'value'>;
+			export function _ctor(t: _tt, v: _vt): InlayHintLabel {
+				return new VariantImpl(t, v) as InlayHintLabel;
+			}
+			class VariantImpl {
+				private readonly _tag: _tt;
+				private readonly _value: _vt;
+				constructor(t: _tt, value: _vt) {
+					this._tag = t;
+					this._value = value;
+				}
+				get tag(): _tt {
+					return this._tag;
+				}
+				get value(): _vt {
+					return this._value;
+				}
+				isText(): this is Text {
+					return this._tag === InlayHintLabel.text;
+				}
+				isParts(): this is Parts {
+					return this._tag === InlayHintLabel.parts;
+				}
+			}
+		}
+		export type InlayHintLabel = InlayHintLabel.Text | InlayHintLabel.Parts;
+
+		export type InlayHint = {
+			position: Position;
+			label: InlayHintLabel;
+			tooltip?: string | undefined;
+			kind?: InlayHintKind | undefined;
+			paddingLeft: boolean;
+			paddingRight: boolean;
+			textEdits: TextEdit[];
+		};
+
+		export type CodeLens = {
+			range: Range;
+			command?: Command | undefined;
+		};
+
+		export namespace ParameterLabel {
+			export const text = 'text' as const;
+			export type Text = { readonly tag: typeof text; readonly value: string } & _common;
+			export function Text(value: string): Text {
+				return new VariantImpl(text, value) as Text;
+			}
+
+			export const offsets = 'offsets' as const;
+			export type Offsets = { readonly tag: typeof offsets; readonly value: [u32, u32] } & _common;
+			export function Offsets(value: [u32, u32]): Offsets {
+				return new VariantImpl(offsets, value) as Offsets;
+			}
+
+			export type _tt = typeof text | typeof offsets;
+			export type _vt = string | [u32, u32];
+			type _common = Omit<VariantImpl, 'tag' | 'value'>;
+			export function _ctor(t: _tt, v: _vt): ParameterLabel {
+				return new VariantImpl(t, v) as ParameterLabel;
+			}
+			class VariantImpl {
+				private readonly _tag: _tt;
+				private readonly _value: _vt;
+				constructor(t: _tt, value: _vt) {
+					this._tag = t;
+					this._value = value;
+				}
+				get tag(): _tt {
+					return this._tag;
+				}
+				get value(): _vt {
+					return this._value;
+				}
+				isText(): this is Text {
+					return this._tag === ParameterLabel.text;
+				}
+				isOffsets(): this is Offsets {
+					return this._tag === ParameterLabel.offsets;
+				}
+			}
+		}
+		export type ParameterLabel = ParameterLabel.Text | ParameterLabel.Offsets;
+
+		export type ParameterInformation = {
+			label: ParameterLabel;
+			documentation?: string | undefined;
+		};
+
+		export type SignatureInformation = {
+			label: string;
+			documentation?: string | undefined;
+			parameters: ParameterInformation[];
+			activeParameter?: u32 | undefined;
+		};
+
+		export type SignatureHelp = {
+			signatures: SignatureInformation[];
+			activeSignature: u32;
+			activeParameter: u32;
+		};
+
+		export enum SignatureHelpTriggerKind {
+			invoke = 'invoke',
+			triggerCharacter = 'triggerCharacter',
+			contentChange = 'contentChange'
+		}
+
+		export type SignatureHelpContext = {
+			triggerKind: SignatureHelpTriggerKind;
+			triggerCharacter?: string | undefined;
+			isRetrigger: boolean;
+			activeSignatureHelp?: SignatureHelp | undefined;
+		};
+
+		export type SignatureHelpProviderMetadata = {
+			triggerCharacters: string[];
+			retriggerCharacters: string[];
+		};
+
+		/**
+		 * A call or type hierarchy item. The handle identifies the item on the
+		 * guest side, which keeps the data needed to resolve it until the host
+		 * releases the item.
+		 */
+		export type HierarchyItem = {
+			handle: u32;
+			name: string;
+			kind: SymbolKind;
+			tags: SymbolTag[];
+			detail?: string | undefined;
+			uri: Uri;
+			range: Range;
+			selectionRange: Range;
+		};
+
+		export type CallHierarchyIncomingCall = {
+			from: HierarchyItem;
+			fromRanges: Range[];
+		};
+
+		export type CallHierarchyOutgoingCall = {
+			to: HierarchyItem;
+			fromRanges: Range[];
+		};
+
+		export type Color = {
+			red: float32;
+			green: float32;
+			blue: float32;
+			alpha: float32;
+		};
+
+		export type ColorInformation = {
+			range: Range;
+			color: Color;
+		};
+
+		export type ColorPresentation = {
+			label: string;
+			textEdit?: TextEdit | undefined;
+			additionalTextEdits: TextEdit[];
+		};
+
+		export type DocumentLink = {
+			range: Range;
+			target?: Uri | undefined;
+			tooltip?: string | undefined;
+		};
+
+		export type LinkedEditingRanges = {
+			ranges: Range[];
+			wordPattern?: string | undefined;
+		};
+
+		export type InlineCompletionItem = {
+			insertText: string;
+			filterText?: string | undefined;
+			range?: Range | undefined;
+			command?: Command | undefined;
+		};
+
+		export enum InlineCompletionTriggerKind {
+			invoke = 'invoke',
+			automatic = 'automatic'
+		}
+
+		export type SelectedCompletionInfo = {
+			range: Range;
+			text: string;
+		};
+
+		export type InlineCompletionContext = {
+			triggerKind: InlineCompletionTriggerKind;
+			selectedCompletionInfo?: SelectedCompletionInfo | undefined;
+		};
+
+		export type CommentRule = {
+			lineComment?: string | undefined;
+			blockComment?: [string, string] | undefined;
+		};
+
+		export type AutoClosingPair = {
+			open: string;
+			close: string;
+			notIn: string[];
+		};
+
+		export type IndentationRule = {
+			decreaseIndentPattern: string;
+			increaseIndentPattern: string;
+			indentNextLinePattern?: string | undefined;
+			unindentedLinePattern?: string | undefined;
+		};
+
+		export enum IndentAction {
+			none = 'none',
+			indent = 'indent',
+			indentOutdent = 'indentOutdent',
+			outdent = 'outdent'
+		}
+
+		export type EnterAction = {
+			indentAction: IndentAction;
+			appendText?: string | undefined;
+			removeText?: u32 | undefined;
+		};
+
+		export type OnEnterRule = {
+			beforeText: string;
+			afterText?: string | undefined;
+			previousLineText?: string | undefined;
+			action: EnterAction;
+		};
+
+		/**
+		 * Patterns are JavaScript regular expression sources.
+		 */
+		export type LanguageConfiguration = {
+			comments?: CommentRule | undefined;
+			brackets: [string, string][];
+			autoClosingPairs: AutoClosingPair[];
+			wordPattern?: string | undefined;
+			indentationRules?: IndentationRule | undefined;
+			onEnterRules: OnEnterRule[];
+		};
+
+		export enum LanguageStatusSeverity {
+			information = 'information',
+			warning = 'warning',
+			error = 'error'
+		}
+
+		export type AccessibilityInformation = {
+			label: string;
+			role?: string | undefined;
+		};
+
+		export namespace Memento {
+			export interface Interface extends $wcm.Resource {
+				get(key: string): string | undefined;
+
+				keys(): string[];
+
+				/**
+				 * Reports completion with `complete-operation`. No value removes the key.
+				 */
+				update(operation: u32, key: string, value: string | undefined): void;
+
+				/**
+				 * Only has an effect on the global state.
+				 */
+				setKeysForSync(keys: string[]): void;
+			}
+			export type Statics = {
+			};
+			export type Class = Statics & {
+			};
+		}
+		export type Memento = Memento.Interface;
+
+		export namespace SecretStorage {
+			export interface Interface extends $wcm.Resource {
+				/**
+				 * The value is the JSON encoded secret or null.
+				 */
+				get(operation: u32, key: string): void;
+
+				store(operation: u32, key: string, value: string): void;
+
+				delete(operation: u32, key: string): void;
+
+				registerOnDidChange(): void;
+
+				unregisterOnDidChange(): void;
+			}
+			export type Statics = {
+			};
+			export type Class = Statics & {
+			};
+		}
+		export type SecretStorage = SecretStorage.Interface;
+
+		export namespace TextDocument {
+			export interface Interface extends $wcm.Resource {
+				uri(): Uri;
+
+				languageId(): string;
+
+				version(): u32;
+
+				getText(): string;
+			}
+			export type Statics = {
+			};
+			export type Class = Statics & {
+			};
+		}
+		export type TextDocument = TextDocument.Interface;
+
+		export namespace TextEditor {
+			export interface Interface extends $wcm.Resource {
+				document(): own<TextDocument>;
+
+				selection(): Selection;
+
+				selections(): Selection[];
+
+				setSelections(selections: Selection[]): void;
+			}
+			export type Statics = {
+			};
+			export type Class = Statics & {
+			};
+		}
+		export type TextEditor = TextEditor.Interface;
+
+		export namespace TextDocumentChangeEvent {
+			export interface Interface extends $wcm.Resource {
+				document(): own<TextDocument>;
+
+				contentChanges(): TextDocumentContentChangeEvent[];
+
+				reason(): TextDocumentChangeReason | undefined;
+			}
+			export type Statics = {
+			};
+			export type Class = Statics & {
+			};
+		}
+		export type TextDocumentChangeEvent = TextDocumentChangeEvent.Interface;
+
+		export namespace OutputChannel {
+			export interface Interface extends $wcm.Resource {
+				name(): string;
+
+				append(value: string): void;
+
+				appendLine(value: string): void;
+
+				clear(): void;
+
+				show(): void;
+			}
+			export type Statics = {
+			};
+			export type Class = Statics & {
+			};
+		}
+		export type OutputChannel = OutputChannel.Interface;
+
+		export namespace CancellationToken {
+			export interface Interface extends $wcm.Resource {
+				isCancellationRequested(): boolean;
+			}
+			export type Statics = {
+			};
+			export type Class = Statics & {
+			};
+		}
+		export type CancellationToken = CancellationToken.Interface;
+
+		export namespace DiagnosticCollection {
+			export interface Interface extends $wcm.Resource {
+				name(): string;
+
+				set(uri: Uri, diagnostics: Diagnostic[]): void;
+
+				delete(uri: Uri): void;
+
+				clear(): void;
+
+				get(uri: Uri): Diagnostic[];
+
+				has(uri: Uri): boolean;
+
+				entries(): DiagnosticEntry[];
+			}
+			export type Statics = {
+			};
+			export type Class = Statics & {
+			};
+		}
+		export type DiagnosticCollection = DiagnosticCollection.Interface;
+
+		export namespace LanguageStatusItem {
+			export interface Interface extends $wcm.Resource {
+				id(): string;
+
+				name(): string | undefined;
+
+				setName(name: string | undefined): void;
+
+				selector(): DocumentSelector;
+
+				setSelector(selector: DocumentSelector): void;
+
+				severity(): LanguageStatusSeverity;
+
+				setSeverity(severity: LanguageStatusSeverity): void;
+
+				text(): string;
+
+				setText(text: string): void;
+
+				detail(): string | undefined;
+
+				setDetail(detail: string | undefined): void;
+
+				busy(): boolean;
+
+				setBusy(busy: boolean): void;
+
+				command(): Command | undefined;
+
+				setCommand(command: Command | undefined): void;
+
+				accessibilityInformation(): AccessibilityInformation | undefined;
+
+				setAccessibilityInformation(accessibilityInformation: AccessibilityInformation | undefined): void;
+			}
+			export type Statics = {
+			};
+			export type Class = Statics & {
+			};
+		}
+		export type LanguageStatusItem = LanguageStatusItem.Interface;
+	}
+	export type Types = {
+		Memento: Types.Memento.Class;
+		SecretStorage: Types.SecretStorage.Class;
+		TextDocument: Types.TextDocument.Class;
+		TextEditor: Types.TextEditor.Class;
+		TextDocumentChangeEvent: Types.TextDocumentChangeEvent.Class;
+		OutputChannel: Types.OutputChannel.Class;
+		CancellationToken: Types.CancellationToken.Class;
+		DiagnosticCollection: Types.DiagnosticCollection.Class;
+		LanguageStatusItem: Types.LanguageStatusItem.Class;
+	};
+
+	export namespace Env {
+		export type Uri = api.Types.Uri;
+
+		export type UiKind = api.Types.UiKind;
+		export const UiKind = api.Types.UiKind;
+
+		export type appName = () => string;
+
+		export type appRoot = () => string;
+
+		export type appHost = () => string;
+
+		export type getUiKind = () => UiKind;
+
+		export type language = () => string;
+
+		export type machineId = () => string;
+
+		export type sessionId = () => string;
+
+		export type remoteName = () => string | undefined;
+
+		export type shell = () => string;
+
+		export type uriScheme = () => string;
+
+		export type isTelemetryEnabled = () => boolean;
+
+		/**
+		 * The following report their JSON encoded result with `complete-operation`.
+		 */
+		export type clipboardReadText = (operation: u32) => void;
+
+		export type clipboardWriteText = (operation: u32, value: string) => void;
+
+		export type openExternal = (operation: u32, uri: Uri) => void;
+
+		export type asExternalUri = (operation: u32, uri: Uri) => void;
+	}
+	export type Env = {
+		appName: Env.appName;
+		appRoot: Env.appRoot;
+		appHost: Env.appHost;
+		getUiKind: Env.getUiKind;
+		language: Env.language;
+		machineId: Env.machineId;
+		sessionId: Env.sessionId;
+		remoteName: Env.remoteName;
+		shell: Env.shell;
+		uriScheme: Env.uriScheme;
+		isTelemetryEnabled: Env.isTelemetryEnabled;
+		clipboardReadText: Env.clipboardReadText;
+		clipboardWriteText: Env.clipboardWriteText;
+		openExternal: Env.openExternal;
+		asExternalUri: Env.asExternalUri;
+	};
+
+	export namespace Commands {
+		export type ContextValue = api.Types.ContextValue;
+		export const ContextValue = api.Types.ContextValue;
+
+		export type registerCommand = (command: string) => void;
+
+		export type registerTextEditorCommand = (command: string) => void;
+
+		export type unregisterCommand = (command: string) => void;
+
+		/**
+		 * Executes a command with JSON encoded arguments. The JSON encoded
+		 * result is reported with `complete-operation`.
+		 */
+		export type executeCommand = (operation: u32, command: string, arguments: string) => void;
+
+		/**
+		 * Reports the JSON encoded list of command ids with `complete-operation`.
+		 */
+		export type getCommands = (operation: u32, filterInternal: boolean) => void;
+
+		/**
+		 * Sets a context key for when clauses. Runs the `setContext` command
+		 * without waiting for it.
+		 */
+		export type setContext = (key: string, value: ContextValue) => void;
+	}
+	export type Commands = {
+		registerCommand: Commands.registerCommand;
+		registerTextEditorCommand: Commands.registerTextEditorCommand;
+		unregisterCommand: Commands.unregisterCommand;
+		executeCommand: Commands.executeCommand;
+		getCommands: Commands.getCommands;
+		setContext: Commands.setContext;
+	};
+
+	export namespace Languages {
+		export type Uri = api.Types.Uri;
+
+		export type DocumentSelector = api.Types.DocumentSelector;
+		export const DocumentSelector = api.Types.DocumentSelector;
+
+		export type TextDocument = api.Types.TextDocument;
+
+		export type Diagnostic = api.Types.Diagnostic;
+
+		export type DiagnosticCollection = api.Types.DiagnosticCollection;
+
+		export type CodeActionProviderMetadata = api.Types.CodeActionProviderMetadata;
+
+		export type SemanticTokensLegend = api.Types.SemanticTokensLegend;
+
+		export type SignatureHelpProviderMetadata = api.Types.SignatureHelpProviderMetadata;
+
+		export type LanguageConfiguration = api.Types.LanguageConfiguration;
+
+		export type LanguageStatusItem = api.Types.LanguageStatusItem;
+
+		export type matchSelector = (selector: DocumentSelector, document: own<TextDocument>) => u32;
+
+		export type unregisterProvider = (provider: u32) => void;
+
+		export type fireDidChangeProvider = (provider: u32) => void;
+
+		export type createDiagnosticCollection = (name: string | undefined) => own<DiagnosticCollection>;
+
+		export type getDiagnostics = (uri: Uri) => Diagnostic[];
+
+		export type registerOnDidChangeDiagnostics = () => void;
+
+		export type unregisterOnDidChangeDiagnostics = () => void;
+
+		export type registerCodeActionsProvider = (provider: u32, selector: DocumentSelector, metadata: CodeActionProviderMetadata | undefined) => void;
+
+		export type registerDocumentFormattingEditProvider = (provider: u32, selector: DocumentSelector) => void;
+
+		export type registerDocumentRangeFormattingEditProvider = (provider: u32, selector: DocumentSelector) => void;
+
+		export type registerOnTypeFormattingEditProvider = (provider: u32, selector: DocumentSelector, firstTriggerCharacter: string, moreTriggerCharacter: string[]) => void;
+
+		export type registerDocumentSemanticTokensProvider = (provider: u32, selector: DocumentSelector, legend: SemanticTokensLegend) => void;
+
+		export type registerDocumentRangeSemanticTokensProvider = (provider: u32, selector: DocumentSelector, legend: SemanticTokensLegend) => void;
+
+		export type registerDocumentSymbolProvider = (provider: u32, selector: DocumentSelector) => void;
+
+		export type registerWorkspaceSymbolProvider = (provider: u32) => void;
+
+		export type registerDefinitionProvider = (provider: u32, selector: DocumentSelector) => void;
+
+		export type registerDeclarationProvider = (provider: u32, selector: DocumentSelector) => void;
+
+		export type registerTypeDefinitionProvider = (provider: u32, selector: DocumentSelector) => void;
+
+		export type registerImplementationProvider = (provider: u32, selector: DocumentSelector) => void;
+
+		export type registerReferenceProvider = (provider: u32, selector: DocumentSelector) => void;
+
+		export type registerDocumentHighlightProvider = (provider: u32, selector: DocumentSelector) => void;
+
+		export type registerRenameProvider = (provider: u32, selector: DocumentSelector) => void;
+
+		export type registerFoldingRangeProvider = (provider: u32, selector: DocumentSelector) => void;
+
+		export type registerSelectionRangeProvider = (provider: u32, selector: DocumentSelector) => void;
+
+		export type registerInlayHintsProvider = (provider: u32, selector: DocumentSelector) => void;
+
+		export type registerCodeLensProvider = (provider: u32, selector: DocumentSelector) => void;
+
+		export type registerSignatureHelpProvider = (provider: u32, selector: DocumentSelector, metadata: SignatureHelpProviderMetadata) => void;
+
+		export type registerCallHierarchyProvider = (provider: u32, selector: DocumentSelector) => void;
+
+		export type registerTypeHierarchyProvider = (provider: u32, selector: DocumentSelector) => void;
+
+		export type registerColorProvider = (provider: u32, selector: DocumentSelector) => void;
+
+		export type registerDocumentLinkProvider = (provider: u32, selector: DocumentSelector) => void;
+
+		export type registerLinkedEditingRangeProvider = (provider: u32, selector: DocumentSelector) => void;
+
+		export type registerInlineCompletionItemProvider = (provider: u32, selector: DocumentSelector) => void;
+
+		export type setLanguageConfiguration = (registration: u32, languageId: string, configuration: LanguageConfiguration) => void;
+
+		export type getLanguages = () => string[];
+
+		/**
+		 * Reports completion with `complete-operation`, passing the uri of the
+		 * reopened document.
+		 */
+		export type setTextDocumentLanguage = (operation: u32, document: TextDocument, languageId: string) => void;
+
+		export type createLanguageStatusItem = (id: string, selector: DocumentSelector) => own<LanguageStatusItem>;
+	}
+	export type Languages = {
+		matchSelector: Languages.matchSelector;
+		unregisterProvider: Languages.unregisterProvider;
+		fireDidChangeProvider: Languages.fireDidChangeProvider;
+		createDiagnosticCollection: Languages.createDiagnosticCollection;
+		getDiagnostics: Languages.getDiagnostics;
+		registerOnDidChangeDiagnostics: Languages.registerOnDidChangeDiagnostics;
+		unregisterOnDidChangeDiagnostics: Languages.unregisterOnDidChangeDiagnostics;
+		registerCodeActionsProvider: Languages.registerCodeActionsProvider;
+		registerDocumentFormattingEditProvider: Languages.registerDocumentFormattingEditProvider;
+		registerDocumentRangeFormattingEditProvider: Languages.registerDocumentRangeFormattingEditProvider;
+		registerOnTypeFormattingEditProvider: Languages.registerOnTypeFormattingEditProvider;
+		registerDocumentSemanticTokensProvider: Languages.registerDocumentSemanticTokensProvider;
+		registerDocumentRangeSemanticTokensProvider: Languages.registerDocumentRangeSemanticTokensProvider;
+		registerDocumentSymbolProvider: Languages.registerDocumentSymbolProvider;
+		registerWorkspaceSymbolProvider: Languages.registerWorkspaceSymbolProvider;
+		registerDefinitionProvider: Languages.registerDefinitionProvider;
+		registerDeclarationProvider: Languages.registerDeclarationProvider;
+		registerTypeDefinitionProvider: Languages.registerTypeDefinitionProvider;
+		registerImplementationProvider: Languages.registerImplementationProvider;
+		registerReferenceProvider: Languages.registerReferenceProvider;
+		registerDocumentHighlightProvider: Languages.registerDocumentHighlightProvider;
+		registerRenameProvider: Languages.registerRenameProvider;
+		registerFoldingRangeProvider: Languages.registerFoldingRangeProvider;
+		registerSelectionRangeProvider: Languages.registerSelectionRangeProvider;
+		registerInlayHintsProvider: Languages.registerInlayHintsProvider;
+		registerCodeLensProvider: Languages.registerCodeLensProvider;
+		registerSignatureHelpProvider: Languages.registerSignatureHelpProvider;
+		registerCallHierarchyProvider: Languages.registerCallHierarchyProvider;
+		registerTypeHierarchyProvider: Languages.registerTypeHierarchyProvider;
+		registerColorProvider: Languages.registerColorProvider;
+		registerDocumentLinkProvider: Languages.registerDocumentLinkProvider;
+		registerLinkedEditingRangeProvider: Languages.registerLinkedEditingRangeProvider;
+		registerInlineCompletionItemProvider: Languages.registerInlineCompletionItemProvider;
+		setLanguageConfiguration: Languages.setLanguageConfiguration;
+		getLanguages: Languages.getLanguages;
+		setTextDocumentLanguage: Languages.setTextDocumentLanguage;
+		createLanguageStatusItem: Languages.createLanguageStatusItem;
+	};
+
+	export namespace Window {
+		export type OutputChannel = api.Types.OutputChannel;
+
+		export type createOutputChannel = (name: string, languageId: string | undefined) => own<OutputChannel>;
+
+		/**
+		 * The following report the JSON encoded selected item, or null if none
+		 * was selected, with `complete-operation`.
+		 */
+		export type showInformationMessage = (operation: u32, message: string, items: string[]) => void;
+
+		export type showWarningMessage = (operation: u32, message: string, items: string[]) => void;
+
+		export type showErrorMessage = (operation: u32, message: string, items: string[]) => void;
+
+		export type showQuickPick = (operation: u32, items: string[]) => void;
+	}
+	export type Window = {
+		createOutputChannel: Window.createOutputChannel;
+		showInformationMessage: Window.showInformationMessage;
+		showWarningMessage: Window.showWarningMessage;
+		showErrorMessage: Window.showErrorMessage;
+		showQuickPick: Window.showQuickPick;
+	};
+
+	export namespace Workspace {
+		export type TextDocument = api.Types.TextDocument;
+
+		export type textDocuments = () => own<TextDocument>[];
+
+		export type registerOnDidChangeTextDocument = () => void;
+
+		export type unregisterOnDidChangeTextDocument = () => void;
+	}
+	export type Workspace = {
+		textDocuments: Workspace.textDocuments;
+		registerOnDidChangeTextDocument: Workspace.registerOnDidChangeTextDocument;
+		unregisterOnDidChangeTextDocument: Workspace.unregisterOnDidChangeTextDocument;
+	};
+
+	export namespace Callbacks {
+		export type TextDocumentChangeEvent = api.Types.TextDocumentChangeEvent;
+
+		export type DiagnosticChangeEvent = api.Types.DiagnosticChangeEvent;
+
+		export type SecretStorageChangeEvent = api.Types.SecretStorageChangeEvent;
+
+		export type TextDocument = api.Types.TextDocument;
+
+		export type TextEditor = api.Types.TextEditor;
+
+		export type Position = api.Types.Position;
+
+		export type Range = api.Types.Range;
+
+		export type CancellationToken = api.Types.CancellationToken;
+
+		export type CodeActionContext = api.Types.CodeActionContext;
+
+		export type CodeAction = api.Types.CodeAction;
+
+		export type FormattingOptions = api.Types.FormattingOptions;
+
+		export type TextEdit = api.Types.TextEdit;
+
+		export type SemanticTokens = api.Types.SemanticTokens;
+
+		export type SemanticTokensResult = api.Types.SemanticTokensResult;
+		export const SemanticTokensResult = api.Types.SemanticTokensResult;
+
+		export type DocumentSymbolNode = api.Types.DocumentSymbolNode;
+
+		export type SymbolInformation = api.Types.SymbolInformation;
+
+		export type Definition = api.Types.Definition;
+		export const Definition = api.Types.Definition;
+
+		export type Location = api.Types.Location;
+
+		export type ReferenceContext = api.Types.ReferenceContext;
+
+		export type DocumentHighlight = api.Types.DocumentHighlight;
+
+		export type PrepareRenameResult = api.Types.PrepareRenameResult;
+		export const PrepareRenameResult = api.Types.PrepareRenameResult;
+
+		export type WorkspaceEdit = api.Types.WorkspaceEdit;
+
+		export type FoldingRange = api.Types.FoldingRange;
+
+		export type InlayHint = api.Types.InlayHint;
+
+		export type CodeLens = api.Types.CodeLens;
+
+		export type SignatureHelpContext = api.Types.SignatureHelpContext;
+
+		export type SignatureHelp = api.Types.SignatureHelp;
+
+		export type HierarchyItem = api.Types.HierarchyItem;
+
+		export type CallHierarchyIncomingCall = api.Types.CallHierarchyIncomingCall;
+
+		export type CallHierarchyOutgoingCall = api.Types.CallHierarchyOutgoingCall;
+
+		export type Color = api.Types.Color;
+
+		export type ColorInformation = api.Types.ColorInformation;
+
+		export type ColorPresentation = api.Types.ColorPresentation;
+
+		export type DocumentLink = api.Types.DocumentLink;
+
+		export type LinkedEditingRanges = api.Types.LinkedEditingRanges;
+
+		export type InlineCompletionContext = api.Types.InlineCompletionContext;
+
+		export type InlineCompletionItem = api.Types.InlineCompletionItem;
+
+		export type didChangeTextDocument = (event: own<TextDocumentChangeEvent>) => void;
+
+		export type didChangeDiagnostics = (event: DiagnosticChangeEvent) => void;
+
+		export type didChangeSecret = (event: SecretStorageChangeEvent) => void;
+
+		/**
+		 * Arguments and result are JSON encoded. The error is a message.
+		 *
+		 * @throws $wcm.wstring.Error
+		 */
+		export type executeCommand = (command: string, arguments: string) => string;
+
+		/**
+		 * The arguments are JSON encoded. Returns the edits to apply to the editor.
+		 *
+		 * @throws $wcm.wstring.Error
+		 */
+		export type executeTextEditorCommand = (command: string, editor: own<TextEditor>, arguments: string) => TextEdit[];
+
+		/**
+		 * Completes an asynchronous host operation. The value is JSON encoded,
+		 * the error is a message.
+		 */
+		export type completeOperation = (operation: u32, outcome: result<string, string>) => void;
+
+		export type provideCodeActions = (provider: u32, document: own<TextDocument>, range: Range, context: CodeActionContext, token: own<CancellationToken>) => CodeAction[] | undefined;
+
+		export type resolveCodeAction = (provider: u32, action: CodeAction, token: own<CancellationToken>) => CodeAction;
+
+		export type provideDocumentFormattingEdits = (provider: u32, document: own<TextDocument>, options: FormattingOptions, token: own<CancellationToken>) => TextEdit[];
+
+		export type provideDocumentRangeFormattingEdits = (provider: u32, document: own<TextDocument>, range: Range, options: FormattingOptions, token: own<CancellationToken>) => TextEdit[];
+
+		export type provideOnTypeFormattingEdits = (provider: u32, document: own<TextDocument>, position: Position, ch: string, options: FormattingOptions, token: own<CancellationToken>) => TextEdit[];
+
+		export type provideDocumentSemanticTokens = (provider: u32, document: own<TextDocument>, token: own<CancellationToken>) => SemanticTokens | undefined;
+
+		export type provideDocumentSemanticTokensEdits = (provider: u32, document: own<TextDocument>, previousResultId: string, token: own<CancellationToken>) => SemanticTokensResult | undefined;
+
+		export type provideDocumentRangeSemanticTokens = (provider: u32, document: own<TextDocument>, range: Range, token: own<CancellationToken>) => SemanticTokens | undefined;
+
+		export type provideDocumentSymbols = (provider: u32, document: own<TextDocument>, token: own<CancellationToken>) => DocumentSymbolNode[] | undefined;
+
+		export type provideWorkspaceSymbols = (provider: u32, query: string, token: own<CancellationToken>) => SymbolInformation[] | undefined;
+
+		export type resolveWorkspaceSymbol = (provider: u32, symbol: SymbolInformation, token: own<CancellationToken>) => SymbolInformation;
+
+		export type provideDefinition = (provider: u32, document: own<TextDocument>, position: Position, token: own<CancellationToken>) => Definition | undefined;
+
+		export type provideDeclaration = (provider: u32, document: own<TextDocument>, position: Position, token: own<CancellationToken>) => Definition | undefined;
+
+		export type provideTypeDefinition = (provider: u32, document: own<TextDocument>, position: Position, token: own<CancellationToken>) => Definition | undefined;
+
+		export type provideImplementation = (provider: u32, document: own<TextDocument>, position: Position, token: own<CancellationToken>) => Definition | undefined;
+
+		export type provideReferences = (provider: u32, document: own<TextDocument>, position: Position, context: ReferenceContext, token: own<CancellationToken>) => Location[] | undefined;
+
+		export type provideDocumentHighlights = (provider: u32, document: own<TextDocument>, position: Position, token: own<CancellationToken>) => DocumentHighlight[] | undefined;
+
+		export type prepareRename = (provider: u32, document: own<TextDocument>, position: Position, token: own<CancellationToken>) => PrepareRenameResult | undefined;
+
+		export type provideRenameEdits = (provider: u32, document: own<TextDocument>, position: Position, newName: string, token: own<CancellationToken>) => WorkspaceEdit | undefined;
+
+		export type provideFoldingRanges = (provider: u32, document: own<TextDocument>, token: own<CancellationToken>) => FoldingRange[] | undefined;
+
+		/**
+		 * Returns one selection range chain per position, each ordered from
+		 * the innermost to the outermost range.
+		 */
+		export type provideSelectionRanges = (provider: u32, document: own<TextDocument>, positions: Position[], token: own<CancellationToken>) => Range[][] | undefined;
+
+		export type provideInlayHints = (provider: u32, document: own<TextDocument>, range: Range, token: own<CancellationToken>) => InlayHint[] | undefined;
+
+		export type resolveInlayHint = (provider: u32, hint: InlayHint, token: own<CancellationToken>) => InlayHint;
+
+		export type provideCodeLenses = (provider: u32, document: own<TextDocument>, token: own<CancellationToken>) => CodeLens[] | undefined;
+
+		export type resolveCodeLens = (provider: u32, lens: CodeLens, token: own<CancellationToken>) => CodeLens;
+
+		export type provideSignatureHelp = (provider: u32, document: own<TextDocument>, position: Position, context: SignatureHelpContext, token: own<CancellationToken>) => SignatureHelp | undefined;
+
+		export type prepareCallHierarchy = (provider: u32, document: own<TextDocument>, position: Position, token: own<CancellationToken>) => HierarchyItem[] | undefined;
+
+		export type provideCallHierarchyIncomingCalls = (provider: u32, item: u32, token: own<CancellationToken>) => CallHierarchyIncomingCall[] | undefined;
+
+		export type provideCallHierarchyOutgoingCalls = (provider: u32, item: u32, token: own<CancellationToken>) => CallHierarchyOutgoingCall[] | undefined;
+
+		export type prepareTypeHierarchy = (provider: u32, document: own<TextDocument>, position: Position, token: own<CancellationToken>) => HierarchyItem[] | undefined;
+
+		export type provideTypeHierarchySupertypes = (provider: u32, item: u32, token: own<CancellationToken>) => HierarchyItem[] | undefined;
+
+		export type provideTypeHierarchySubtypes = (provider: u32, item: u32, token: own<CancellationToken>) => HierarchyItem[] | undefined;
 
-		export type textDocuments = () => own<TextDocument>[];
+		/**
+		 * Called once the host no longer references a call or type hierarchy
+		 * item.
+		 */
+		export type releaseHierarchyItem = (provider: u32, item: u32) => void;
 
-		export type registerOnDidChangeTextDocument = () => void;
+		export type provideDocumentColors = (provider: u32, document: own<TextDocument>, token: own<CancellationToken>) => ColorInformation[] | undefined;
 
-		export type unregisterOnDidChangeTextDocument = () => void;
-	}
-	export type Workspace = {
-		textDocuments: Workspace.textDocuments;
-		registerOnDidChangeTextDocument: Workspace.registerOnDidChangeTextDocument;
-		unregisterOnDidChangeTextDocument: Workspace.unregisterOnDidChangeTextDocument;
-	};
+		export type provideColorPresentations = (provider: u32, color: Color, document: own<TextDocument>, range: Range, token: own<CancellationToken>) => ColorPresentation[] | undefined;
 
-	export namespace Callbacks {
-		export type TextDocumentChangeEvent = api.Types.TextDocumentChangeEvent;
+		export type provideDocumentLinks = (provider: u32, document: own<TextDocument>, token: own<CancellationToken>) => DocumentLink[] | undefined;
 
-		export type didChangeTextDocument = (event: own<TextDocumentChangeEvent>) => void;
+		export type resolveDocumentLink = (provider: u32, link: DocumentLink, token: own<CancellationToken>) => DocumentLink;
 
-		export type executeCommand = (command: string) => void;
+		export type provideLinkedEditingRanges = (provider: u32, document: own<TextDocument>, position: Position, token: own<CancellationToken>) => LinkedEditingRanges | undefined;
+
+		export type provideInlineCompletionItems = (provider: u32, document: own<TextDocument>, position: Position, context: InlineCompletionContext, token: own<CancellationToken>) => InlineCompletionItem[] | undefined;
 	}
 	export type Callbacks = {
 		didChangeTextDocument: Callbacks.didChangeTextDocument;
+		didChangeDiagnostics: Callbacks.didChangeDiagnostics;
+		didChangeSecret: Callbacks.didChangeSecret;
 		executeCommand: Callbacks.executeCommand;
+		executeTextEditorCommand: Callbacks.executeTextEditorCommand;
+		completeOperation: Callbacks.completeOperation;
+		provideCodeActions: Callbacks.provideCodeActions;
+		resolveCodeAction: Callbacks.resolveCodeAction;
+		provideDocumentFormattingEdits: Callbacks.provideDocumentFormattingEdits;
+		provideDocumentRangeFormattingEdits: Callbacks.provideDocumentRangeFormattingEdits;
+		provideOnTypeFormattingEdits: Callbacks.provideOnTypeFormattingEdits;
+		provideDocumentSemanticTokens: Callbacks.provideDocumentSemanticTokens;
+		provideDocumentSemanticTokensEdits: Callbacks.provideDocumentSemanticTokensEdits;
+		provideDocumentRangeSemanticTokens: Callbacks.provideDocumentRangeSemanticTokens;
+		provideDocumentSymbols: Callbacks.provideDocumentSymbols;
+		provideWorkspaceSymbols: Callbacks.provideWorkspaceSymbols;
+		resolveWorkspaceSymbol: Callbacks.resolveWorkspaceSymbol;
+		provideDefinition: Callbacks.provideDefinition;
+		provideDeclaration: Callbacks.provideDeclaration;
+		provideTypeDefinition: Callbacks.provideTypeDefinition;
+		provideImplementation: Callbacks.provideImplementation;
+		provideReferences: Callbacks.provideReferences;
+		provideDocumentHighlights: Callbacks.provideDocumentHighlights;
+		prepareRename: Callbacks.prepareRename;
+		provideRenameEdits: Callbacks.provideRenameEdits;
+		provideFoldingRanges: Callbacks.provideFoldingRanges;
+		provideSelectionRanges: Callbacks.provideSelectionRanges;
+		provideInlayHints: Callbacks.provideInlayHints;
+		resolveInlayHint: Callbacks.resolveInlayHint;
+		provideCodeLenses: Callbacks.provideCodeLenses;
+		resolveCodeLens: Callbacks.resolveCodeLens;
+		provideSignatureHelp: Callbacks.provideSignatureHelp;
+		prepareCallHierarchy: Callbacks.prepareCallHierarchy;
+		provideCallHierarchyIncomingCalls: Callbacks.provideCallHierarchyIncomingCalls;
+		provideCallHierarchyOutgoingCalls: Callbacks.provideCallHierarchyOutgoingCalls;
+		prepareTypeHierarchy: Callbacks.prepareTypeHierarchy;
+		provideTypeHierarchySupertypes: Callbacks.provideTypeHierarchySupertypes;
+		provideTypeHierarchySubtypes: Callbacks.provideTypeHierarchySubtypes;
+		releaseHierarchyItem: Callbacks.releaseHierarchyItem;
+		provideDocumentColors: Callbacks.provideDocumentColors;
+		provideColorPresentations: Callbacks.provideColorPresentations;
+		provideDocumentLinks: Callbacks.provideDocumentLinks;
+		resolveDocumentLink: Callbacks.resolveDocumentLink;
+		provideLinkedEditingRanges: Callbacks.provideLinkedEditingRanges;
+		provideInlineCompletionItems: Callbacks.provideInlineCompletionItems;
 	};
 	export namespace all {
+		export type HostExtensionContext = Types.ExtensionContext;
 		export type Imports = {
 			types: api.Types;
 			workspace: api.Workspace;
+			env: api.Env;
 			commands: api.Commands;
 			window: api.Window;
 			languages: api.Languages;
@@ -239,6 +1670,8 @@ export namespace api {
 			export type Promisify<T> = $wcm.$imports.Promisify<T>;
 		}
 		export type Exports = {
+			activate: (context: HostExtensionContext) => void;
+			deactivate: () => void;
 			callbacks: api.Callbacks;
 		};
 		export namespace Exports {
@@ -252,6 +1685,13 @@ export namespace api {
 
 export namespace api {
 	export namespace Types.$ {
+		export const Uri = new $wcm.RecordType<api.Types.Uri>([
+			['scheme', $wcm.wstring],
+			['authority', $wcm.wstring],
+			['path', $wcm.wstring],
+			['query', $wcm.wstring],
+			['fragment', $wcm.wstring],
+		]);
 		export const Position = new $wcm.RecordType<api.Types.Position>([
 			['line', $wcm.u32],
 			['character', $wcm.u32],
@@ -260,6 +1700,30 @@ export namespace api {
 			['start', Position],
 			['end', Position],
 		]);
+		export const Selection = new $wcm.RecordType<api.Types.Selection>([
+			['anchor', Position],
+			['active', Position],
+		]);
+		export const UiKind = new $wcm.EnumType<api.Types.UiKind>(['desktop', 'web']);
+		export const ExtensionMode = new $wcm.EnumType<api.Types.ExtensionMode>(['production', 'development', 'test']);
+		export const Memento = new $wcm.ResourceType<api.Types.Memento>('memento', 'host:api/types/memento');
+		export const Memento_Handle = new $wcm.ResourceHandleType('memento');
+		export const SecretStorageChangeEvent = new $wcm.RecordType<api.Types.SecretStorageChangeEvent>([
+			['key', $wcm.wstring],
+		]);
+		export const SecretStorage = new $wcm.ResourceType<api.Types.SecretStorage>('secret-storage', 'host:api/types/secret-storage');
+		export const SecretStorage_Handle = new $wcm.ResourceHandleType('secret-storage');
+		export const ExtensionContext = new $wcm.RecordType<api.Types.ExtensionContext>([
+			['globalState', new $wcm.OwnType<api.Types.Memento>(Memento)],
+			['workspaceState', new $wcm.OwnType<api.Types.Memento>(Memento)],
+			['secrets', new $wcm.OwnType<api.Types.SecretStorage>(SecretStorage)],
+			['extensionUri', Uri],
+			['extensionPath', $wcm.wstring],
+			['extensionMode', ExtensionMode],
+			['storageUri', new $wcm.OptionType<api.Types.Uri>(Uri)],
+			['globalStorageUri', Uri],
+			['logUri', Uri],
+		]);
 		export const TextDocumentContentChangeEvent = new $wcm.RecordType<api.Types.TextDocumentContentChangeEvent>([
 			['range', Range],
 			['rangeOffset', $wcm.u32],
@@ -269,6 +1733,8 @@ export namespace api {
 		export const TextDocument = new $wcm.ResourceType<api.Types.TextDocument>('text-document', 'host:api/types/text-document');
 		export const TextDocument_Handle = new $wcm.ResourceHandleType('text-document');
 		export const TextDocumentChangeReason = new $wcm.EnumType<api.Types.TextDocumentChangeReason>(['undo', 'redo']);
+		export const TextEditor = new $wcm.ResourceType<api.Types.TextEditor>('text-editor', 'host:api/types/text-editor');
+		export const TextEditor_Handle = new $wcm.ResourceHandleType('text-editor');
 		export const TextDocumentChangeEvent = new $wcm.ResourceType<api.Types.TextDocumentChangeEvent>('text-document-change-event', 'host:api/types/text-document-change-event');
 		export const TextDocumentChangeEvent_Handle = new $wcm.ResourceHandleType('text-document-change-event');
 		export const OutputChannel = new $wcm.ResourceType<api.Types.OutputChannel>('output-channel', 'host:api/types/output-channel');
@@ -281,11 +1747,337 @@ export namespace api {
 			['pattern', new $wcm.OptionType<api.Types.GlobPattern>(GlobPattern)],
 		]);
 		export const DocumentSelector = new $wcm.VariantType<api.Types.DocumentSelector, api.Types.DocumentSelector._tt, api.Types.DocumentSelector._vt>([['many', new $wcm.ListType<api.Types.DocumentFilter>(DocumentFilter)], ['single', DocumentFilter]], api.Types.DocumentSelector._ctor);
+		export const Location = new $wcm.RecordType<api.Types.Location>([
+			['uri', Uri],
+			['range', Range],
+		]);
+		export const LocationLink = new $wcm.RecordType<api.Types.LocationLink>([
+			['originSelectionRange', new $wcm.OptionType<api.Types.Range>(Range)],
+			['targetUri', Uri],
+			['targetRange', Range],
+			['targetSelectionRange', new $wcm.OptionType<api.Types.Range>(Range)],
+		]);
+		export const Definition = new $wcm.VariantType<api.Types.Definition, api.Types.Definition._tt, api.Types.Definition._vt>([['locations', new $wcm.ListType<api.Types.Location>(Location)], ['links', new $wcm.ListType<api.Types.LocationLink>(LocationLink)]], api.Types.Definition._ctor);
+		export const DiagnosticSeverity = new $wcm.EnumType<api.Types.DiagnosticSeverity>(['error', 'warning', 'information', 'hint']);
+		export const DiagnosticTag = new $wcm.EnumType<api.Types.DiagnosticTag>(['unnecessary', 'deprecated']);
+		export const DiagnosticCodeValue = new $wcm.VariantType<api.Types.DiagnosticCodeValue, api.Types.DiagnosticCodeValue._tt, api.Types.DiagnosticCodeValue._vt>([['text', $wcm.wstring], ['number', $wcm.s32]], api.Types.DiagnosticCodeValue._ctor);
+		export const DiagnosticCode = new $wcm.RecordType<api.Types.DiagnosticCode>([
+			['value', DiagnosticCodeValue],
+			['target', new $wcm.OptionType<api.Types.Uri>(Uri)],
+		]);
+		export const DiagnosticRelatedInformation = new $wcm.RecordType<api.Types.DiagnosticRelatedInformation>([
+			['location', Location],
+			['message', $wcm.wstring],
+		]);
+		export const Diagnostic = new $wcm.RecordType<api.Types.Diagnostic>([
+			['range', Range],
+			['message', $wcm.wstring],
+			['severity', DiagnosticSeverity],
+			['source', new $wcm.OptionType<string>($wcm.wstring)],
+			['code', new $wcm.OptionType<api.Types.DiagnosticCode>(DiagnosticCode)],
+			['relatedInformation', new $wcm.ListType<api.Types.DiagnosticRelatedInformation>(DiagnosticRelatedInformation)],
+			['tags', new $wcm.ListType<api.Types.DiagnosticTag>(DiagnosticTag)],
+		]);
+		export const DiagnosticEntry = new $wcm.RecordType<api.Types.DiagnosticEntry>([
+			['uri', Uri],
+			['diagnostics', new $wcm.ListType<api.Types.Diagnostic>(Diagnostic)],
+		]);
+		export const DiagnosticChangeEvent = new $wcm.RecordType<api.Types.DiagnosticChangeEvent>([
+			['uris', new $wcm.ListType<api.Types.Uri>(Uri)],
+		]);
+		export const TextEdit = new $wcm.RecordType<api.Types.TextEdit>([
+			['range', Range],
+			['newText', $wcm.wstring],
+		]);
+		export const WorkspaceEditEntry = new $wcm.RecordType<api.Types.WorkspaceEditEntry>([
+			['uri', Uri],
+			['edits', new $wcm.ListType<api.Types.TextEdit>(TextEdit)],
+		]);
+		export const WorkspaceEdit = new $wcm.RecordType<api.Types.WorkspaceEdit>([
+			['entries', new $wcm.ListType<api.Types.WorkspaceEditEntry>(WorkspaceEditEntry)],
+		]);
+		export const Command = new $wcm.RecordType<api.Types.Command>([
+			['title', $wcm.wstring],
+			['command', $wcm.wstring],
+			['tooltip', new $wcm.OptionType<string>($wcm.wstring)],
+			['arguments', new $wcm.OptionType<string>($wcm.wstring)],
+		]);
+		export const CodeActionTriggerKind = new $wcm.EnumType<api.Types.CodeActionTriggerKind>(['invoke', 'automatic']);
+		export const CodeActionContext = new $wcm.RecordType<api.Types.CodeActionContext>([
+			['diagnostics', new $wcm.ListType<api.Types.Diagnostic>(Diagnostic)],
+			['only', new $wcm.OptionType<string>($wcm.wstring)],
+			['triggerKind', CodeActionTriggerKind],
+		]);
+		export const CodeAction = new $wcm.RecordType<api.Types.CodeAction>([
+			['title', $wcm.wstring],
+			['kind', new $wcm.OptionType<string>($wcm.wstring)],
+			['diagnostics', new $wcm.ListType<api.Types.Diagnostic>(Diagnostic)],
+			['edit', new $wcm.OptionType<api.Types.WorkspaceEdit>(WorkspaceEdit)],
+			['command', new $wcm.OptionType<api.Types.Command>(Command)],
+			['isPreferred', $wcm.bool],
+			['disabled', new $wcm.OptionType<string>($wcm.wstring)],
+		]);
+		export const CodeActionProviderMetadata = new $wcm.RecordType<api.Types.CodeActionProviderMetadata>([
+			['providedCodeActionKinds', new $wcm.ListType<string>($wcm.wstring)],
+		]);
+		export const FormattingOptionValue = new $wcm.VariantType<api.Types.FormattingOptionValue, api.Types.FormattingOptionValue._tt, api.Types.FormattingOptionValue._vt>([['boolean', $wcm.bool], ['number', $wcm.float64], ['text', $wcm.wstring]], api.Types.FormattingOptionValue._ctor);
+		export const ContextValue = new $wcm.VariantType<api.Types.ContextValue, api.Types.ContextValue._tt, api.Types.ContextValue._vt>([['boolean', $wcm.bool], ['number', $wcm.float64], ['text', $wcm.wstring], ['textList', new $wcm.ListType<string>($wcm.wstring)]], api.Types.ContextValue._ctor);
+		export const FormattingOption = new $wcm.RecordType<api.Types.FormattingOption>([
+			['key', $wcm.wstring],
+			['value', FormattingOptionValue],
+		]);
+		export const FormattingOptions = new $wcm.RecordType<api.Types.FormattingOptions>([
+			['tabSize', $wcm.u32],
+			['insertSpaces', $wcm.bool],
+			['properties', new $wcm.ListType<api.Types.FormattingOption>(FormattingOption)],
+		]);
+		export const SemanticTokensLegend = new $wcm.RecordType<api.Types.SemanticTokensLegend>([
+			['tokenTypes', new $wcm.ListType<string>($wcm.wstring)],
+			['tokenModifiers', new $wcm.ListType<string>($wcm.wstring)],
+		]);
+		export const SemanticTokens = new $wcm.RecordType<api.Types.SemanticTokens>([
+			['resultId', new $wcm.OptionType<string>($wcm.wstring)],
+			['data', new $wcm.Uint32ArrayType()],
+		]);
+		export const SemanticTokensEdit = new $wcm.RecordType<api.Types.SemanticTokensEdit>([
+			['start', $wcm.u32],
+			['deleteCount', $wcm.u32],
+			['data', new $wcm.OptionType<Uint32Array>(new $wcm.Uint32ArrayType())],
+		]);
+		export const SemanticTokensEdits = new $wcm.RecordType<api.Types.SemanticTokensEdits>([
+			['resultId', new $wcm.OptionType<string>($wcm.wstring)],
+			['edits', new $wcm.ListType<api.Types.SemanticTokensEdit>(SemanticTokensEdit)],
+		]);
+		export const SemanticTokensResult = new $wcm.VariantType<api.Types.SemanticTokensResult, api.Types.SemanticTokensResult._tt, api.Types.SemanticTokensResult._vt>([['full', SemanticTokens], ['edits', SemanticTokensEdits]], api.Types.SemanticTokensResult._ctor);
+		export const SymbolKind = new $wcm.EnumType<api.Types.SymbolKind>(['file', 'module', 'namespace', 'package', 'class', 'method', 'property', 'field', 'constructor', 'enum', 'interface', 'function', 'variable', 'constant', 'string', 'number', 'boolean', 'array', 'object', 'key', 'null', 'enumMember', 'struct', 'event', 'operator', 'typeParameter']);
+		export const SymbolTag = new $wcm.EnumType<api.Types.SymbolTag>(['deprecated']);
+		export const DocumentSymbolNode = new $wcm.RecordType<api.Types.DocumentSymbolNode>([
+			['name', $wcm.wstring],
+			['detail', $wcm.wstring],
+			['kind', SymbolKind],
+			['tags', new $wcm.ListType<api.Types.SymbolTag>(SymbolTag)],
+			['range', Range],
+			['selectionRange', Range],
+			['parent', new $wcm.OptionType<u32>($wcm.u32)],
+		]);
+		export const SymbolInformation = new $wcm.RecordType<api.Types.SymbolInformation>([
+			['name', $wcm.wstring],
+			['containerName', new $wcm.OptionType<string>($wcm.wstring)],
+			['kind', SymbolKind],
+			['tags', new $wcm.ListType<api.Types.SymbolTag>(SymbolTag)],
+			['location', Location],
+		]);
+		export const ReferenceContext = new $wcm.RecordType<api.Types.ReferenceContext>([
+			['includeDeclaration', $wcm.bool],
+		]);
+		export const DocumentHighlightKind = new $wcm.EnumType<api.Types.DocumentHighlightKind>(['text', 'read', 'write']);
+		export const DocumentHighlight = new $wcm.RecordType<api.Types.DocumentHighlight>([
+			['range', Range],
+			['kind', DocumentHighlightKind],
+		]);
+		export const RenameLocation = new $wcm.RecordType<api.Types.RenameLocation>([
+			['range', Range],
+			['placeholder', new $wcm.OptionType<string>($wcm.wstring)],
+		]);
+		export const PrepareRenameResult = new $wcm.VariantType<api.Types.PrepareRenameResult, api.Types.PrepareRenameResult._tt, api.Types.PrepareRenameResult._vt>([['accepted', RenameLocation], ['rejected', $wcm.wstring]], api.Types.PrepareRenameResult._ctor);
+		export const FoldingRangeKind = new $wcm.EnumType<api.Types.FoldingRangeKind>(['comment', 'imports', 'region']);
+		export const FoldingRange = new $wcm.RecordType<api.Types.FoldingRange>([
+			['start', $wcm.u32],
+			['end', $wcm.u32],
+			['kind', new $wcm.OptionType<api.Types.FoldingRangeKind>(FoldingRangeKind)],
+		]);
+		export const InlayHintKind = new $wcm.EnumType<api.Types.InlayHintKind>(['type', 'parameter']);
+		export const InlayHintLabelPart = new $wcm.RecordType<api.Types.InlayHintLabelPart>([
+			['value', $wcm.wstring],
+			['tooltip', new $wcm.OptionType<string>($wcm.wstring)],
+			['location', new $wcm.OptionType<api.Types.Location>(Location)],
+			['command', new $wcm.OptionType<api.Types.Command>(Command)],
+		]);
+		export const InlayHintLabel = new $wcm.VariantType<api.Types.InlayHintLabel, api.Types.InlayHintLabel._tt, api.Types.InlayHintLabel._vt>([['text', $wcm.wstring], ['parts', new $wcm.ListType<api.Types.InlayHintLabelPart>(InlayHintLabelPart)]], api.Types.InlayHintLabel._ctor);
+		export const InlayHint = new $wcm.RecordType<api.Types.InlayHint>([
+			['position', Position],
+			['label', InlayHintLabel],
+			['tooltip', new $wcm.OptionType<string>($wcm.wstring)],
+			['kind', new $wcm.OptionType<api.Types.InlayHintKind>(InlayHintKind)],
+			['paddingLeft', $wcm.bool],
+			['paddingRight', $wcm.bool],
+			['textEdits', new $wcm.ListType<api.Types.TextEdit>(TextEdit)],
+		]);
+		export const CodeLens = new $wcm.RecordType<api.Types.CodeLens>([
+			['range', Range],
+			['command', new $wcm.OptionType<api.Types.Command>(Command)],
+		]);
+		export const ParameterLabel = new $wcm.VariantType<api.Types.ParameterLabel, api.Types.ParameterLabel._tt, api.Types.ParameterLabel._vt>([['text', $wcm.wstring], ['offsets', new $wcm.TupleType<[u32, u32]>([$wcm.u32, $wcm.u32])]], api.Types.ParameterLabel._ctor);
+		export const ParameterInformation = new $wcm.RecordType<api.Types.ParameterInformation>([
+			['label', ParameterLabel],
+			['documentation', new $wcm.OptionType<string>($wcm.wstring)],
+		]);
+		export const SignatureInformation = new $wcm.RecordType<api.Types.SignatureInformation>([
+			['label', $wcm.wstring],
+			['documentation', new $wcm.OptionType<string>($wcm.wstring)],
+			['parameters', new $wcm.ListType<api.Types.ParameterInformation>(ParameterInformation)],
+			['activeParameter', new $wcm.OptionType<u32>($wcm.u32)],
+		]);
+		export const SignatureHelp = new $wcm.RecordType<api.Types.SignatureHelp>([
+			['signatures', new $wcm.ListType<api.Types.SignatureInformation>(SignatureInformation)],
+			['activeSignature', $wcm.u32],
+			['activeParameter', $wcm.u32],
+		]);
+		export const SignatureHelpTriggerKind = new $wcm.EnumType<api.Types.SignatureHelpTriggerKind>(['invoke', 'triggerCharacter', 'contentChange']);
+		export const SignatureHelpContext = new $wcm.RecordType<api.Types.SignatureHelpContext>([
+			['triggerKind', SignatureHelpTriggerKind],
+			['triggerCharacter', new $wcm.OptionType<string>($wcm.wstring)],
+			['isRetrigger', $wcm.bool],
+			['activeSignatureHelp', new $wcm.OptionType<api.Types.SignatureHelp>(SignatureHelp)],
+		]);
+		export const SignatureHelpProviderMetadata = new $wcm.RecordType<api.Types.SignatureHelpProviderMetadata>([
+			['triggerCharacters', new $wcm.ListType<string>($wcm.wstring)],
+			['retriggerCharacters', new $wcm.ListType<string>($wcm.wstring)],
+		]);
+		export const HierarchyItem = new $wcm.RecordType<api.Types.HierarchyItem>([
+			['handle', $wcm.u32],
+			['name', $wcm.wstring],
+			['kind', SymbolKind],
+			['tags', new $wcm.ListType<api.Types.SymbolTag>(SymbolTag)],
+			['detail', new $wcm.OptionType<string>($wcm.wstring)],
+			['uri', Uri],
+			['range', Range],
+			['selectionRange', Range],
+		]);
+		export const CallHierarchyIncomingCall = new $wcm.RecordType<api.Types.CallHierarchyIncomingCall>([
+			['from', HierarchyItem],
+			['fromRanges', new $wcm.ListType<api.Types.Range>(Range)],
+		]);
+		export const CallHierarchyOutgoingCall = new $wcm.RecordType<api.Types.CallHierarchyOutgoingCall>([
+			['to', HierarchyItem],
+			['fromRanges', new $wcm.ListType<api.Types.Range>(Range)],
+		]);
+		export const Color = new $wcm.RecordType<api.Types.Color>([
+			['red', $wcm.float32],
+			['green', $wcm.float32],
+			['blue', $wcm.float32],
+			['alpha', $wcm.float32],
+		]);
+		export const ColorInformation = new $wcm.RecordType<api.Types.ColorInformation>([
+			['range', Range],
+			['color', Color],
+		]);
+		export const ColorPresentation = new $wcm.RecordType<api.Types.ColorPresentation>([
+			['label', $wcm.wstring],
+			['textEdit', new $wcm.OptionType<api.Types.TextEdit>(TextEdit)],
+			['additionalTextEdits', new $wcm.ListType<api.Types.TextEdit>(TextEdit)],
+		]);
+		export const DocumentLink = new $wcm.RecordType<api.Types.DocumentLink>([
+			['range', Range],
+			['target', new $wcm.OptionType<api.Types.Uri>(Uri)],
+			['tooltip', new $wcm.OptionType<string>($wcm.wstring)],
+		]);
+		export const LinkedEditingRanges = new $wcm.RecordType<api.Types.LinkedEditingRanges>([
+			['ranges', new $wcm.ListType<api.Types.Range>(Range)],
+			['wordPattern', new $wcm.OptionType<string>($wcm.wstring)],
+		]);
+		export const InlineCompletionItem = new $wcm.RecordType<api.Types.InlineCompletionItem>([
+			['insertText', $wcm.wstring],
+			['filterText', new $wcm.OptionType<string>($wcm.wstring)],
+			['range', new $wcm.OptionType<api.Types.Range>(Range)],
+			['command', new $wcm.OptionType<api.Types.Command>(Command)],
+		]);
+		export const InlineCompletionTriggerKind = new $wcm.EnumType<api.Types.InlineCompletionTriggerKind>(['invoke', 'automatic']);
+		export const SelectedCompletionInfo = new $wcm.RecordType<api.Types.SelectedCompletionInfo>([
+			['range', Range],
+			['text', $wcm.wstring],
+		]);
+		export const InlineCompletionContext = new $wcm.RecordType<api.Types.InlineCompletionContext>([
+			['triggerKind', InlineCompletionTriggerKind],
+			['selectedCompletionInfo', new $wcm.OptionType<api.Types.SelectedCompletionInfo>(SelectedCompletionInfo)],
+		]);
+		export const CommentRule = new $wcm.RecordType<api.Types.CommentRule>([
+			['lineComment', new $wcm.OptionType<string>($wcm.wstring)],
+			['blockComment', new $wcm.OptionType<[string, string]>(new $wcm.TupleType<[string, string]>([$wcm.wstring, $wcm.wstring]))],
+		]);
+		export const AutoClosingPair = new $wcm.RecordType<api.Types.AutoClosingPair>([
+			['open', $wcm.wstring],
+			['close', $wcm.wstring],
+			['notIn', new $wcm.ListType<string>($wcm.wstring)],
+		]);
+		export const IndentationRule = new $wcm.RecordType<api.Types.IndentationRule>([
+			['decreaseIndentPattern', $wcm.wstring],
+			['increaseIndentPattern', $wcm.wstring],
+			['indentNextLinePattern', new $wcm.OptionType<string>($wcm.wstring)],
+			['unindentedLinePattern', new $wcm.OptionType<string>($wcm.wstring)],
+		]);
+		export const IndentAction = new $wcm.EnumType<api.Types.IndentAction>(['none', 'indent', 'indentOutdent', 'outdent']);
+		export const EnterAction = new $wcm.RecordType<api.Types.EnterAction>([
+			['indentAction', IndentAction],
+			['appendText', new $wcm.OptionType<string>($wcm.wstring)],
+			['removeText', new $wcm.OptionType<u32>($wcm.u32)],
+		]);
+		export const OnEnterRule = new $wcm.RecordType<api.Types.OnEnterRule>([
+			['beforeText', $wcm.wstring],
+			['afterText', new $wcm.OptionType<string>($wcm.wstring)],
+			['previousLineText', new $wcm.OptionType<string>($wcm.wstring)],
+			['action', EnterAction],
+		]);
+		export const LanguageConfiguration = new $wcm.RecordType<api.Types.LanguageConfiguration>([
+			['comments', new $wcm.OptionType<api.Types.CommentRule>(CommentRule)],
+			['brackets', new $wcm.ListType<[string, string]>(new $wcm.TupleType<[string, string]>([$wcm.wstring, $wcm.wstring]))],
+			['autoClosingPairs', new $wcm.ListType<api.Types.AutoClosingPair>(AutoClosingPair)],
+			['wordPattern', new $wcm.OptionType<string>($wcm.wstring)],
+			['indentationRules', new $wcm.OptionType<api.Types.IndentationRule>(IndentationRule)],
+			['onEnterRules', new $wcm.ListType<api.Types.OnEnterRule>(OnEnterRule)],
+		]);
+		export const LanguageStatusSeverity = new $wcm.EnumType<api.Types.LanguageStatusSeverity>(['information', 'warning', 'error']);
+		export const AccessibilityInformation = new $wcm.RecordType<api.Types.AccessibilityInformation>([
+			['label', $wcm.wstring],
+			['role', new $wcm.OptionType<string>($wcm.wstring)],
+		]);
+		export const CancellationToken = new $wcm.ResourceType<api.Types.CancellationToken>('cancellation-token', 'host:api/types/cancellation-token');
+		export const CancellationToken_Handle = new $wcm.ResourceHandleType('cancellation-token');
+		export const DiagnosticCollection = new $wcm.ResourceType<api.Types.DiagnosticCollection>('diagnostic-collection', 'host:api/types/diagnostic-collection');
+		export const DiagnosticCollection_Handle = new $wcm.ResourceHandleType('diagnostic-collection');
+		export const LanguageStatusItem = new $wcm.ResourceType<api.Types.LanguageStatusItem>('language-status-item', 'host:api/types/language-status-item');
+		export const LanguageStatusItem_Handle = new $wcm.ResourceHandleType('language-status-item');
+		Memento.addDestructor('$drop', new $wcm.DestructorType('[resource-drop]memento', [['inst', Memento]]));
+		Memento.addMethod('get', new $wcm.MethodType<api.Types.Memento.Interface['get']>('[method]memento.get', [
+			['key', $wcm.wstring],
+		], new $wcm.OptionType<string>($wcm.wstring)));
+		Memento.addMethod('keys', new $wcm.MethodType<api.Types.Memento.Interface['keys']>('[method]memento.keys', [], new $wcm.ListType<string>($wcm.wstring)));
+		Memento.addMethod('update', new $wcm.MethodType<api.Types.Memento.Interface['update']>('[method]memento.update', [
+			['operation', $wcm.u32],
+			['key', $wcm.wstring],
+			['value', new $wcm.OptionType<string>($wcm.wstring)],
+		], undefined));
+		Memento.addMethod('setKeysForSync', new $wcm.MethodType<api.Types.Memento.Interface['setKeysForSync']>('[method]memento.set-keys-for-sync', [
+			['keys', new $wcm.ListType<string>($wcm.wstring)],
+		], undefined));
+		SecretStorage.addDestructor('$drop', new $wcm.DestructorType('[resource-drop]secret-storage', [['inst', SecretStorage]]));
+		SecretStorage.addMethod('get', new $wcm.MethodType<api.Types.SecretStorage.Interface['get']>('[method]secret-storage.get', [
+			['operation', $wcm.u32],
+			['key', $wcm.wstring],
+		], undefined));
+		SecretStorage.addMethod('store', new $wcm.MethodType<api.Types.SecretStorage.Interface['store']>('[method]secret-storage.store', [
+			['operation', $wcm.u32],
+			['key', $wcm.wstring],
+			['value', $wcm.wstring],
+		], undefined));
+		SecretStorage.addMethod('delete', new $wcm.MethodType<api.Types.SecretStorage.Interface['delete']>('[method]secret-storage.delete', [
+			['operation', $wcm.u32],
+			['key', $wcm.wstring],
+		], undefined));
+		SecretStorage.addMethod('registerOnDidChange', new $wcm.MethodType<api.Types.SecretStorage.Interface['registerOnDidChange']>('[method]secret-storage.register-on-did-change', [], undefined));
+		SecretStorage.addMethod('unregisterOnDidChange', new $wcm.MethodType<api.Types.SecretStorage.Interface['unregisterOnDidChange']>('[method]secret-storage.unregister-on-did-change', [], undefined));
 		TextDocument.addDestructor('$drop', new $wcm.DestructorType('[resource-drop]text-document', [['inst', TextDocument]]));
-		TextDocument.addMethod('uri', new $wcm.MethodType<api.Types.TextDocument.Interface['uri']>('[method]text-document.uri', [], $wcm.wstring));
+		TextDocument.addMethod('uri', new $wcm.MethodType<api.Types.TextDocument.Interface['uri']>('[method]text-document.uri', [], Uri));
 		TextDocument.addMethod('languageId', new $wcm.MethodType<api.Types.TextDocument.Interface['languageId']>('[method]text-document.language-id', [], $wcm.wstring));
 		TextDocument.addMethod('version', new $wcm.MethodType<api.Types.TextDocument.Interface['version']>('[method]text-document.version', [], $wcm.u32));
 		TextDocument.addMethod('getText', new $wcm.MethodType<api.Types.TextDocument.Interface['getText']>('[method]text-document.get-text', [], $wcm.wstring));
+		TextEditor.addDestructor('$drop', new $wcm.DestructorType('[resource-drop]text-editor', [['inst', TextEditor]]));
+		TextEditor.addMethod('document', new $wcm.MethodType<api.Types.TextEditor.Interface['document']>('[method]text-editor.document', [], new $wcm.OwnType<api.Types.TextDocument>(TextDocument)));
+		TextEditor.addMethod('selection', new $wcm.MethodType<api.Types.TextEditor.Interface['selection']>('[method]text-editor.selection', [], Selection));
+		TextEditor.addMethod('selections', new $wcm.MethodType<api.Types.TextEditor.Interface['selections']>('[method]text-editor.selections', [], new $wcm.ListType<api.Types.Selection>(Selection)));
+		TextEditor.addMethod('setSelections', new $wcm.MethodType<api.Types.TextEditor.Interface['setSelections']>('[method]text-editor.set-selections', [
+			['selections', new $wcm.ListType<api.Types.Selection>(Selection)],
+		], undefined));
 		TextDocumentChangeEvent.addDestructor('$drop', new $wcm.DestructorType('[resource-drop]text-document-change-event', [['inst', TextDocumentChangeEvent]]));
 		TextDocumentChangeEvent.addMethod('document', new $wcm.MethodType<api.Types.TextDocumentChangeEvent.Interface['document']>('[method]text-document-change-event.document', [], new $wcm.OwnType<api.Types.TextDocument>(TextDocument)));
 		TextDocumentChangeEvent.addMethod('contentChanges', new $wcm.MethodType<api.Types.TextDocumentChangeEvent.Interface['contentChanges']>('[method]text-document-change-event.content-changes', [], new $wcm.ListType<api.Types.TextDocumentContentChangeEvent>(TextDocumentContentChangeEvent)));
@@ -300,13 +2092,95 @@ export namespace api {
 		], undefined));
 		OutputChannel.addMethod('clear', new $wcm.MethodType<api.Types.OutputChannel.Interface['clear']>('[method]output-channel.clear', [], undefined));
 		OutputChannel.addMethod('show', new $wcm.MethodType<api.Types.OutputChannel.Interface['show']>('[method]output-channel.show', [], undefined));
+		CancellationToken.addDestructor('$drop', new $wcm.DestructorType('[resource-drop]cancellation-token', [['inst', CancellationToken]]));
+		CancellationToken.addMethod('isCancellationRequested', new $wcm.MethodType<api.Types.CancellationToken.Interface['isCancellationRequested']>('[method]cancellation-token.is-cancellation-requested', [], $wcm.bool));
+		DiagnosticCollection.addDestructor('$drop', new $wcm.DestructorType('[resource-drop]diagnostic-collection', [['inst', DiagnosticCollection]]));
+		DiagnosticCollection.addMethod('name', new $wcm.MethodType<api.Types.DiagnosticCollection.Interface['name']>('[method]diagnostic-collection.name', [], $wcm.wstring));
+		DiagnosticCollection.addMethod('set', new $wcm.MethodType<api.Types.DiagnosticCollection.Interface['set']>('[method]diagnostic-collection.set', [
+			['uri', Uri],
+			['diagnostics', new $wcm.ListType<api.Types.Diagnostic>(Diagnostic)],
+		], undefined));
+		DiagnosticCollection.addMethod('delete', new $wcm.MethodType<api.Types.DiagnosticCollection.Interface['delete']>('[method]diagnostic-collection.delete', [
+			['uri', Uri],
+		], undefined));
+		DiagnosticCollection.addMethod('clear', new $wcm.MethodType<api.Types.DiagnosticCollection.Interface['clear']>('[method]diagnostic-collection.clear', [], undefined));
+		DiagnosticCollection.addMethod('get', new $wcm.MethodType<api.Types.DiagnosticCollection.Interface['get']>('[method]diagnostic-collection.get', [
+			['uri', Uri],
+		], new $wcm.ListType<api.Types.Diagnostic>(Diagnostic)));
+		DiagnosticCollection.addMethod('has', new $wcm.MethodType<api.Types.DiagnosticCollection.Interface['has']>('[method]diagnostic-collection.has', [
+			['uri', Uri],
+		], $wcm.bool));
+		DiagnosticCollection.addMethod('entries', new $wcm.MethodType<api.Types.DiagnosticCollection.Interface['entries']>('[method]diagnostic-collection.entries', [], new $wcm.ListType<api.Types.DiagnosticEntry>(DiagnosticEntry)));
+		LanguageStatusItem.addDestructor('$drop', new $wcm.DestructorType('[resource-drop]language-status-item', [['inst', LanguageStatusItem]]));
+		LanguageStatusItem.addMethod('id', new $wcm.MethodType<api.Types.LanguageStatusItem.Interface['id']>('[method]language-status-item.id', [], $wcm.wstring));
+		LanguageStatusItem.addMethod('name', new $wcm.MethodType<api.Types.LanguageStatusItem.Interface['name']>('[method]language-status-item.name', [], new $wcm.OptionType<string>($wcm.wstring)));
+		LanguageStatusItem.addMethod('setName', new $wcm.MethodType<api.Types.LanguageStatusItem.Interface['setName']>('[method]language-status-item.set-name', [
+			['name', new $wcm.OptionType<string>($wcm.wstring)],
+		], undefined));
+		LanguageStatusItem.addMethod('selector', new $wcm.MethodType<api.Types.LanguageStatusItem.Interface['selector']>('[method]language-status-item.selector', [], DocumentSelector));
+		LanguageStatusItem.addMethod('setSelector', new $wcm.MethodType<api.Types.LanguageStatusItem.Interface['setSelector']>('[method]language-status-item.set-selector', [
+			['selector', DocumentSelector],
+		], undefined));
+		LanguageStatusItem.addMethod('severity', new $wcm.MethodType<api.Types.LanguageStatusItem.Interface['severity']>('[method]language-status-item.severity', [], LanguageStatusSeverity));
+		LanguageStatusItem.addMethod('setSeverity', new $wcm.MethodType<api.Types.LanguageStatusItem.Interface['setSeverity']>('[method]language-status-item.set-severity', [
+			['severity', LanguageStatusSeverity],
+		], undefined));
+		LanguageStatusItem.addMethod('text', new $wcm.MethodType<api.Types.LanguageStatusItem.Interface['text']>('[method]language-status-item.text', [], $wcm.wstring));
+		LanguageStatusItem.addMethod('setText', new $wcm.MethodType<api.Types.LanguageStatusItem.Interface['setText']>('[method]language-status-item.set-text', [
+			['text', $wcm.wstring],
+		], undefined));
+		LanguageStatusItem.addMethod('detail', new $wcm.MethodType<api.Types.LanguageStatusItem.Interface['detail']>('[method]language-status-item.detail', [], new $wcm.OptionType<string>($wcm.wstring)));
+		LanguageStatusItem.addMethod('setDetail', new $wcm.MethodType<api.Types.LanguageStatusItem.Interface['setDetail']>('[method]language-status-item.set-detail', [
+			['detail', new $wcm.OptionType<string>($wcm.wstring)],
+		], undefined));
+		LanguageStatusItem.addMethod('busy', new $wcm.MethodType<api.Types.LanguageStatusItem.Interface['busy']>('[method]language-status-item.busy', [], $wcm.bool));
+		LanguageStatusItem.addMethod('setBusy', new $wcm.MethodType<api.Types.LanguageStatusItem.Interface['setBusy']>('[method]language-status-item.set-busy', [
+			['busy', $wcm.bool],
+		], undefined));
+		LanguageStatusItem.addMethod('command', new $wcm.MethodType<api.Types.LanguageStatusItem.Interface['command']>('[method]language-status-item.command', [], new $wcm.OptionType<api.Types.Command>(Command)));
+		LanguageStatusItem.addMethod('setCommand', new $wcm.MethodType<api.Types.LanguageStatusItem.Interface['setCommand']>('[method]language-status-item.set-command', [
+			['command', new $wcm.OptionType<api.Types.Command>(Command)],
+		], undefined));
+		LanguageStatusItem.addMethod('accessibilityInformation', new $wcm.MethodType<api.Types.LanguageStatusItem.Interface['accessibilityInformation']>('[method]language-status-item.accessibility-information', [], new $wcm.OptionType<api.Types.AccessibilityInformation>(AccessibilityInformation)));
+		LanguageStatusItem.addMethod('setAccessibilityInformation', new $wcm.MethodType<api.Types.LanguageStatusItem.Interface['setAccessibilityInformation']>('[method]language-status-item.set-accessibility-information', [
+			['accessibilityInformation', new $wcm.OptionType<api.Types.AccessibilityInformation>(AccessibilityInformation)],
+		], undefined));
 	}
 	export namespace Types._ {
 		export const id = 'host:api/types' as const;
 		export const witName = 'types' as const;
+		export namespace Memento {
+			export type WasmInterface = {
+				'[method]memento.get': (self: i32, key_ptr: i32, key_len: i32, result: ptr<string | undefined>) => void;
+				'[method]memento.keys': (self: i32, result: ptr<string[]>) => void;
+				'[method]memento.update': (self: i32, operation: i32, key_ptr: i32, key_len: i32, value_case: i32, value_option_ptr: i32, value_option_len: i32) => void;
+				'[method]memento.set-keys-for-sync': (self: i32, keys_ptr: i32, keys_len: i32) => void;
+			};
+			export namespace imports {
+				export type WasmInterface = Memento.WasmInterface & { '[resource-drop]memento': (self: i32) => void };
+			}
+			export namespace exports {
+				export type WasmInterface = Memento.WasmInterface & { '[dtor]memento': (self: i32) => void };
+			}
+		}
+		export namespace SecretStorage {
+			export type WasmInterface = {
+				'[method]secret-storage.get': (self: i32, operation: i32, key_ptr: i32, key_len: i32) => void;
+				'[method]secret-storage.store': (self: i32, operation: i32, key_ptr: i32, key_len: i32, value_ptr: i32, value_len: i32) => void;
+				'[method]secret-storage.delete': (self: i32, operation: i32, key_ptr: i32, key_len: i32) => void;
+				'[method]secret-storage.register-on-did-change': (self: i32) => void;
+				'[method]secret-storage.unregister-on-did-change': (self: i32) => void;
+			};
+			export namespace imports {
+				export type WasmInterface = SecretStorage.WasmInterface & { '[resource-drop]secret-storage': (self: i32) => void };
+			}
+			export namespace exports {
+				export type WasmInterface = SecretStorage.WasmInterface & { '[dtor]secret-storage': (self: i32) => void };
+			}
+		}
 		export namespace TextDocument {
 			export type WasmInterface = {
-				'[method]text-document.uri': (self: i32, result: ptr<string>) => void;
+				'[method]text-document.uri': (self: i32, result: ptr<Uri>) => void;
 				'[method]text-document.language-id': (self: i32, result: ptr<string>) => void;
 				'[method]text-document.version': (self: i32) => i32;
 				'[method]text-document.get-text': (self: i32, result: ptr<string>) => void;
@@ -318,6 +2192,20 @@ export namespace api {
 				export type WasmInterface = TextDocument.WasmInterface & { '[dtor]text-document': (self: i32) => void };
 			}
 		}
+		export namespace TextEditor {
+			export type WasmInterface = {
+				'[method]text-editor.document': (self: i32) => i32;
+				'[method]text-editor.selection': (self: i32, result: ptr<Selection>) => void;
+				'[method]text-editor.selections': (self: i32, result: ptr<Selection[]>) => void;
+				'[method]text-editor.set-selections': (self: i32, selections_ptr: i32, selections_len: i32) => void;
+			};
+			export namespace imports {
+				export type WasmInterface = TextEditor.WasmInterface & { '[resource-drop]text-editor': (self: i32) => void };
+			}
+			export namespace exports {
+				export type WasmInterface = TextEditor.WasmInterface & { '[dtor]text-editor': (self: i32) => void };
+			}
+		}
 		export namespace TextDocumentChangeEvent {
 			export type WasmInterface = {
 				'[method]text-document-change-event.document': (self: i32) => i32;
@@ -346,64 +2234,334 @@ export namespace api {
 				export type WasmInterface = OutputChannel.WasmInterface & { '[dtor]output-channel': (self: i32) => void };
 			}
 		}
+		export namespace CancellationToken {
+			export type WasmInterface = {
+				'[method]cancellation-token.is-cancellation-requested': (self: i32) => i32;
+			};
+			export namespace imports {
+				export type WasmInterface = CancellationToken.WasmInterface & { '[resource-drop]cancellation-token': (self: i32) => void };
+			}
+			export namespace exports {
+				export type WasmInterface = CancellationToken.WasmInterface & { '[dtor]cancellation-token': (self: i32) => void };
+			}
+		}
+		export namespace DiagnosticCollection {
+			export type WasmInterface = {
+				'[method]diagnostic-collection.name': (self: i32, result: ptr<string>) => void;
+				'[method]diagnostic-collection.set': (self: i32, uri_scheme_ptr: i32, uri_scheme_len: i32, uri_authority_ptr: i32, uri_authority_len: i32, uri_path_ptr: i32, uri_path_len: i32, uri_query_ptr: i32, uri_query_len: i32, uri_fragment_ptr: i32, uri_fragment_len: i32, diagnostics_ptr: i32, diagnostics_len: i32) => void;
+				'[method]diagnostic-collection.delete': (self: i32, uri_scheme_ptr: i32, uri_scheme_len: i32, uri_authority_ptr: i32, uri_authority_len: i32, uri_path_ptr: i32, uri_path_len: i32, uri_query_ptr: i32, uri_query_len: i32, uri_fragment_ptr: i32, uri_fragment_len: i32) => void;
+				'[method]diagnostic-collection.clear': (self: i32) => void;
+				'[method]diagnostic-collection.get': (self: i32, uri_scheme_ptr: i32, uri_scheme_len: i32, uri_authority_ptr: i32, uri_authority_len: i32, uri_path_ptr: i32, uri_path_len: i32, uri_query_ptr: i32, uri_query_len: i32, uri_fragment_ptr: i32, uri_fragment_len: i32, result: ptr<Diagnostic[]>) => void;
+				'[method]diagnostic-collection.has': (self: i32, uri_scheme_ptr: i32, uri_scheme_len: i32, uri_authority_ptr: i32, uri_authority_len: i32, uri_path_ptr: i32, uri_path_len: i32, uri_query_ptr: i32, uri_query_len: i32, uri_fragment_ptr: i32, uri_fragment_len: i32) => i32;
+				'[method]diagnostic-collection.entries': (self: i32, result: ptr<DiagnosticEntry[]>) => void;
+			};
+			export namespace imports {
+				export type WasmInterface = DiagnosticCollection.WasmInterface & { '[resource-drop]diagnostic-collection': (self: i32) => void };
+			}
+			export namespace exports {
+				export type WasmInterface = DiagnosticCollection.WasmInterface & { '[dtor]diagnostic-collection': (self: i32) => void };
+			}
+		}
+		export namespace LanguageStatusItem {
+			export type WasmInterface = {
+				'[method]language-status-item.id': (self: i32, result: ptr<string>) => void;
+				'[method]language-status-item.name': (self: i32, result: ptr<string | undefined>) => void;
+				'[method]language-status-item.set-name': (self: i32, name_case: i32, name_option_ptr: i32, name_option_len: i32) => void;
+				'[method]language-status-item.selector': (self: i32, result: ptr<DocumentSelector>) => void;
+				'[method]language-status-item.set-selector': (self: i32, selector_case: i32, selector_0: i32, selector_1: i32, selector_2: i32, selector_3: i32, selector_4: i32, selector_5: i32, selector_6: i32, selector_7: i32, selector_8: i32, selector_9: i32, selector_10: i32, selector_11: i32, selector_12: i32) => void;
+				'[method]language-status-item.severity': (self: i32) => i32;
+				'[method]language-status-item.set-severity': (self: i32, severity_LanguageStatusSeverity: i32) => void;
+				'[method]language-status-item.text': (self: i32, result: ptr<string>) => void;
+				'[method]language-status-item.set-text': (self: i32, text_ptr: i32, text_len: i32) => void;
+				'[method]language-status-item.detail': (self: i32, result: ptr<string | undefined>) => void;
+				'[method]language-status-item.set-detail': (self: i32, detail_case: i32, detail_option_ptr: i32, detail_option_len: i32) => void;
+				'[method]language-status-item.busy': (self: i32) => i32;
+				'[method]language-status-item.set-busy': (self: i32, busy: i32) => void;
+				'[method]language-status-item.command': (self: i32, result: ptr<Command | undefined>) => void;
+				'[method]language-status-item.set-command': (self: i32, command_case: i32, command_option_title_ptr: i32, command_option_title_len: i32, command_option_command_ptr: i32, command_option_command_len: i32, command_option_tooltip_case: i32, command_option_tooltip_option_ptr: i32, command_option_tooltip_option_len: i32, command_option_arguments_case: i32, command_option_arguments_option_ptr: i32, command_option_arguments_option_len: i32) => void;
+				'[method]language-status-item.accessibility-information': (self: i32, result: ptr<AccessibilityInformation | undefined>) => void;
+				'[method]language-status-item.set-accessibility-information': (self: i32, accessibilityInformation_case: i32, accessibilityInformation_option_label_ptr: i32, accessibilityInformation_option_label_len: i32, accessibilityInformation_option_role_case: i32, accessibilityInformation_option_role_option_ptr: i32, accessibilityInformation_option_role_option_len: i32) => void;
+			};
+			export namespace imports {
+				export type WasmInterface = LanguageStatusItem.WasmInterface & { '[resource-drop]language-status-item': (self: i32) => void };
+			}
+			export namespace exports {
+				export type WasmInterface = LanguageStatusItem.WasmInterface & { '[dtor]language-status-item': (self: i32) => void };
+			}
+		}
 		export const types: Map<string, $wcm.AnyComponentModelType> = new Map<string, $wcm.AnyComponentModelType>([
+			['Uri', $.Uri],
 			['Position', $.Position],
 			['Range', $.Range],
+			['Selection', $.Selection],
+			['UiKind', $.UiKind],
+			['ExtensionMode', $.ExtensionMode],
+			['SecretStorageChangeEvent', $.SecretStorageChangeEvent],
+			['ExtensionContext', $.ExtensionContext],
 			['TextDocumentContentChangeEvent', $.TextDocumentContentChangeEvent],
 			['TextDocumentChangeReason', $.TextDocumentChangeReason],
 			['GlobPattern', $.GlobPattern],
 			['DocumentFilter', $.DocumentFilter],
 			['DocumentSelector', $.DocumentSelector],
+			['Location', $.Location],
+			['LocationLink', $.LocationLink],
+			['Definition', $.Definition],
+			['DiagnosticSeverity', $.DiagnosticSeverity],
+			['DiagnosticTag', $.DiagnosticTag],
+			['DiagnosticCodeValue', $.DiagnosticCodeValue],
+			['DiagnosticCode', $.DiagnosticCode],
+			['DiagnosticRelatedInformation', $.DiagnosticRelatedInformation],
+			['Diagnostic', $.Diagnostic],
+			['DiagnosticEntry', $.DiagnosticEntry],
+			['DiagnosticChangeEvent', $.DiagnosticChangeEvent],
+			['TextEdit', $.TextEdit],
+			['WorkspaceEditEntry', $.WorkspaceEditEntry],
+			['WorkspaceEdit', $.WorkspaceEdit],
+			['Command', $.Command],
+			['CodeActionTriggerKind', $.CodeActionTriggerKind],
+			['CodeActionContext', $.CodeActionContext],
+			['CodeAction', $.CodeAction],
+			['CodeActionProviderMetadata', $.CodeActionProviderMetadata],
+			['FormattingOptionValue', $.FormattingOptionValue],
+			['ContextValue', $.ContextValue],
+			['FormattingOption', $.FormattingOption],
+			['FormattingOptions', $.FormattingOptions],
+			['SemanticTokensLegend', $.SemanticTokensLegend],
+			['SemanticTokens', $.SemanticTokens],
+			['SemanticTokensEdit', $.SemanticTokensEdit],
+			['SemanticTokensEdits', $.SemanticTokensEdits],
+			['SemanticTokensResult', $.SemanticTokensResult],
+			['SymbolKind', $.SymbolKind],
+			['SymbolTag', $.SymbolTag],
+			['DocumentSymbolNode', $.DocumentSymbolNode],
+			['SymbolInformation', $.SymbolInformation],
+			['ReferenceContext', $.ReferenceContext],
+			['DocumentHighlightKind', $.DocumentHighlightKind],
+			['DocumentHighlight', $.DocumentHighlight],
+			['RenameLocation', $.RenameLocation],
+			['PrepareRenameResult', $.PrepareRenameResult],
+			['FoldingRangeKind', $.FoldingRangeKind],
+			['FoldingRange', $.FoldingRange],
+			['InlayHintKind', $.InlayHintKind],
+			['InlayHintLabelPart', $.InlayHintLabelPart],
+			['InlayHintLabel', $.InlayHintLabel],
+			['InlayHint', $.InlayHint],
+			['CodeLens', $.CodeLens],
+			['ParameterLabel', $.ParameterLabel],
+			['ParameterInformation', $.ParameterInformation],
+			['SignatureInformation', $.SignatureInformation],
+			['SignatureHelp', $.SignatureHelp],
+			['SignatureHelpTriggerKind', $.SignatureHelpTriggerKind],
+			['SignatureHelpContext', $.SignatureHelpContext],
+			['SignatureHelpProviderMetadata', $.SignatureHelpProviderMetadata],
+			['HierarchyItem', $.HierarchyItem],
+			['CallHierarchyIncomingCall', $.CallHierarchyIncomingCall],
+			['CallHierarchyOutgoingCall', $.CallHierarchyOutgoingCall],
+			['Color', $.Color],
+			['ColorInformation', $.ColorInformation],
+			['ColorPresentation', $.ColorPresentation],
+			['DocumentLink', $.DocumentLink],
+			['LinkedEditingRanges', $.LinkedEditingRanges],
+			['InlineCompletionItem', $.InlineCompletionItem],
+			['InlineCompletionTriggerKind', $.InlineCompletionTriggerKind],
+			['SelectedCompletionInfo', $.SelectedCompletionInfo],
+			['InlineCompletionContext', $.InlineCompletionContext],
+			['CommentRule', $.CommentRule],
+			['AutoClosingPair', $.AutoClosingPair],
+			['IndentationRule', $.IndentationRule],
+			['IndentAction', $.IndentAction],
+			['EnterAction', $.EnterAction],
+			['OnEnterRule', $.OnEnterRule],
+			['LanguageConfiguration', $.LanguageConfiguration],
+			['LanguageStatusSeverity', $.LanguageStatusSeverity],
+			['AccessibilityInformation', $.AccessibilityInformation],
+			['Memento', $.Memento],
+			['SecretStorage', $.SecretStorage],
 			['TextDocument', $.TextDocument],
+			['TextEditor', $.TextEditor],
 			['TextDocumentChangeEvent', $.TextDocumentChangeEvent],
-			['OutputChannel', $.OutputChannel]
+			['OutputChannel', $.OutputChannel],
+			['CancellationToken', $.CancellationToken],
+			['DiagnosticCollection', $.DiagnosticCollection],
+			['LanguageStatusItem', $.LanguageStatusItem]
 		]);
 		export const resources: Map<string, $wcm.ResourceType> = new Map<string, $wcm.ResourceType>([
+			['Memento', $.Memento],
+			['SecretStorage', $.SecretStorage],
 			['TextDocument', $.TextDocument],
+			['TextEditor', $.TextEditor],
 			['TextDocumentChangeEvent', $.TextDocumentChangeEvent],
-			['OutputChannel', $.OutputChannel]
+			['OutputChannel', $.OutputChannel],
+			['CancellationToken', $.CancellationToken],
+			['DiagnosticCollection', $.DiagnosticCollection],
+			['LanguageStatusItem', $.LanguageStatusItem]
 		]);
 		export type WasmInterface = {
 		};
 		export namespace imports {
-			export type WasmInterface = _.WasmInterface & TextDocument.imports.WasmInterface & TextDocumentChangeEvent.imports.WasmInterface & OutputChannel.imports.WasmInterface;
+			export type WasmInterface = _.WasmInterface & Memento.imports.WasmInterface & SecretStorage.imports.WasmInterface & TextDocument.imports.WasmInterface & TextEditor.imports.WasmInterface & TextDocumentChangeEvent.imports.WasmInterface & OutputChannel.imports.WasmInterface & CancellationToken.imports.WasmInterface & DiagnosticCollection.imports.WasmInterface & LanguageStatusItem.imports.WasmInterface;
 		}
 		export namespace exports {
-			export type WasmInterface = _.WasmInterface & TextDocument.exports.WasmInterface & TextDocumentChangeEvent.exports.WasmInterface & OutputChannel.exports.WasmInterface;
+			export type WasmInterface = _.WasmInterface & Memento.exports.WasmInterface & SecretStorage.exports.WasmInterface & TextDocument.exports.WasmInterface & TextEditor.exports.WasmInterface & TextDocumentChangeEvent.exports.WasmInterface & OutputChannel.exports.WasmInterface & CancellationToken.exports.WasmInterface & DiagnosticCollection.exports.WasmInterface & LanguageStatusItem.exports.WasmInterface;
 			export namespace imports {
 				export type WasmInterface = {
+					'[resource-new]memento': (rep: i32) => i32;
+					'[resource-rep]memento': (handle: i32) => i32;
+					'[resource-drop]memento': (handle: i32) => void;
+					'[resource-new]secret-storage': (rep: i32) => i32;
+					'[resource-rep]secret-storage': (handle: i32) => i32;
+					'[resource-drop]secret-storage': (handle: i32) => void;
 					'[resource-new]text-document': (rep: i32) => i32;
 					'[resource-rep]text-document': (handle: i32) => i32;
 					'[resource-drop]text-document': (handle: i32) => void;
+					'[resource-new]text-editor': (rep: i32) => i32;
+					'[resource-rep]text-editor': (handle: i32) => i32;
+					'[resource-drop]text-editor': (handle: i32) => void;
 					'[resource-new]text-document-change-event': (rep: i32) => i32;
 					'[resource-rep]text-document-change-event': (handle: i32) => i32;
 					'[resource-drop]text-document-change-event': (handle: i32) => void;
 					'[resource-new]output-channel': (rep: i32) => i32;
 					'[resource-rep]output-channel': (handle: i32) => i32;
 					'[resource-drop]output-channel': (handle: i32) => void;
+					'[resource-new]cancellation-token': (rep: i32) => i32;
+					'[resource-rep]cancellation-token': (handle: i32) => i32;
+					'[resource-drop]cancellation-token': (handle: i32) => void;
+					'[resource-new]diagnostic-collection': (rep: i32) => i32;
+					'[resource-rep]diagnostic-collection': (handle: i32) => i32;
+					'[resource-drop]diagnostic-collection': (handle: i32) => void;
+					'[resource-new]language-status-item': (rep: i32) => i32;
+					'[resource-rep]language-status-item': (handle: i32) => i32;
+					'[resource-drop]language-status-item': (handle: i32) => void;
 				};
 			}
 		}
 	}
 
+	export namespace Env.$ {
+		export const Uri = api.Types.$.Uri;
+		export const UiKind = api.Types.$.UiKind;
+		export const appName = new $wcm.FunctionType<api.Env.appName>('app-name', [], $wcm.wstring);
+		export const appRoot = new $wcm.FunctionType<api.Env.appRoot>('app-root', [], $wcm.wstring);
+		export const appHost = new $wcm.FunctionType<api.Env.appHost>('app-host', [], $wcm.wstring);
+		export const getUiKind = new $wcm.FunctionType<api.Env.getUiKind>('get-ui-kind', [], UiKind);
+		export const language = new $wcm.FunctionType<api.Env.language>('language', [], $wcm.wstring);
+		export const machineId = new $wcm.FunctionType<api.Env.machineId>('machine-id', [], $wcm.wstring);
+		export const sessionId = new $wcm.FunctionType<api.Env.sessionId>('session-id', [], $wcm.wstring);
+		export const remoteName = new $wcm.FunctionType<api.Env.remoteName>('remote-name', [], new $wcm.OptionType<string>($wcm.wstring));
+		export const shell = new $wcm.FunctionType<api.Env.shell>('shell', [], $wcm.wstring);
+		export const uriScheme = new $wcm.FunctionType<api.Env.uriScheme>('uri-scheme', [], $wcm.wstring);
+		export const isTelemetryEnabled = new $wcm.FunctionType<api.Env.isTelemetryEnabled>('is-telemetry-enabled', [], $wcm.bool);
+		export const clipboardReadText = new $wcm.FunctionType<api.Env.clipboardReadText>('clipboard-read-text',[
+			['operation', $wcm.u32],
+		], undefined);
+		export const clipboardWriteText = new $wcm.FunctionType<api.Env.clipboardWriteText>('clipboard-write-text',[
+			['operation', $wcm.u32],
+			['value', $wcm.wstring],
+		], undefined);
+		export const openExternal = new $wcm.FunctionType<api.Env.openExternal>('open-external',[
+			['operation', $wcm.u32],
+			['uri', Uri],
+		], undefined);
+		export const asExternalUri = new $wcm.FunctionType<api.Env.asExternalUri>('as-external-uri',[
+			['operation', $wcm.u32],
+			['uri', Uri],
+		], undefined);
+	}
+	export namespace Env._ {
+		export const id = 'host:api/env' as const;
+		export const witName = 'env' as const;
+		export const types: Map<string, $wcm.AnyComponentModelType> = new Map<string, $wcm.AnyComponentModelType>([
+			['Uri', $.Uri],
+			['UiKind', $.UiKind]
+		]);
+		export const functions: Map<string, $wcm.FunctionType> = new Map([
+			['appName', $.appName],
+			['appRoot', $.appRoot],
+			['appHost', $.appHost],
+			['getUiKind', $.getUiKind],
+			['language', $.language],
+			['machineId', $.machineId],
+			['sessionId', $.sessionId],
+			['remoteName', $.remoteName],
+			['shell', $.shell],
+			['uriScheme', $.uriScheme],
+			['isTelemetryEnabled', $.isTelemetryEnabled],
+			['clipboardReadText', $.clipboardReadText],
+			['clipboardWriteText', $.clipboardWriteText],
+			['openExternal', $.openExternal],
+			['asExternalUri', $.asExternalUri]
+		]);
+		export type WasmInterface = {
+			'app-name': (result: ptr<string>) => void;
+			'app-root': (result: ptr<string>) => void;
+			'app-host': (result: ptr<string>) => void;
+			'get-ui-kind': () => i32;
+			'language': (result: ptr<string>) => void;
+			'machine-id': (result: ptr<string>) => void;
+			'session-id': (result: ptr<string>) => void;
+			'remote-name': (result: ptr<string | undefined>) => void;
+			'shell': (result: ptr<string>) => void;
+			'uri-scheme': (result: ptr<string>) => void;
+			'is-telemetry-enabled': () => i32;
+			'clipboard-read-text': (operation: i32) => void;
+			'clipboard-write-text': (operation: i32, value_ptr: i32, value_len: i32) => void;
+			'open-external': (operation: i32, uri_Uri_scheme_ptr: i32, uri_Uri_scheme_len: i32, uri_Uri_authority_ptr: i32, uri_Uri_authority_len: i32, uri_Uri_path_ptr: i32, uri_Uri_path_len: i32, uri_Uri_query_ptr: i32, uri_Uri_query_len: i32, uri_Uri_fragment_ptr: i32, uri_Uri_fragment_len: i32) => void;
+			'as-external-uri': (operation: i32, uri_Uri_scheme_ptr: i32, uri_Uri_scheme_len: i32, uri_Uri_authority_ptr: i32, uri_Uri_authority_len: i32, uri_Uri_path_ptr: i32, uri_Uri_path_len: i32, uri_Uri_query_ptr: i32, uri_Uri_query_len: i32, uri_Uri_fragment_ptr: i32, uri_Uri_fragment_len: i32) => void;
+		};
+		export namespace imports {
+			export type WasmInterface = _.WasmInterface;
+		}
+		export namespace exports {
+			export type WasmInterface = _.WasmInterface;
+		}
+	}
+
 	export namespace Commands.$ {
+		export const ContextValue = api.Types.$.ContextValue;
 		export const registerCommand = new $wcm.FunctionType<api.Commands.registerCommand>('register-command',[
 			['command', $wcm.wstring],
 		], undefined);
+		export const registerTextEditorCommand = new $wcm.FunctionType<api.Commands.registerTextEditorCommand>('register-text-editor-command',[
+			['command', $wcm.wstring],
+		], undefined);
 		export const unregisterCommand = new $wcm.FunctionType<api.Commands.unregisterCommand>('unregister-command',[
 			['command', $wcm.wstring],
 		], undefined);
+		export const executeCommand = new $wcm.FunctionType<api.Commands.executeCommand>('execute-command',[
+			['operation', $wcm.u32],
+			['command', $wcm.wstring],
+			['arguments', $wcm.wstring],
+		], undefined);
+		export const getCommands = new $wcm.FunctionType<api.Commands.getCommands>('get-commands',[
+			['operation', $wcm.u32],
+			['filterInternal', $wcm.bool],
+		], undefined);
+		export const setContext = new $wcm.FunctionType<api.Commands.setContext>('set-context',[
+			['key', $wcm.wstring],
+			['value', ContextValue],
+		], undefined);
 	}
 	export namespace Commands._ {
 		export const id = 'host:api/commands' as const;
 		export const witName = 'commands' as const;
+		export const types: Map<string, $wcm.AnyComponentModelType> = new Map<string, $wcm.AnyComponentModelType>([
+			['ContextValue', $.ContextValue]
+		]);
 		export const functions: Map<string, $wcm.FunctionType> = new Map([
 			['registerCommand', $.registerCommand],
-			['unregisterCommand', $.unregisterCommand]
+			['registerTextEditorCommand', $.registerTextEditorCommand],
+			['unregisterCommand', $.unregisterCommand],
+			['executeCommand', $.executeCommand],
+			['getCommands', $.getCommands],
+			['setContext', $.setContext]
 		]);
 		export type WasmInterface = {
 			'register-command': (command_ptr: i32, command_len: i32) => void;
+			'register-text-editor-command': (command_ptr: i32, command_len: i32) => void;
 			'unregister-command': (command_ptr: i32, command_len: i32) => void;
+			'execute-command': (operation: i32, command_ptr: i32, command_len: i32, arguments_ptr: i32, arguments_len: i32) => void;
+			'get-commands': (operation: i32, filterInternal: i32) => void;
+			'set-context': (key_ptr: i32, key_len: i32, value_ContextValue_case: i32, value_ContextValue_0: i64, value_ContextValue_1: i32) => void;
 		};
 		export namespace imports {
 			export type WasmInterface = _.WasmInterface;
@@ -414,25 +2572,251 @@ export namespace api {
 	}
 
 	export namespace Languages.$ {
+		export const Uri = api.Types.$.Uri;
 		export const DocumentSelector = api.Types.$.DocumentSelector;
 		export const TextDocument = api.Types.$.TextDocument;
+		export const Diagnostic = api.Types.$.Diagnostic;
+		export const DiagnosticCollection = api.Types.$.DiagnosticCollection;
+		export const CodeActionProviderMetadata = api.Types.$.CodeActionProviderMetadata;
+		export const SemanticTokensLegend = api.Types.$.SemanticTokensLegend;
+		export const SignatureHelpProviderMetadata = api.Types.$.SignatureHelpProviderMetadata;
+		export const LanguageConfiguration = api.Types.$.LanguageConfiguration;
+		export const LanguageStatusItem = api.Types.$.LanguageStatusItem;
 		export const matchSelector = new $wcm.FunctionType<api.Languages.matchSelector>('match-selector',[
 			['selector', DocumentSelector],
 			['document', new $wcm.OwnType<api.Languages.TextDocument>(TextDocument)],
 		], $wcm.u32);
+		export const unregisterProvider = new $wcm.FunctionType<api.Languages.unregisterProvider>('unregister-provider',[
+			['provider', $wcm.u32],
+		], undefined);
+		export const fireDidChangeProvider = new $wcm.FunctionType<api.Languages.fireDidChangeProvider>('fire-did-change-provider',[
+			['provider', $wcm.u32],
+		], undefined);
+		export const createDiagnosticCollection = new $wcm.FunctionType<api.Languages.createDiagnosticCollection>('create-diagnostic-collection',[
+			['name', new $wcm.OptionType<string>($wcm.wstring)],
+		], new $wcm.OwnType<api.Languages.DiagnosticCollection>(DiagnosticCollection));
+		export const getDiagnostics = new $wcm.FunctionType<api.Languages.getDiagnostics>('get-diagnostics',[
+			['uri', Uri],
+		], new $wcm.ListType<api.Languages.Diagnostic>(Diagnostic));
+		export const registerOnDidChangeDiagnostics = new $wcm.FunctionType<api.Languages.registerOnDidChangeDiagnostics>('register-on-did-change-diagnostics', [], undefined);
+		export const unregisterOnDidChangeDiagnostics = new $wcm.FunctionType<api.Languages.unregisterOnDidChangeDiagnostics>('unregister-on-did-change-diagnostics', [], undefined);
+		export const registerCodeActionsProvider = new $wcm.FunctionType<api.Languages.registerCodeActionsProvider>('register-code-actions-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+			['metadata', new $wcm.OptionType<api.Languages.CodeActionProviderMetadata>(CodeActionProviderMetadata)],
+		], undefined);
+		export const registerDocumentFormattingEditProvider = new $wcm.FunctionType<api.Languages.registerDocumentFormattingEditProvider>('register-document-formatting-edit-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+		], undefined);
+		export const registerDocumentRangeFormattingEditProvider = new $wcm.FunctionType<api.Languages.registerDocumentRangeFormattingEditProvider>('register-document-range-formatting-edit-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+		], undefined);
+		export const registerOnTypeFormattingEditProvider = new $wcm.FunctionType<api.Languages.registerOnTypeFormattingEditProvider>('register-on-type-formatting-edit-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+			['firstTriggerCharacter', $wcm.wstring],
+			['moreTriggerCharacter', new $wcm.ListType<string>($wcm.wstring)],
+		], undefined);
+		export const registerDocumentSemanticTokensProvider = new $wcm.FunctionType<api.Languages.registerDocumentSemanticTokensProvider>('register-document-semantic-tokens-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+			['legend', SemanticTokensLegend],
+		], undefined);
+		export const registerDocumentRangeSemanticTokensProvider = new $wcm.FunctionType<api.Languages.registerDocumentRangeSemanticTokensProvider>('register-document-range-semantic-tokens-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+			['legend', SemanticTokensLegend],
+		], undefined);
+		export const registerDocumentSymbolProvider = new $wcm.FunctionType<api.Languages.registerDocumentSymbolProvider>('register-document-symbol-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+		], undefined);
+		export const registerWorkspaceSymbolProvider = new $wcm.FunctionType<api.Languages.registerWorkspaceSymbolProvider>('register-workspace-symbol-provider',[
+			['provider', $wcm.u32],
+		], undefined);
+		export const registerDefinitionProvider = new $wcm.FunctionType<api.Languages.registerDefinitionProvider>('register-definition-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+		], undefined);
+		export const registerDeclarationProvider = new $wcm.FunctionType<api.Languages.registerDeclarationProvider>('register-declaration-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+		], undefined);
+		export const registerTypeDefinitionProvider = new $wcm.FunctionType<api.Languages.registerTypeDefinitionProvider>('register-type-definition-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+		], undefined);
+		export const registerImplementationProvider = new $wcm.FunctionType<api.Languages.registerImplementationProvider>('register-implementation-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+		], undefined);
+		export const registerReferenceProvider = new $wcm.FunctionType<api.Languages.registerReferenceProvider>('register-reference-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+		], undefined);
+		export const registerDocumentHighlightProvider = new $wcm.FunctionType<api.Languages.registerDocumentHighlightProvider>('register-document-highlight-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+		], undefined);
+		export const registerRenameProvider = new $wcm.FunctionType<api.Languages.registerRenameProvider>('register-rename-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+		], undefined);
+		export const registerFoldingRangeProvider = new $wcm.FunctionType<api.Languages.registerFoldingRangeProvider>('register-folding-range-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+		], undefined);
+		export const registerSelectionRangeProvider = new $wcm.FunctionType<api.Languages.registerSelectionRangeProvider>('register-selection-range-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+		], undefined);
+		export const registerInlayHintsProvider = new $wcm.FunctionType<api.Languages.registerInlayHintsProvider>('register-inlay-hints-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+		], undefined);
+		export const registerCodeLensProvider = new $wcm.FunctionType<api.Languages.registerCodeLensProvider>('register-code-lens-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+		], undefined);
+		export const registerSignatureHelpProvider = new $wcm.FunctionType<api.Languages.registerSignatureHelpProvider>('register-signature-help-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+			['metadata', SignatureHelpProviderMetadata],
+		], undefined);
+		export const registerCallHierarchyProvider = new $wcm.FunctionType<api.Languages.registerCallHierarchyProvider>('register-call-hierarchy-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+		], undefined);
+		export const registerTypeHierarchyProvider = new $wcm.FunctionType<api.Languages.registerTypeHierarchyProvider>('register-type-hierarchy-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+		], undefined);
+		export const registerColorProvider = new $wcm.FunctionType<api.Languages.registerColorProvider>('register-color-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+		], undefined);
+		export const registerDocumentLinkProvider = new $wcm.FunctionType<api.Languages.registerDocumentLinkProvider>('register-document-link-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+		], undefined);
+		export const registerLinkedEditingRangeProvider = new $wcm.FunctionType<api.Languages.registerLinkedEditingRangeProvider>('register-linked-editing-range-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+		], undefined);
+		export const registerInlineCompletionItemProvider = new $wcm.FunctionType<api.Languages.registerInlineCompletionItemProvider>('register-inline-completion-item-provider',[
+			['provider', $wcm.u32],
+			['selector', DocumentSelector],
+		], undefined);
+		export const setLanguageConfiguration = new $wcm.FunctionType<api.Languages.setLanguageConfiguration>('set-language-configuration',[
+			['registration', $wcm.u32],
+			['languageId', $wcm.wstring],
+			['configuration', LanguageConfiguration],
+		], undefined);
+		export const getLanguages = new $wcm.FunctionType<api.Languages.getLanguages>('get-languages', [], new $wcm.ListType<string>($wcm.wstring));
+		export const setTextDocumentLanguage = new $wcm.FunctionType<api.Languages.setTextDocumentLanguage>('set-text-document-language',[
+			['operation', $wcm.u32],
+			['document', new $wcm.BorrowType<api.Languages.TextDocument>(TextDocument)],
+			['languageId', $wcm.wstring],
+		], undefined);
+		export const createLanguageStatusItem = new $wcm.FunctionType<api.Languages.createLanguageStatusItem>('create-language-status-item',[
+			['id', $wcm.wstring],
+			['selector', DocumentSelector],
+		], new $wcm.OwnType<api.Languages.LanguageStatusItem>(LanguageStatusItem));
 	}
 	export namespace Languages._ {
 		export const id = 'host:api/languages' as const;
 		export const witName = 'languages' as const;
 		export const types: Map<string, $wcm.AnyComponentModelType> = new Map<string, $wcm.AnyComponentModelType>([
+			['Uri', $.Uri],
 			['DocumentSelector', $.DocumentSelector],
-			['TextDocument', $.TextDocument]
+			['TextDocument', $.TextDocument],
+			['Diagnostic', $.Diagnostic],
+			['DiagnosticCollection', $.DiagnosticCollection],
+			['CodeActionProviderMetadata', $.CodeActionProviderMetadata],
+			['SemanticTokensLegend', $.SemanticTokensLegend],
+			['SignatureHelpProviderMetadata', $.SignatureHelpProviderMetadata],
+			['LanguageConfiguration', $.LanguageConfiguration],
+			['LanguageStatusItem', $.LanguageStatusItem]
 		]);
 		export const functions: Map<string, $wcm.FunctionType> = new Map([
-			['matchSelector', $.matchSelector]
+			['matchSelector', $.matchSelector],
+			['unregisterProvider', $.unregisterProvider],
+			['fireDidChangeProvider', $.fireDidChangeProvider],
+			['createDiagnosticCollection', $.createDiagnosticCollection],
+			['getDiagnostics', $.getDiagnostics],
+			['registerOnDidChangeDiagnostics', $.registerOnDidChangeDiagnostics],
+			['unregisterOnDidChangeDiagnostics', $.unregisterOnDidChangeDiagnostics],
+			['registerCodeActionsProvider', $.registerCodeActionsProvider],
+			['registerDocumentFormattingEditProvider', $.registerDocumentFormattingEditProvider],
+			['registerDocumentRangeFormattingEditProvider', $.registerDocumentRangeFormattingEditProvider],
+			['registerOnTypeFormattingEditProvider', $.registerOnTypeFormattingEditProvider],
+			['registerDocumentSemanticTokensProvider', $.registerDocumentSemanticTokensProvider],
+			['registerDocumentRangeSemanticTokensProvider', $.registerDocumentRangeSemanticTokensProvider],
+			['registerDocumentSymbolProvider', $.registerDocumentSymbolProvider],
+			['registerWorkspaceSymbolProvider', $.registerWorkspaceSymbolProvider],
+			['registerDefinitionProvider', $.registerDefinitionProvider],
+			['registerDeclarationProvider', $.registerDeclarationProvider],
+			['registerTypeDefinitionProvider', $.registerTypeDefinitionProvider],
+			['registerImplementationProvider', $.registerImplementationProvider],
+			['registerReferenceProvider', $.registerReferenceProvider],
+			['registerDocumentHighlightProvider', $.registerDocumentHighlightProvider],
+			['registerRenameProvider', $.registerRenameProvider],
+			['registerFoldingRangeProvider', $.registerFoldingRangeProvider],
+			['registerSelectionRangeProvider', $.registerSelectionRangeProvider],
+			['registerInlayHintsProvider', $.registerInlayHintsProvider],
+			['registerCodeLensProvider', $.registerCodeLensProvider],
+			['registerSignatureHelpProvider', $.registerSignatureHelpProvider],
+			['registerCallHierarchyProvider', $.registerCallHierarchyProvider],
+			['registerTypeHierarchyProvider', $.registerTypeHierarchyProvider],
+			['registerColorProvider', $.registerColorProvider],
+			['registerDocumentLinkProvider', $.registerDocumentLinkProvider],
+			['registerLinkedEditingRangeProvider', $.registerLinkedEditingRangeProvider],
+			['registerInlineCompletionItemProvider', $.registerInlineCompletionItemProvider],
+			['setLanguageConfiguration', $.setLanguageConfiguration],
+			['getLanguages', $.getLanguages],
+			['setTextDocumentLanguage', $.setTextDocumentLanguage],
+			['createLanguageStatusItem', $.createLanguageStatusItem]
 		]);
 		export type WasmInterface = {
 			'match-selector': (selector_DocumentSelector_case: i32, selector_DocumentSelector_0: i32, selector_DocumentSelector_1: i32, selector_DocumentSelector_2: i32, selector_DocumentSelector_3: i32, selector_DocumentSelector_4: i32, selector_DocumentSelector_5: i32, selector_DocumentSelector_6: i32, selector_DocumentSelector_7: i32, selector_DocumentSelector_8: i32, selector_DocumentSelector_9: i32, selector_DocumentSelector_10: i32, selector_DocumentSelector_11: i32, selector_DocumentSelector_12: i32, document: i32) => i32;
+			'unregister-provider': (provider: i32) => void;
+			'fire-did-change-provider': (provider: i32) => void;
+			'create-diagnostic-collection': (name_case: i32, name_option_ptr: i32, name_option_len: i32) => i32;
+			'get-diagnostics': (uri_Uri_scheme_ptr: i32, uri_Uri_scheme_len: i32, uri_Uri_authority_ptr: i32, uri_Uri_authority_len: i32, uri_Uri_path_ptr: i32, uri_Uri_path_len: i32, uri_Uri_query_ptr: i32, uri_Uri_query_len: i32, uri_Uri_fragment_ptr: i32, uri_Uri_fragment_len: i32, result: ptr<Diagnostic[]>) => void;
+			'register-on-did-change-diagnostics': () => void;
+			'unregister-on-did-change-diagnostics': () => void;
+			'register-code-actions-provider': (args: ptr<[u32, DocumentSelector, CodeActionProviderMetadata | undefined]>) => void;
+			'register-document-formatting-edit-provider': (provider: i32, selector_DocumentSelector_case: i32, selector_DocumentSelector_0: i32, selector_DocumentSelector_1: i32, selector_DocumentSelector_2: i32, selector_DocumentSelector_3: i32, selector_DocumentSelector_4: i32, selector_DocumentSelector_5: i32, selector_DocumentSelector_6: i32, selector_DocumentSelector_7: i32, selector_DocumentSelector_8: i32, selector_DocumentSelector_9: i32, selector_DocumentSelector_10: i32, selector_DocumentSelector_11: i32, selector_DocumentSelector_12: i32) => void;
+			'register-document-range-formatting-edit-provider': (provider: i32, selector_DocumentSelector_case: i32, selector_DocumentSelector_0: i32, selector_DocumentSelector_1: i32, selector_DocumentSelector_2: i32, selector_DocumentSelector_3: i32, selector_DocumentSelector_4: i32, selector_DocumentSelector_5: i32, selector_DocumentSelector_6: i32, selector_DocumentSelector_7: i32, selector_DocumentSelector_8: i32, selector_DocumentSelector_9: i32, selector_DocumentSelector_10: i32, selector_DocumentSelector_11: i32, selector_DocumentSelector_12: i32) => void;
+			'register-on-type-formatting-edit-provider': (args: ptr<[u32, DocumentSelector, string, string[]]>) => void;
+			'register-document-semantic-tokens-provider': (args: ptr<[u32, DocumentSelector, SemanticTokensLegend]>) => void;
+			'register-document-range-semantic-tokens-provider': (args: ptr<[u32, DocumentSelector, SemanticTokensLegend]>) => void;
+			'register-document-symbol-provider': (provider: i32, selector_DocumentSelector_case: i32, selector_DocumentSelector_0: i32, selector_DocumentSelector_1: i32, selector_DocumentSelector_2: i32, selector_DocumentSelector_3: i32, selector_DocumentSelector_4: i32, selector_DocumentSelector_5: i32, selector_DocumentSelector_6: i32, selector_DocumentSelector_7: i32, selector_DocumentSelector_8: i32, selector_DocumentSelector_9: i32, selector_DocumentSelector_10: i32, selector_DocumentSelector_11: i32, selector_DocumentSelector_12: i32) => void;
+			'register-workspace-symbol-provider': (provider: i32) => void;
+			'register-definition-provider': (provider: i32, selector_DocumentSelector_case: i32, selector_DocumentSelector_0: i32, selector_DocumentSelector_1: i32, selector_DocumentSelector_2: i32, selector_DocumentSelector_3: i32, selector_DocumentSelector_4: i32, selector_DocumentSelector_5: i32, selector_DocumentSelector_6: i32, selector_DocumentSelector_7: i32, selector_DocumentSelector_8: i32, selector_DocumentSelector_9: i32, selector_DocumentSelector_10: i32, selector_DocumentSelector_11: i32, selector_DocumentSelector_12: i32) => void;
+			'register-declaration-provider': (provider: i32, selector_DocumentSelector_case: i32, selector_DocumentSelector_0: i32, selector_DocumentSelector_1: i32, selector_DocumentSelector_2: i32, selector_DocumentSelector_3: i32, selector_DocumentSelector_4: i32, selector_DocumentSelector_5: i32, selector_DocumentSelector_6: i32, selector_DocumentSelector_7: i32, selector_DocumentSelector_8: i32, selector_DocumentSelector_9: i32, selector_DocumentSelector_10: i32, selector_DocumentSelector_11: i32, selector_DocumentSelector_12: i32) => void;
+			'register-type-definition-provider': (provider: i32, selector_DocumentSelector_case: i32, selector_DocumentSelector_0: i32, selector_DocumentSelector_1: i32, selector_DocumentSelector_2: i32, selector_DocumentSelector_3: i32, selector_DocumentSelector_4: i32, selector_DocumentSelector_5: i32, selector_DocumentSelector_6: i32, selector_DocumentSelector_7: i32, selector_DocumentSelector_8: i32, selector_DocumentSelector_9: i32, selector_DocumentSelector_10: i32, selector_DocumentSelector_11: i32, selector_DocumentSelector_12: i32) => void;
+			'register-implementation-provider': (provider: i32, selector_DocumentSelector_case: i32, selector_DocumentSelector_0: i32, selector_DocumentSelector_1: i32, selector_DocumentSelector_2: i32, selector_DocumentSelector_3: i32, selector_DocumentSelector_4: i32, selector_DocumentSelector_5: i32, selector_DocumentSelector_6: i32, selector_DocumentSelector_7: i32, selector_DocumentSelector_8: i32, selector_DocumentSelector_9: i32, selector_DocumentSelector_10: i32, selector_DocumentSelector_11: i32, selector_DocumentSelector_12: i32) => void;
+			'register-reference-provider': (provider: i32, selector_DocumentSelector_case: i32, selector_DocumentSelector_0: i32, selector_DocumentSelector_1: i32, selector_DocumentSelector_2: i32, selector_DocumentSelector_3: i32, selector_DocumentSelector_4: i32, selector_DocumentSelector_5: i32, selector_DocumentSelector_6: i32, selector_DocumentSelector_7: i32, selector_DocumentSelector_8: i32, selector_DocumentSelector_9: i32, selector_DocumentSelector_10: i32, selector_DocumentSelector_11: i32, selector_DocumentSelector_12: i32) => void;
+			'register-document-highlight-provider': (provider: i32, selector_DocumentSelector_case: i32, selector_DocumentSelector_0: i32, selector_DocumentSelector_1: i32, selector_DocumentSelector_2: i32, selector_DocumentSelector_3: i32, selector_DocumentSelector_4: i32, selector_DocumentSelector_5: i32, selector_DocumentSelector_6: i32, selector_DocumentSelector_7: i32, selector_DocumentSelector_8: i32, selector_DocumentSelector_9: i32, selector_DocumentSelector_10: i32, selector_DocumentSelector_11: i32, selector_DocumentSelector_12: i32) => void;
+			'register-rename-provider': (provider: i32, selector_DocumentSelector_case: i32, selector_DocumentSelector_0: i32, selector_DocumentSelector_1: i32, selector_DocumentSelector_2: i32, selector_DocumentSelector_3: i32, selector_DocumentSelector_4: i32, selector_DocumentSelector_5: i32, selector_DocumentSelector_6: i32, selector_DocumentSelector_7: i32, selector_DocumentSelector_8: i32, selector_DocumentSelector_9: i32, selector_DocumentSelector_10: i32, selector_DocumentSelector_11: i32, selector_DocumentSelector_12: i32) => void;
+			'register-folding-range-provider': (provider: i32, selector_DocumentSelector_case: i32, selector_DocumentSelector_0: i32, selector_DocumentSelector_1: i32, selector_DocumentSelector_2: i32, selector_DocumentSelector_3: i32, selector_DocumentSelector_4: i32, selector_DocumentSelector_5: i32, selector_DocumentSelector_6: i32, selector_DocumentSelector_7: i32, selector_DocumentSelector_8: i32, selector_DocumentSelector_9: i32, selector_DocumentSelector_10: i32, selector_DocumentSelector_11: i32, selector_DocumentSelector_12: i32) => void;
+			'register-selection-range-provider': (provider: i32, selector_DocumentSelector_case: i32, selector_DocumentSelector_0: i32, selector_DocumentSelector_1: i32, selector_DocumentSelector_2: i32, selector_DocumentSelector_3: i32, selector_DocumentSelector_4: i32, selector_DocumentSelector_5: i32, selector_DocumentSelector_6: i32, selector_DocumentSelector_7: i32, selector_DocumentSelector_8: i32, selector_DocumentSelector_9: i32, selector_DocumentSelector_10: i32, selector_DocumentSelector_11: i32, selector_DocumentSelector_12: i32) => void;
+			'register-inlay-hints-provider': (provider: i32, selector_DocumentSelector_case: i32, selector_DocumentSelector_0: i32, selector_DocumentSelector_1: i32, selector_DocumentSelector_2: i32, selector_DocumentSelector_3: i32, selector_DocumentSelector_4: i32, selector_DocumentSelector_5: i32, selector_DocumentSelector_6: i32, selector_DocumentSelector_7: i32, selector_DocumentSelector_8: i32, selector_DocumentSelector_9: i32, selector_DocumentSelector_10: i32, selector_DocumentSelector_11: i32, selector_DocumentSelector_12: i32) => void;
+			'register-code-lens-provider': (provider: i32, selector_DocumentSelector_case: i32, selector_DocumentSelector_0: i32, selector_DocumentSelector_1: i32, selector_DocumentSelector_2: i32, selector_DocumentSelector_3: i32, selector_DocumentSelector_4: i32, selector_DocumentSelector_5: i32, selector_DocumentSelector_6: i32, selector_DocumentSelector_7: i32, selector_DocumentSelector_8: i32, selector_DocumentSelector_9: i32, selector_DocumentSelector_10: i32, selector_DocumentSelector_11: i32, selector_DocumentSelector_12: i32) => void;
+			'register-signature-help-provider': (args: ptr<[u32, DocumentSelector, SignatureHelpProviderMetadata]>) => void;
+			'register-call-hierarchy-provider': (provider: i32, selector_DocumentSelector_case: i32, selector_DocumentSelector_0: i32, selector_DocumentSelector_1: i32, selector_DocumentSelector_2: i32, selector_DocumentSelector_3: i32, selector_DocumentSelector_4: i32, selector_DocumentSelector_5: i32, selector_DocumentSelector_6: i32, selector_DocumentSelector_7: i32, selector_DocumentSelector_8: i32, selector_DocumentSelector_9: i32, selector_DocumentSelector_10: i32, selector_DocumentSelector_11: i32, selector_DocumentSelector_12: i32) => void;
+			'register-type-hierarchy-provider': (provider: i32, selector_DocumentSelector_case: i32, selector_DocumentSelector_0: i32, selector_DocumentSelector_1: i32, selector_DocumentSelector_2: i32, selector_DocumentSelector_3: i32, selector_DocumentSelector_4: i32, selector_DocumentSelector_5: i32, selector_DocumentSelector_6: i32, selector_DocumentSelector_7: i32, selector_DocumentSelector_8: i32, selector_DocumentSelector_9: i32, selector_DocumentSelector_10: i32, selector_DocumentSelector_11: i32, selector_DocumentSelector_12: i32) => void;
+			'register-color-provider': (provider: i32, selector_DocumentSelector_case: i32, selector_DocumentSelector_0: i32, selector_DocumentSelector_1: i32, selector_DocumentSelector_2: i32, selector_DocumentSelector_3: i32, selector_DocumentSelector_4: i32, selector_DocumentSelector_5: i32, selector_DocumentSelector_6: i32, selector_DocumentSelector_7: i32, selector_DocumentSelector_8: i32, selector_DocumentSelector_9: i32, selector_DocumentSelector_10: i32, selector_DocumentSelector_11: i32, selector_DocumentSelector_12: i32) => void;
+			'register-document-link-provider': (provider: i32, selector_DocumentSelector_case: i32, selector_DocumentSelector_0: i32, selector_DocumentSelector_1: i32, selector_DocumentSelector_2: i32, selector_DocumentSelector_3: i32, selector_DocumentSelector_4: i32, selector_DocumentSelector_5: i32, selector_DocumentSelector_6: i32, selector_DocumentSelector_7: i32, selector_DocumentSelector_8: i32, selector_DocumentSelector_9: i32, selector_DocumentSelector_10: i32, selector_DocumentSelector_11: i32, selector_DocumentSelector_12: i32) => void;
+			'register-linked-editing-range-provider': (provider: i32, selector_DocumentSelector_case: i32, selector_DocumentSelector_0: i32, selector_DocumentSelector_1: i32, selector_DocumentSelector_2: i32, selector_DocumentSelector_3: i32, selector_DocumentSelector_4: i32, selector_DocumentSelector_5: i32, selector_DocumentSelector_6: i32, selector_DocumentSelector_7: i32, selector_DocumentSelector_8: i32, selector_DocumentSelector_9: i32, selector_DocumentSelector_10: i32, selector_DocumentSelector_11: i32, selector_DocumentSelector_12: i32) => void;
+			'register-inline-completion-item-provider': (provider: i32, selector_DocumentSelector_case: i32, selector_DocumentSelector_0: i32, selector_DocumentSelector_1: i32, selector_DocumentSelector_2: i32, selector_DocumentSelector_3: i32, selector_DocumentSelector_4: i32, selector_DocumentSelector_5: i32, selector_DocumentSelector_6: i32, selector_DocumentSelector_7: i32, selector_DocumentSelector_8: i32, selector_DocumentSelector_9: i32, selector_DocumentSelector_10: i32, selector_DocumentSelector_11: i32, selector_DocumentSelector_12: i32) => void;
+			'set-language-configuration': (args: ptr<[u32, string, LanguageConfiguration]>) => void;
+			'get-languages': (result: ptr<string[]>) => void;
+			'set-text-document-language': (operation: i32, document: i32, languageId_ptr: i32, languageId_len: i32) => void;
+			'create-language-status-item': (id_ptr: i32, id_len: i32, selector_DocumentSelector_case: i32, selector_DocumentSelector_0: i32, selector_DocumentSelector_1: i32, selector_DocumentSelector_2: i32, selector_DocumentSelector_3: i32, selector_DocumentSelector_4: i32, selector_DocumentSelector_5: i32, selector_DocumentSelector_6: i32, selector_DocumentSelector_7: i32, selector_DocumentSelector_8: i32, selector_DocumentSelector_9: i32, selector_DocumentSelector_10: i32, selector_DocumentSelector_11: i32, selector_DocumentSelector_12: i32) => i32;
 		};
 		export namespace imports {
 			export type WasmInterface = _.WasmInterface;
@@ -448,6 +2832,25 @@ export namespace api {
 			['name', $wcm.wstring],
 			['languageId', new $wcm.OptionType<string>($wcm.wstring)],
 		], new $wcm.OwnType<api.Window.OutputChannel>(OutputChannel));
+		export const showInformationMessage = new $wcm.FunctionType<api.Window.showInformationMessage>('show-information-message',[
+			['operation', $wcm.u32],
+			['message', $wcm.wstring],
+			['items', new $wcm.ListType<string>($wcm.wstring)],
+		], undefined);
+		export const showWarningMessage = new $wcm.FunctionType<api.Window.showWarningMessage>('show-warning-message',[
+			['operation', $wcm.u32],
+			['message', $wcm.wstring],
+			['items', new $wcm.ListType<string>($wcm.wstring)],
+		], undefined);
+		export const showErrorMessage = new $wcm.FunctionType<api.Window.showErrorMessage>('show-error-message',[
+			['operation', $wcm.u32],
+			['message', $wcm.wstring],
+			['items', new $wcm.ListType<string>($wcm.wstring)],
+		], undefined);
+		export const showQuickPick = new $wcm.FunctionType<api.Window.showQuickPick>('show-quick-pick',[
+			['operation', $wcm.u32],
+			['items', new $wcm.ListType<string>($wcm.wstring)],
+		], undefined);
 	}
 	export namespace Window._ {
 		export const id = 'host:api/window' as const;
@@ -456,10 +2859,18 @@ export namespace api {
 			['OutputChannel', $.OutputChannel]
 		]);
 		export const functions: Map<string, $wcm.FunctionType> = new Map([
-			['createOutputChannel', $.createOutputChannel]
+			['createOutputChannel', $.createOutputChannel],
+			['showInformationMessage', $.showInformationMessage],
+			['showWarningMessage', $.showWarningMessage],
+			['showErrorMessage', $.showErrorMessage],
+			['showQuickPick', $.showQuickPick]
 		]);
 		export type WasmInterface = {
 			'create-output-channel': (name_ptr: i32, name_len: i32, languageId_case: i32, languageId_option_ptr: i32, languageId_option_len: i32) => i32;
+			'show-information-message': (operation: i32, message_ptr: i32, message_len: i32, items_ptr: i32, items_len: i32) => void;
+			'show-warning-message': (operation: i32, message_ptr: i32, message_len: i32, items_ptr: i32, items_len: i32) => void;
+			'show-error-message': (operation: i32, message_ptr: i32, message_len: i32, items_ptr: i32, items_len: i32) => void;
+			'show-quick-pick': (operation: i32, items_ptr: i32, items_len: i32) => void;
 		};
 		export namespace imports {
 			export type WasmInterface = _.WasmInterface;
@@ -501,26 +2912,425 @@ export namespace api {
 
 	export namespace Callbacks.$ {
 		export const TextDocumentChangeEvent = api.Types.$.TextDocumentChangeEvent;
+		export const DiagnosticChangeEvent = api.Types.$.DiagnosticChangeEvent;
+		export const SecretStorageChangeEvent = api.Types.$.SecretStorageChangeEvent;
+		export const TextDocument = api.Types.$.TextDocument;
+		export const TextEditor = api.Types.$.TextEditor;
+		export const Position = api.Types.$.Position;
+		export const Range = api.Types.$.Range;
+		export const CancellationToken = api.Types.$.CancellationToken;
+		export const CodeActionContext = api.Types.$.CodeActionContext;
+		export const CodeAction = api.Types.$.CodeAction;
+		export const FormattingOptions = api.Types.$.FormattingOptions;
+		export const TextEdit = api.Types.$.TextEdit;
+		export const SemanticTokens = api.Types.$.SemanticTokens;
+		export const SemanticTokensResult = api.Types.$.SemanticTokensResult;
+		export const DocumentSymbolNode = api.Types.$.DocumentSymbolNode;
+		export const SymbolInformation = api.Types.$.SymbolInformation;
+		export const Definition = api.Types.$.Definition;
+		export const Location = api.Types.$.Location;
+		export const ReferenceContext = api.Types.$.ReferenceContext;
+		export const DocumentHighlight = api.Types.$.DocumentHighlight;
+		export const PrepareRenameResult = api.Types.$.PrepareRenameResult;
+		export const WorkspaceEdit = api.Types.$.WorkspaceEdit;
+		export const FoldingRange = api.Types.$.FoldingRange;
+		export const InlayHint = api.Types.$.InlayHint;
+		export const CodeLens = api.Types.$.CodeLens;
+		export const SignatureHelpContext = api.Types.$.SignatureHelpContext;
+		export const SignatureHelp = api.Types.$.SignatureHelp;
+		export const HierarchyItem = api.Types.$.HierarchyItem;
+		export const CallHierarchyIncomingCall = api.Types.$.CallHierarchyIncomingCall;
+		export const CallHierarchyOutgoingCall = api.Types.$.CallHierarchyOutgoingCall;
+		export const Color = api.Types.$.Color;
+		export const ColorInformation = api.Types.$.ColorInformation;
+		export const ColorPresentation = api.Types.$.ColorPresentation;
+		export const DocumentLink = api.Types.$.DocumentLink;
+		export const LinkedEditingRanges = api.Types.$.LinkedEditingRanges;
+		export const InlineCompletionContext = api.Types.$.InlineCompletionContext;
+		export const InlineCompletionItem = api.Types.$.InlineCompletionItem;
 		export const didChangeTextDocument = new $wcm.FunctionType<api.Callbacks.didChangeTextDocument>('did-change-text-document',[
 			['event', new $wcm.OwnType<api.Callbacks.TextDocumentChangeEvent>(TextDocumentChangeEvent)],
 		], undefined);
+		export const didChangeDiagnostics = new $wcm.FunctionType<api.Callbacks.didChangeDiagnostics>('did-change-diagnostics',[
+			['event', DiagnosticChangeEvent],
+		], undefined);
+		export const didChangeSecret = new $wcm.FunctionType<api.Callbacks.didChangeSecret>('did-change-secret',[
+			['event', SecretStorageChangeEvent],
+		], undefined);
 		export const executeCommand = new $wcm.FunctionType<api.Callbacks.executeCommand>('execute-command',[
 			['command', $wcm.wstring],
+			['arguments', $wcm.wstring],
+		], new $wcm.ResultType<string, string>($wcm.wstring, $wcm.wstring, $wcm.wstring.Error));
+		export const executeTextEditorCommand = new $wcm.FunctionType<api.Callbacks.executeTextEditorCommand>('execute-text-editor-command',[
+			['command', $wcm.wstring],
+			['editor', new $wcm.OwnType<api.Callbacks.TextEditor>(TextEditor)],
+			['arguments', $wcm.wstring],
+		], new $wcm.ResultType<api.Callbacks.TextEdit[], string>(new $wcm.ListType<api.Callbacks.TextEdit>(TextEdit), $wcm.wstring, $wcm.wstring.Error));
+		export const completeOperation = new $wcm.FunctionType<api.Callbacks.completeOperation>('complete-operation',[
+			['operation', $wcm.u32],
+			['outcome', new $wcm.ResultType<string, string>($wcm.wstring, $wcm.wstring, $wcm.wstring.Error)],
+		], undefined);
+		export const provideCodeActions = new $wcm.FunctionType<api.Callbacks.provideCodeActions>('provide-code-actions',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['range', Range],
+			['context', CodeActionContext],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.CodeAction[]>(new $wcm.ListType<api.Callbacks.CodeAction>(CodeAction)));
+		export const resolveCodeAction = new $wcm.FunctionType<api.Callbacks.resolveCodeAction>('resolve-code-action',[
+			['provider', $wcm.u32],
+			['action', CodeAction],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], CodeAction);
+		export const provideDocumentFormattingEdits = new $wcm.FunctionType<api.Callbacks.provideDocumentFormattingEdits>('provide-document-formatting-edits',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['options', FormattingOptions],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.ListType<api.Callbacks.TextEdit>(TextEdit));
+		export const provideDocumentRangeFormattingEdits = new $wcm.FunctionType<api.Callbacks.provideDocumentRangeFormattingEdits>('provide-document-range-formatting-edits',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['range', Range],
+			['options', FormattingOptions],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.ListType<api.Callbacks.TextEdit>(TextEdit));
+		export const provideOnTypeFormattingEdits = new $wcm.FunctionType<api.Callbacks.provideOnTypeFormattingEdits>('provide-on-type-formatting-edits',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['position', Position],
+			['ch', $wcm.wstring],
+			['options', FormattingOptions],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.ListType<api.Callbacks.TextEdit>(TextEdit));
+		export const provideDocumentSemanticTokens = new $wcm.FunctionType<api.Callbacks.provideDocumentSemanticTokens>('provide-document-semantic-tokens',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.SemanticTokens>(SemanticTokens));
+		export const provideDocumentSemanticTokensEdits = new $wcm.FunctionType<api.Callbacks.provideDocumentSemanticTokensEdits>('provide-document-semantic-tokens-edits',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['previousResultId', $wcm.wstring],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.SemanticTokensResult>(SemanticTokensResult));
+		export const provideDocumentRangeSemanticTokens = new $wcm.FunctionType<api.Callbacks.provideDocumentRangeSemanticTokens>('provide-document-range-semantic-tokens',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['range', Range],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.SemanticTokens>(SemanticTokens));
+		export const provideDocumentSymbols = new $wcm.FunctionType<api.Callbacks.provideDocumentSymbols>('provide-document-symbols',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.DocumentSymbolNode[]>(new $wcm.ListType<api.Callbacks.DocumentSymbolNode>(DocumentSymbolNode)));
+		export const provideWorkspaceSymbols = new $wcm.FunctionType<api.Callbacks.provideWorkspaceSymbols>('provide-workspace-symbols',[
+			['provider', $wcm.u32],
+			['query', $wcm.wstring],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.SymbolInformation[]>(new $wcm.ListType<api.Callbacks.SymbolInformation>(SymbolInformation)));
+		export const resolveWorkspaceSymbol = new $wcm.FunctionType<api.Callbacks.resolveWorkspaceSymbol>('resolve-workspace-symbol',[
+			['provider', $wcm.u32],
+			['symbol', SymbolInformation],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], SymbolInformation);
+		export const provideDefinition = new $wcm.FunctionType<api.Callbacks.provideDefinition>('provide-definition',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['position', Position],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.Definition>(Definition));
+		export const provideDeclaration = new $wcm.FunctionType<api.Callbacks.provideDeclaration>('provide-declaration',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['position', Position],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.Definition>(Definition));
+		export const provideTypeDefinition = new $wcm.FunctionType<api.Callbacks.provideTypeDefinition>('provide-type-definition',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['position', Position],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.Definition>(Definition));
+		export const provideImplementation = new $wcm.FunctionType<api.Callbacks.provideImplementation>('provide-implementation',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['position', Position],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.Definition>(Definition));
+		export const provideReferences = new $wcm.FunctionType<api.Callbacks.provideReferences>('provide-references',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['position', Position],
+			['context', ReferenceContext],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.Location[]>(new $wcm.ListType<api.Callbacks.Location>(Location)));
+		export const provideDocumentHighlights = new $wcm.FunctionType<api.Callbacks.provideDocumentHighlights>('provide-document-highlights',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['position', Position],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.DocumentHighlight[]>(new $wcm.ListType<api.Callbacks.DocumentHighlight>(DocumentHighlight)));
+		export const prepareRename = new $wcm.FunctionType<api.Callbacks.prepareRename>('prepare-rename',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['position', Position],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.PrepareRenameResult>(PrepareRenameResult));
+		export const provideRenameEdits = new $wcm.FunctionType<api.Callbacks.provideRenameEdits>('provide-rename-edits',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['position', Position],
+			['newName', $wcm.wstring],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.WorkspaceEdit>(WorkspaceEdit));
+		export const provideFoldingRanges = new $wcm.FunctionType<api.Callbacks.provideFoldingRanges>('provide-folding-ranges',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.FoldingRange[]>(new $wcm.ListType<api.Callbacks.FoldingRange>(FoldingRange)));
+		export const provideSelectionRanges = new $wcm.FunctionType<api.Callbacks.provideSelectionRanges>('provide-selection-ranges',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['positions', new $wcm.ListType<api.Callbacks.Position>(Position)],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.Range[][]>(new $wcm.ListType<api.Callbacks.Range[]>(new $wcm.ListType<api.Callbacks.Range>(Range))));
+		export const provideInlayHints = new $wcm.FunctionType<api.Callbacks.provideInlayHints>('provide-inlay-hints',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['range', Range],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.InlayHint[]>(new $wcm.ListType<api.Callbacks.InlayHint>(InlayHint)));
+		export const resolveInlayHint = new $wcm.FunctionType<api.Callbacks.resolveInlayHint>('resolve-inlay-hint',[
+			['provider', $wcm.u32],
+			['hint', InlayHint],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], InlayHint);
+		export const provideCodeLenses = new $wcm.FunctionType<api.Callbacks.provideCodeLenses>('provide-code-lenses',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.CodeLens[]>(new $wcm.ListType<api.Callbacks.CodeLens>(CodeLens)));
+		export const resolveCodeLens = new $wcm.FunctionType<api.Callbacks.resolveCodeLens>('resolve-code-lens',[
+			['provider', $wcm.u32],
+			['lens', CodeLens],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], CodeLens);
+		export const provideSignatureHelp = new $wcm.FunctionType<api.Callbacks.provideSignatureHelp>('provide-signature-help',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['position', Position],
+			['context', SignatureHelpContext],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.SignatureHelp>(SignatureHelp));
+		export const prepareCallHierarchy = new $wcm.FunctionType<api.Callbacks.prepareCallHierarchy>('prepare-call-hierarchy',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['position', Position],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.HierarchyItem[]>(new $wcm.ListType<api.Callbacks.HierarchyItem>(HierarchyItem)));
+		export const provideCallHierarchyIncomingCalls = new $wcm.FunctionType<api.Callbacks.provideCallHierarchyIncomingCalls>('provide-call-hierarchy-incoming-calls',[
+			['provider', $wcm.u32],
+			['item', $wcm.u32],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.CallHierarchyIncomingCall[]>(new $wcm.ListType<api.Callbacks.CallHierarchyIncomingCall>(CallHierarchyIncomingCall)));
+		export const provideCallHierarchyOutgoingCalls = new $wcm.FunctionType<api.Callbacks.provideCallHierarchyOutgoingCalls>('provide-call-hierarchy-outgoing-calls',[
+			['provider', $wcm.u32],
+			['item', $wcm.u32],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.CallHierarchyOutgoingCall[]>(new $wcm.ListType<api.Callbacks.CallHierarchyOutgoingCall>(CallHierarchyOutgoingCall)));
+		export const prepareTypeHierarchy = new $wcm.FunctionType<api.Callbacks.prepareTypeHierarchy>('prepare-type-hierarchy',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['position', Position],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.HierarchyItem[]>(new $wcm.ListType<api.Callbacks.HierarchyItem>(HierarchyItem)));
+		export const provideTypeHierarchySupertypes = new $wcm.FunctionType<api.Callbacks.provideTypeHierarchySupertypes>('provide-type-hierarchy-supertypes',[
+			['provider', $wcm.u32],
+			['item', $wcm.u32],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.HierarchyItem[]>(new $wcm.ListType<api.Callbacks.HierarchyItem>(HierarchyItem)));
+		export const provideTypeHierarchySubtypes = new $wcm.FunctionType<api.Callbacks.provideTypeHierarchySubtypes>('provide-type-hierarchy-subtypes',[
+			['provider', $wcm.u32],
+			['item', $wcm.u32],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.HierarchyItem[]>(new $wcm.ListType<api.Callbacks.HierarchyItem>(HierarchyItem)));
+		export const releaseHierarchyItem = new $wcm.FunctionType<api.Callbacks.releaseHierarchyItem>('release-hierarchy-item',[
+			['provider', $wcm.u32],
+			['item', $wcm.u32],
 		], undefined);
+		export const provideDocumentColors = new $wcm.FunctionType<api.Callbacks.provideDocumentColors>('provide-document-colors',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.ColorInformation[]>(new $wcm.ListType<api.Callbacks.ColorInformation>(ColorInformation)));
+		export const provideColorPresentations = new $wcm.FunctionType<api.Callbacks.provideColorPresentations>('provide-color-presentations',[
+			['provider', $wcm.u32],
+			['color', Color],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['range', Range],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.ColorPresentation[]>(new $wcm.ListType<api.Callbacks.ColorPresentation>(ColorPresentation)));
+		export const provideDocumentLinks = new $wcm.FunctionType<api.Callbacks.provideDocumentLinks>('provide-document-links',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.DocumentLink[]>(new $wcm.ListType<api.Callbacks.DocumentLink>(DocumentLink)));
+		export const resolveDocumentLink = new $wcm.FunctionType<api.Callbacks.resolveDocumentLink>('resolve-document-link',[
+			['provider', $wcm.u32],
+			['link', DocumentLink],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], DocumentLink);
+		export const provideLinkedEditingRanges = new $wcm.FunctionType<api.Callbacks.provideLinkedEditingRanges>('provide-linked-editing-ranges',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['position', Position],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.LinkedEditingRanges>(LinkedEditingRanges));
+		export const provideInlineCompletionItems = new $wcm.FunctionType<api.Callbacks.provideInlineCompletionItems>('provide-inline-completion-items',[
+			['provider', $wcm.u32],
+			['document', new $wcm.OwnType<api.Callbacks.TextDocument>(TextDocument)],
+			['position', Position],
+			['context', InlineCompletionContext],
+			['token', new $wcm.OwnType<api.Callbacks.CancellationToken>(CancellationToken)],
+		], new $wcm.OptionType<api.Callbacks.InlineCompletionItem[]>(new $wcm.ListType<api.Callbacks.InlineCompletionItem>(InlineCompletionItem)));
 	}
 	export namespace Callbacks._ {
 		export const id = 'host:api/callbacks' as const;
 		export const witName = 'callbacks' as const;
 		export const types: Map<string, $wcm.AnyComponentModelType> = new Map<string, $wcm.AnyComponentModelType>([
-			['TextDocumentChangeEvent', $.TextDocumentChangeEvent]
+			['TextDocumentChangeEvent', $.TextDocumentChangeEvent],
+			['DiagnosticChangeEvent', $.DiagnosticChangeEvent],
+			['SecretStorageChangeEvent', $.SecretStorageChangeEvent],
+			['TextDocument', $.TextDocument],
+			['TextEditor', $.TextEditor],
+			['Position', $.Position],
+			['Range', $.Range],
+			['CancellationToken', $.CancellationToken],
+			['CodeActionContext', $.CodeActionContext],
+			['CodeAction', $.CodeAction],
+			['FormattingOptions', $.FormattingOptions],
+			['TextEdit', $.TextEdit],
+			['SemanticTokens', $.SemanticTokens],
+			['SemanticTokensResult', $.SemanticTokensResult],
+			['DocumentSymbolNode', $.DocumentSymbolNode],
+			['SymbolInformation', $.SymbolInformation],
+			['Definition', $.Definition],
+			['Location', $.Location],
+			['ReferenceContext', $.ReferenceContext],
+			['DocumentHighlight', $.DocumentHighlight],
+			['PrepareRenameResult', $.PrepareRenameResult],
+			['WorkspaceEdit', $.WorkspaceEdit],
+			['FoldingRange', $.FoldingRange],
+			['InlayHint', $.InlayHint],
+			['CodeLens', $.CodeLens],
+			['SignatureHelpContext', $.SignatureHelpContext],
+			['SignatureHelp', $.SignatureHelp],
+			['HierarchyItem', $.HierarchyItem],
+			['CallHierarchyIncomingCall', $.CallHierarchyIncomingCall],
+			['CallHierarchyOutgoingCall', $.CallHierarchyOutgoingCall],
+			['Color', $.Color],
+			['ColorInformation', $.ColorInformation],
+			['ColorPresentation', $.ColorPresentation],
+			['DocumentLink', $.DocumentLink],
+			['LinkedEditingRanges', $.LinkedEditingRanges],
+			['InlineCompletionContext', $.InlineCompletionContext],
+			['InlineCompletionItem', $.InlineCompletionItem]
 		]);
 		export const functions: Map<string, $wcm.FunctionType> = new Map([
 			['didChangeTextDocument', $.didChangeTextDocument],
-			['executeCommand', $.executeCommand]
+			['didChangeDiagnostics', $.didChangeDiagnostics],
+			['didChangeSecret', $.didChangeSecret],
+			['executeCommand', $.executeCommand],
+			['executeTextEditorCommand', $.executeTextEditorCommand],
+			['completeOperation', $.completeOperation],
+			['provideCodeActions', $.provideCodeActions],
+			['resolveCodeAction', $.resolveCodeAction],
+			['provideDocumentFormattingEdits', $.provideDocumentFormattingEdits],
+			['provideDocumentRangeFormattingEdits', $.provideDocumentRangeFormattingEdits],
+			['provideOnTypeFormattingEdits', $.provideOnTypeFormattingEdits],
+			['provideDocumentSemanticTokens', $.provideDocumentSemanticTokens],
+			['provideDocumentSemanticTokensEdits', $.provideDocumentSemanticTokensEdits],
+			['provideDocumentRangeSemanticTokens', $.provideDocumentRangeSemanticTokens],
+			['provideDocumentSymbols', $.provideDocumentSymbols],
+			['provideWorkspaceSymbols', $.provideWorkspaceSymbols],
+			['resolveWorkspaceSymbol', $.resolveWorkspaceSymbol],
+			['provideDefinition', $.provideDefinition],
+			['provideDeclaration', $.provideDeclaration],
+			['provideTypeDefinition', $.provideTypeDefinition],
+			['provideImplementation', $.provideImplementation],
+			['provideReferences', $.provideReferences],
+			['provideDocumentHighlights', $.provideDocumentHighlights],
+			['prepareRename', $.prepareRename],
+			['provideRenameEdits', $.provideRenameEdits],
+			['provideFoldingRanges', $.provideFoldingRanges],
+			['provideSelectionRanges', $.provideSelectionRanges],
+			['provideInlayHints', $.provideInlayHints],
+			['resolveInlayHint', $.resolveInlayHint],
+			['provideCodeLenses', $.provideCodeLenses],
+			['resolveCodeLens', $.resolveCodeLens],
+			['provideSignatureHelp', $.provideSignatureHelp],
+			['prepareCallHierarchy', $.prepareCallHierarchy],
+			['provideCallHierarchyIncomingCalls', $.provideCallHierarchyIncomingCalls],
+			['provideCallHierarchyOutgoingCalls', $.provideCallHierarchyOutgoingCalls],
+			['prepareTypeHierarchy', $.prepareTypeHierarchy],
+			['provideTypeHierarchySupertypes', $.provideTypeHierarchySupertypes],
+			['provideTypeHierarchySubtypes', $.provideTypeHierarchySubtypes],
+			['releaseHierarchyItem', $.releaseHierarchyItem],
+			['provideDocumentColors', $.provideDocumentColors],
+			['provideColorPresentations', $.provideColorPresentations],
+			['provideDocumentLinks', $.provideDocumentLinks],
+			['resolveDocumentLink', $.resolveDocumentLink],
+			['provideLinkedEditingRanges', $.provideLinkedEditingRanges],
+			['provideInlineCompletionItems', $.provideInlineCompletionItems]
 		]);
 		export type WasmInterface = {
 			'did-change-text-document': (event: i32) => void;
-			'execute-command': (command_ptr: i32, command_len: i32) => void;
+			'did-change-diagnostics': (event_DiagnosticChangeEvent_uris_ptr: i32, event_DiagnosticChangeEvent_uris_len: i32) => void;
+			'did-change-secret': (event_SecretStorageChangeEvent_key_ptr: i32, event_SecretStorageChangeEvent_key_len: i32) => void;
+			'execute-command': (command_ptr: i32, command_len: i32, arguments_ptr: i32, arguments_len: i32, result: ptr<result<string, string>>) => void;
+			'execute-text-editor-command': (command_ptr: i32, command_len: i32, editor: i32, arguments_ptr: i32, arguments_len: i32, result: ptr<result<TextEdit[], string>>) => void;
+			'complete-operation': (operation: i32, outcome_case: i32, outcome_0: i32, outcome_1: i32) => void;
+			'provide-code-actions': (provider: i32, document: i32, range_Range_start_line: i32, range_Range_start_character: i32, range_Range_end_line: i32, range_Range_end_character: i32, context_CodeActionContext_diagnostics_ptr: i32, context_CodeActionContext_diagnostics_len: i32, context_CodeActionContext_only_case: i32, context_CodeActionContext_only_option_ptr: i32, context_CodeActionContext_only_option_len: i32, context_CodeActionContext_triggerKind_CodeActionTriggerKind: i32, token: i32, result: ptr<CodeAction[] | undefined>) => void;
+			'resolve-code-action': (args: ptr<[u32, CodeAction, own<CancellationToken>]>) => void;
+			'provide-document-formatting-edits': (provider: i32, document: i32, options_FormattingOptions_tabSize: i32, options_FormattingOptions_insertSpaces: i32, options_FormattingOptions_properties_ptr: i32, options_FormattingOptions_properties_len: i32, token: i32, result: ptr<TextEdit[]>) => void;
+			'provide-document-range-formatting-edits': (provider: i32, document: i32, range_Range_start_line: i32, range_Range_start_character: i32, range_Range_end_line: i32, range_Range_end_character: i32, options_FormattingOptions_tabSize: i32, options_FormattingOptions_insertSpaces: i32, options_FormattingOptions_properties_ptr: i32, options_FormattingOptions_properties_len: i32, token: i32, result: ptr<TextEdit[]>) => void;
+			'provide-on-type-formatting-edits': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, ch_ptr: i32, ch_len: i32, options_FormattingOptions_tabSize: i32, options_FormattingOptions_insertSpaces: i32, options_FormattingOptions_properties_ptr: i32, options_FormattingOptions_properties_len: i32, token: i32, result: ptr<TextEdit[]>) => void;
+			'provide-document-semantic-tokens': (provider: i32, document: i32, token: i32, result: ptr<SemanticTokens | undefined>) => void;
+			'provide-document-semantic-tokens-edits': (provider: i32, document: i32, previousResultId_ptr: i32, previousResultId_len: i32, token: i32, result: ptr<SemanticTokensResult | undefined>) => void;
+			'provide-document-range-semantic-tokens': (provider: i32, document: i32, range_Range_start_line: i32, range_Range_start_character: i32, range_Range_end_line: i32, range_Range_end_character: i32, token: i32, result: ptr<SemanticTokens | undefined>) => void;
+			'provide-document-symbols': (provider: i32, document: i32, token: i32, result: ptr<DocumentSymbolNode[] | undefined>) => void;
+			'provide-workspace-symbols': (provider: i32, query_ptr: i32, query_len: i32, token: i32, result: ptr<SymbolInformation[] | undefined>) => void;
+			'resolve-workspace-symbol': (args: ptr<[u32, SymbolInformation, own<CancellationToken>]>) => void;
+			'provide-definition': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, token: i32, result: ptr<Definition | undefined>) => void;
+			'provide-declaration': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, token: i32, result: ptr<Definition | undefined>) => void;
+			'provide-type-definition': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, token: i32, result: ptr<Definition | undefined>) => void;
+			'provide-implementation': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, token: i32, result: ptr<Definition | undefined>) => void;
+			'provide-references': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, context_ReferenceContext_includeDeclaration: i32, token: i32, result: ptr<Location[] | undefined>) => void;
+			'provide-document-highlights': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, token: i32, result: ptr<DocumentHighlight[] | undefined>) => void;
+			'prepare-rename': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, token: i32, result: ptr<PrepareRenameResult | undefined>) => void;
+			'provide-rename-edits': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, newName_ptr: i32, newName_len: i32, token: i32, result: ptr<WorkspaceEdit | undefined>) => void;
+			'provide-folding-ranges': (provider: i32, document: i32, token: i32, result: ptr<FoldingRange[] | undefined>) => void;
+			'provide-selection-ranges': (provider: i32, document: i32, positions_ptr: i32, positions_len: i32, token: i32, result: ptr<Range[][] | undefined>) => void;
+			'provide-inlay-hints': (provider: i32, document: i32, range_Range_start_line: i32, range_Range_start_character: i32, range_Range_end_line: i32, range_Range_end_character: i32, token: i32, result: ptr<InlayHint[] | undefined>) => void;
+			'resolve-inlay-hint': (args: ptr<[u32, InlayHint, own<CancellationToken>]>) => void;
+			'provide-code-lenses': (provider: i32, document: i32, token: i32, result: ptr<CodeLens[] | undefined>) => void;
+			'resolve-code-lens': (args: ptr<[u32, CodeLens, own<CancellationToken>]>) => void;
+			'provide-signature-help': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, context_SignatureHelpContext_triggerKind_SignatureHelpTriggerKind: i32, context_SignatureHelpContext_triggerCharacter_case: i32, context_SignatureHelpContext_triggerCharacter_option_ptr: i32, context_SignatureHelpContext_triggerCharacter_option_len: i32, context_SignatureHelpContext_isRetrigger: i32, context_SignatureHelpContext_activeSignatureHelp_case: i32, context_SignatureHelpContext_activeSignatureHelp_option_signatures_ptr: i32, context_SignatureHelpContext_activeSignatureHelp_option_signatures_len: i32, context_SignatureHelpContext_activeSignatureHelp_option_activeSignature: i32, context_SignatureHelpContext_activeSignatureHelp_option_activeParameter: i32, token: i32, result: ptr<SignatureHelp | undefined>) => void;
+			'prepare-call-hierarchy': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, token: i32, result: ptr<HierarchyItem[] | undefined>) => void;
+			'provide-call-hierarchy-incoming-calls': (provider: i32, item: i32, token: i32, result: ptr<CallHierarchyIncomingCall[] | undefined>) => void;
+			'provide-call-hierarchy-outgoing-calls': (provider: i32, item: i32, token: i32, result: ptr<CallHierarchyOutgoingCall[] | undefined>) => void;
+			'prepare-type-hierarchy': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, token: i32, result: ptr<HierarchyItem[] | undefined>) => void;
+			'provide-type-hierarchy-supertypes': (provider: i32, item: i32, token: i32, result: ptr<HierarchyItem[] | undefined>) => void;
+			'provide-type-hierarchy-subtypes': (provider: i32, item: i32, token: i32, result: ptr<HierarchyItem[] | undefined>) => void;
+			'release-hierarchy-item': (provider: i32, item: i32) => void;
+			'provide-document-colors': (provider: i32, document: i32, token: i32, result: ptr<ColorInformation[] | undefined>) => void;
+			'provide-color-presentations': (provider: i32, color_Color_red: f32, color_Color_green: f32, color_Color_blue: f32, color_Color_alpha: f32, document: i32, range_Range_start_line: i32, range_Range_start_character: i32, range_Range_end_line: i32, range_Range_end_character: i32, token: i32, result: ptr<ColorPresentation[] | undefined>) => void;
+			'provide-document-links': (provider: i32, document: i32, token: i32, result: ptr<DocumentLink[] | undefined>) => void;
+			'resolve-document-link': (args: ptr<[u32, DocumentLink, own<CancellationToken>]>) => void;
+			'provide-linked-editing-ranges': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, token: i32, result: ptr<LinkedEditingRanges | undefined>) => void;
+			'provide-inline-completion-items': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, context_InlineCompletionContext_triggerKind_InlineCompletionTriggerKind: i32, context_InlineCompletionContext_selectedCompletionInfo_case: i32, context_InlineCompletionContext_selectedCompletionInfo_option_range_start_line: i32, context_InlineCompletionContext_selectedCompletionInfo_option_range_start_character: i32, context_InlineCompletionContext_selectedCompletionInfo_option_range_end_line: i32, context_InlineCompletionContext_selectedCompletionInfo_option_range_end_character: i32, context_InlineCompletionContext_selectedCompletionInfo_option_text_ptr: i32, context_InlineCompletionContext_selectedCompletionInfo_option_text_len: i32, token: i32, result: ptr<InlineCompletionItem[] | undefined>) => void;
 		};
 		export namespace imports {
 			export type WasmInterface = _.WasmInterface;
@@ -530,6 +3340,13 @@ export namespace api {
 		}
 	}
 	export namespace all.$ {
+		export const HostExtensionContext = Types.$.ExtensionContext;
+		export namespace exports {
+			export const activate = new $wcm.FunctionType<all.Exports['activate']>('activate',[
+				['context', HostExtensionContext],
+			], undefined);
+			export const deactivate = new $wcm.FunctionType<all.Exports['deactivate']>('deactivate', [], undefined);
+		}
 	}
 	export namespace all._ {
 		export const id = 'host:api/all' as const;
@@ -538,6 +3355,7 @@ export namespace api {
 			export const interfaces: Map<string, $wcm.InterfaceType> = new Map<string, $wcm.InterfaceType>([
 				['Types', Types._],
 				['Workspace', Workspace._],
+				['Env', Env._],
 				['Commands', Commands._],
 				['Window', Window._],
 				['Languages', Languages._]
@@ -552,11 +3370,16 @@ export namespace api {
 		export type Imports = {
 			'host:api/types': api.Types._.imports.WasmInterface;
 			'host:api/workspace': api.Workspace._.imports.WasmInterface;
+			'host:api/env': api.Env._.imports.WasmInterface;
 			'host:api/commands': api.Commands._.imports.WasmInterface;
 			'host:api/window': api.Window._.imports.WasmInterface;
 			'host:api/languages': api.Languages._.imports.WasmInterface;
 		};
 		export namespace exports {
+			export const functions: Map<string, $wcm.FunctionType> = new Map([
+				['activate', $.exports.activate],
+				['deactivate', $.exports.deactivate]
+			]);
 			export const interfaces: Map<string, $wcm.InterfaceType> = new Map<string, $wcm.InterfaceType>([
 				['Callbacks', Callbacks._]
 			]);
@@ -565,12 +3388,57 @@ export namespace api {
 			}
 		}
 		export type Exports = {
+			'activate': (args: ptr<[HostExtensionContext]>) => void;
+			'deactivate': () => void;
 			'host:api/callbacks#did-change-text-document': (event: i32) => void;
-			'host:api/callbacks#execute-command': (command_ptr: i32, command_len: i32) => void;
+			'host:api/callbacks#did-change-diagnostics': (event_DiagnosticChangeEvent_uris_ptr: i32, event_DiagnosticChangeEvent_uris_len: i32) => void;
+			'host:api/callbacks#did-change-secret': (event_SecretStorageChangeEvent_key_ptr: i32, event_SecretStorageChangeEvent_key_len: i32) => void;
+			'host:api/callbacks#execute-command': (command_ptr: i32, command_len: i32, arguments_ptr: i32, arguments_len: i32, result: ptr<result<string, string>>) => void;
+			'host:api/callbacks#execute-text-editor-command': (command_ptr: i32, command_len: i32, editor: i32, arguments_ptr: i32, arguments_len: i32, result: ptr<result<TextEdit[], string>>) => void;
+			'host:api/callbacks#complete-operation': (operation: i32, outcome_case: i32, outcome_0: i32, outcome_1: i32) => void;
+			'host:api/callbacks#provide-code-actions': (provider: i32, document: i32, range_Range_start_line: i32, range_Range_start_character: i32, range_Range_end_line: i32, range_Range_end_character: i32, context_CodeActionContext_diagnostics_ptr: i32, context_CodeActionContext_diagnostics_len: i32, context_CodeActionContext_only_case: i32, context_CodeActionContext_only_option_ptr: i32, context_CodeActionContext_only_option_len: i32, context_CodeActionContext_triggerKind_CodeActionTriggerKind: i32, token: i32, result: ptr<CodeAction[] | undefined>) => void;
+			'host:api/callbacks#resolve-code-action': (args: ptr<[u32, CodeAction, own<CancellationToken>]>) => void;
+			'host:api/callbacks#provide-document-formatting-edits': (provider: i32, document: i32, options_FormattingOptions_tabSize: i32, options_FormattingOptions_insertSpaces: i32, options_FormattingOptions_properties_ptr: i32, options_FormattingOptions_properties_len: i32, token: i32, result: ptr<TextEdit[]>) => void;
+			'host:api/callbacks#provide-document-range-formatting-edits': (provider: i32, document: i32, range_Range_start_line: i32, range_Range_start_character: i32, range_Range_end_line: i32, range_Range_end_character: i32, options_FormattingOptions_tabSize: i32, options_FormattingOptions_insertSpaces: i32, options_FormattingOptions_properties_ptr: i32, options_FormattingOptions_properties_len: i32, token: i32, result: ptr<TextEdit[]>) => void;
+			'host:api/callbacks#provide-on-type-formatting-edits': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, ch_ptr: i32, ch_len: i32, options_FormattingOptions_tabSize: i32, options_FormattingOptions_insertSpaces: i32, options_FormattingOptions_properties_ptr: i32, options_FormattingOptions_properties_len: i32, token: i32, result: ptr<TextEdit[]>) => void;
+			'host:api/callbacks#provide-document-semantic-tokens': (provider: i32, document: i32, token: i32, result: ptr<SemanticTokens | undefined>) => void;
+			'host:api/callbacks#provide-document-semantic-tokens-edits': (provider: i32, document: i32, previousResultId_ptr: i32, previousResultId_len: i32, token: i32, result: ptr<SemanticTokensResult | undefined>) => void;
+			'host:api/callbacks#provide-document-range-semantic-tokens': (provider: i32, document: i32, range_Range_start_line: i32, range_Range_start_character: i32, range_Range_end_line: i32, range_Range_end_character: i32, token: i32, result: ptr<SemanticTokens | undefined>) => void;
+			'host:api/callbacks#provide-document-symbols': (provider: i32, document: i32, token: i32, result: ptr<DocumentSymbolNode[] | undefined>) => void;
+			'host:api/callbacks#provide-workspace-symbols': (provider: i32, query_ptr: i32, query_len: i32, token: i32, result: ptr<SymbolInformation[] | undefined>) => void;
+			'host:api/callbacks#resolve-workspace-symbol': (args: ptr<[u32, SymbolInformation, own<CancellationToken>]>) => void;
+			'host:api/callbacks#provide-definition': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, token: i32, result: ptr<Definition | undefined>) => void;
+			'host:api/callbacks#provide-declaration': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, token: i32, result: ptr<Definition | undefined>) => void;
+			'host:api/callbacks#provide-type-definition': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, token: i32, result: ptr<Definition | undefined>) => void;
+			'host:api/callbacks#provide-implementation': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, token: i32, result: ptr<Definition | undefined>) => void;
+			'host:api/callbacks#provide-references': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, context_ReferenceContext_includeDeclaration: i32, token: i32, result: ptr<Location[] | undefined>) => void;
+			'host:api/callbacks#provide-document-highlights': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, token: i32, result: ptr<DocumentHighlight[] | undefined>) => void;
+			'host:api/callbacks#prepare-rename': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, token: i32, result: ptr<PrepareRenameResult | undefined>) => void;
+			'host:api/callbacks#provide-rename-edits': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, newName_ptr: i32, newName_len: i32, token: i32, result: ptr<WorkspaceEdit | undefined>) => void;
+			'host:api/callbacks#provide-folding-ranges': (provider: i32, document: i32, token: i32, result: ptr<FoldingRange[] | undefined>) => void;
+			'host:api/callbacks#provide-selection-ranges': (provider: i32, document: i32, positions_ptr: i32, positions_len: i32, token: i32, result: ptr<Range[][] | undefined>) => void;
+			'host:api/callbacks#provide-inlay-hints': (provider: i32, document: i32, range_Range_start_line: i32, range_Range_start_character: i32, range_Range_end_line: i32, range_Range_end_character: i32, token: i32, result: ptr<InlayHint[] | undefined>) => void;
+			'host:api/callbacks#resolve-inlay-hint': (args: ptr<[u32, InlayHint, own<CancellationToken>]>) => void;
+			'host:api/callbacks#provide-code-lenses': (provider: i32, document: i32, token: i32, result: ptr<CodeLens[] | undefined>) => void;
+			'host:api/callbacks#resolve-code-lens': (args: ptr<[u32, CodeLens, own<CancellationToken>]>) => void;
+			'host:api/callbacks#provide-signature-help': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, context_SignatureHelpContext_triggerKind_SignatureHelpTriggerKind: i32, context_SignatureHelpContext_triggerCharacter_case: i32, context_SignatureHelpContext_triggerCharacter_option_ptr: i32, context_SignatureHelpContext_triggerCharacter_option_len: i32, context_SignatureHelpContext_isRetrigger: i32, context_SignatureHelpContext_activeSignatureHelp_case: i32, context_SignatureHelpContext_activeSignatureHelp_option_signatures_ptr: i32, context_SignatureHelpContext_activeSignatureHelp_option_signatures_len: i32, context_SignatureHelpContext_activeSignatureHelp_option_activeSignature: i32, context_SignatureHelpContext_activeSignatureHelp_option_activeParameter: i32, token: i32, result: ptr<SignatureHelp | undefined>) => void;
+			'host:api/callbacks#prepare-call-hierarchy': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, token: i32, result: ptr<HierarchyItem[] | undefined>) => void;
+			'host:api/callbacks#provide-call-hierarchy-incoming-calls': (provider: i32, item: i32, token: i32, result: ptr<CallHierarchyIncomingCall[] | undefined>) => void;
+			'host:api/callbacks#provide-call-hierarchy-outgoing-calls': (provider: i32, item: i32, token: i32, result: ptr<CallHierarchyOutgoingCall[] | undefined>) => void;
+			'host:api/callbacks#prepare-type-hierarchy': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, token: i32, result: ptr<HierarchyItem[] | undefined>) => void;
+			'host:api/callbacks#provide-type-hierarchy-supertypes': (provider: i32, item: i32, token: i32, result: ptr<HierarchyItem[] | undefined>) => void;
+			'host:api/callbacks#provide-type-hierarchy-subtypes': (provider: i32, item: i32, token: i32, result: ptr<HierarchyItem[] | undefined>) => void;
+			'host:api/callbacks#release-hierarchy-item': (provider: i32, item: i32) => void;
+			'host:api/callbacks#provide-document-colors': (provider: i32, document: i32, token: i32, result: ptr<ColorInformation[] | undefined>) => void;
+			'host:api/callbacks#provide-color-presentations': (provider: i32, color_Color_red: f32, color_Color_green: f32, color_Color_blue: f32, color_Color_alpha: f32, document: i32, range_Range_start_line: i32, range_Range_start_character: i32, range_Range_end_line: i32, range_Range_end_character: i32, token: i32, result: ptr<ColorPresentation[] | undefined>) => void;
+			'host:api/callbacks#provide-document-links': (provider: i32, document: i32, token: i32, result: ptr<DocumentLink[] | undefined>) => void;
+			'host:api/callbacks#resolve-document-link': (args: ptr<[u32, DocumentLink, own<CancellationToken>]>) => void;
+			'host:api/callbacks#provide-linked-editing-ranges': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, token: i32, result: ptr<LinkedEditingRanges | undefined>) => void;
+			'host:api/callbacks#provide-inline-completion-items': (provider: i32, document: i32, position_Position_line: i32, position_Position_character: i32, context_InlineCompletionContext_triggerKind_InlineCompletionTriggerKind: i32, context_InlineCompletionContext_selectedCompletionInfo_case: i32, context_InlineCompletionContext_selectedCompletionInfo_option_range_start_line: i32, context_InlineCompletionContext_selectedCompletionInfo_option_range_start_character: i32, context_InlineCompletionContext_selectedCompletionInfo_option_range_end_line: i32, context_InlineCompletionContext_selectedCompletionInfo_option_range_end_character: i32, context_InlineCompletionContext_selectedCompletionInfo_option_text_ptr: i32, context_InlineCompletionContext_selectedCompletionInfo_option_text_len: i32, token: i32, result: ptr<InlineCompletionItem[] | undefined>) => void;
 		};
-		export function bind(service: all.Imports, code: $wcm.Code, context: $wcm.ComponentModelContext): Promise<all.Exports>;
-		export function bind(service: all.Imports.Promisified, code: $wcm.Code, port: $wcm.RAL.ConnectionPort, context: $wcm.ComponentModelContext): Promise<all.Exports.Promisified>;
-		export function bind(service: all.Imports | all.Imports.Promisified, code: $wcm.Code, portOrContext: $wcm.RAL.ConnectionPort | $wcm.ComponentModelContext, context?: $wcm.ComponentModelContext | undefined): Promise<all.Exports> | Promise<all.Exports.Promisified> {
+		export function bind(service: all.Imports, code: $wcm.Code, context?: $wcm.ComponentModelContext): Promise<all.Exports>;
+		export function bind(service: all.Imports.Promisified, code: $wcm.Code, port: $wcm.RAL.ConnectionPort, context?: $wcm.ComponentModelContext): Promise<all.Exports.Promisified>;
+		export function bind(service: all.Imports | all.Imports.Promisified, code: $wcm.Code, portOrContext?: $wcm.RAL.ConnectionPort | $wcm.ComponentModelContext, context?: $wcm.ComponentModelContext | undefined): Promise<all.Exports> | Promise<all.Exports.Promisified> {
 			return $wcm.$main.bind(_, service, code, portOrContext, context);
 		}
 	}
@@ -581,6 +3449,7 @@ export namespace api._ {
 	export const witName = 'api' as const;
 	export const interfaces: Map<string, $wcm.InterfaceType> = new Map<string, $wcm.InterfaceType>([
 		['Types', Types._],
+		['Env', Env._],
 		['Commands', Commands._],
 		['Languages', Languages._],
 		['Window', Window._],
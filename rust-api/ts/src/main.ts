@@ -4,7 +4,7 @@
  *--------------------------------------------------------------------------------------------*/
 import * as vscode from 'vscode';
 
-import { Memory, MemoryError, RAL, Resource, ResourceManager, ResourceManagers, WasmContext, type ResourceHandle } from '@vscode/wasm-component-model';
+import { Memory, MemoryError, RAL, Resource, ResourceManager, ResourceManagers, ResultError, WasmContext, result, type ResourceHandle } from '@vscode/wasm-component-model';
 
 import { api } from './api';
 import Types = api.Types;
@@ -24,6 +24,15 @@ namespace Converter {
 		return value.map(asDocumentFilter);
 	}
 
+	export function asDocumentSelector(value: Types.DocumentSelector): vscode.DocumentSelector {
+		switch (value.tag) {
+			case Types.DocumentSelector.single:
+				return asDocumentFilter(value.value);
+			case Types.DocumentSelector.many:
+				return asDocumentFilters(value.value);
+		}
+	}
+
 	function asPattern(value: Types.GlobPattern | undefined | null): vscode.GlobPattern | undefined {
 		if (value === undefined || value === null) {
 			return undefined;
@@ -33,12 +42,744 @@ namespace Converter {
 				return value.value;
 		}
 	}
+
+	export function asUri(value: Types.Uri): vscode.Uri {
+		return vscode.Uri.from(value);
+	}
+
+	export function asPosition(value: Types.Position): vscode.Position {
+		return new vscode.Position(value.line, value.character);
+	}
+
+	export function asRange(value: Types.Range): vscode.Range {
+		return new vscode.Range(asPosition(value.start), asPosition(value.end));
+	}
+
+	export function asSelection(value: Types.Selection): vscode.Selection {
+		return new vscode.Selection(asPosition(value.anchor), asPosition(value.active));
+	}
+
+	export function asLocation(value: Types.Location): vscode.Location {
+		return new vscode.Location(asUri(value.uri), asRange(value.range));
+	}
+
+	export function asLocationLink(value: Types.LocationLink): vscode.LocationLink {
+		return {
+			originSelectionRange: value.originSelectionRange !== undefined ? asRange(value.originSelectionRange) : undefined,
+			targetUri: asUri(value.targetUri),
+			targetRange: asRange(value.targetRange),
+			targetSelectionRange: value.targetSelectionRange !== undefined ? asRange(value.targetSelectionRange) : undefined
+		};
+	}
+
+	export function asDefinition(value: Types.Definition): vscode.Location[] | vscode.LocationLink[] {
+		switch (value.tag) {
+			case Types.Definition.locations:
+				return value.value.map(asLocation);
+			case Types.Definition.links:
+				return value.value.map(asLocationLink);
+		}
+	}
+
+	export function asDocumentHighlightKind(value: Types.DocumentHighlightKind): vscode.DocumentHighlightKind {
+		switch (value) {
+			case Types.DocumentHighlightKind.text:
+				return vscode.DocumentHighlightKind.Text;
+			case Types.DocumentHighlightKind.read:
+				return vscode.DocumentHighlightKind.Read;
+			case Types.DocumentHighlightKind.write:
+				return vscode.DocumentHighlightKind.Write;
+		}
+	}
+
+	export function asDocumentHighlight(value: Types.DocumentHighlight): vscode.DocumentHighlight {
+		return new vscode.DocumentHighlight(asRange(value.range), asDocumentHighlightKind(value.kind));
+	}
+
+	export function asFoldingRangeKind(value: Types.FoldingRangeKind | undefined): vscode.FoldingRangeKind | undefined {
+		switch (value) {
+			case Types.FoldingRangeKind.comment:
+				return vscode.FoldingRangeKind.Comment;
+			case Types.FoldingRangeKind.imports:
+				return vscode.FoldingRangeKind.Imports;
+			case Types.FoldingRangeKind.region:
+				return vscode.FoldingRangeKind.Region;
+			default:
+				return undefined;
+		}
+	}
+
+	export function asFoldingRange(value: Types.FoldingRange): vscode.FoldingRange {
+		return new vscode.FoldingRange(value.start, value.end, asFoldingRangeKind(value.kind));
+	}
+
+	export function asSelectionRange(value: Types.Range[]): vscode.SelectionRange {
+		let result: vscode.SelectionRange | undefined;
+		for (let i = value.length - 1; i >= 0; i--) {
+			result = new vscode.SelectionRange(asRange(value[i]), result);
+		}
+		if (result === undefined) {
+			throw new Error('Selection range chain must not be empty.');
+		}
+		return result;
+	}
+
+	export function asInlayHintKind(value: Types.InlayHintKind | undefined): vscode.InlayHintKind | undefined {
+		switch (value) {
+			case Types.InlayHintKind.type:
+				return vscode.InlayHintKind.Type;
+			case Types.InlayHintKind.parameter:
+				return vscode.InlayHintKind.Parameter;
+			default:
+				return undefined;
+		}
+	}
+
+	export function asInlayHintLabelPart(value: Types.InlayHintLabelPart): vscode.InlayHintLabelPart {
+		const result = new vscode.InlayHintLabelPart(value.value);
+		result.tooltip = value.tooltip;
+		result.location = value.location !== undefined ? asLocation(value.location) : undefined;
+		result.command = value.command !== undefined ? asCommand(value.command) : undefined;
+		return result;
+	}
+
+	export function asInlayHint(value: Types.InlayHint): vscode.InlayHint {
+		const label = value.label.tag === Types.InlayHintLabel.text ? value.label.value : value.label.value.map(asInlayHintLabelPart);
+		const result = new vscode.InlayHint(asPosition(value.position), label, asInlayHintKind(value.kind));
+		result.tooltip = value.tooltip;
+		result.paddingLeft = value.paddingLeft;
+		result.paddingRight = value.paddingRight;
+		result.textEdits = value.textEdits.map(asTextEdit);
+		return result;
+	}
+
+	export function asCodeLens(value: Types.CodeLens): vscode.CodeLens {
+		return new vscode.CodeLens(asRange(value.range), value.command !== undefined ? asCommand(value.command) : undefined);
+	}
+
+	export function asParameterInformation(value: Types.ParameterInformation): vscode.ParameterInformation {
+		return new vscode.ParameterInformation(value.label.value, value.documentation);
+	}
+
+	export function asSignatureInformation(value: Types.SignatureInformation): vscode.SignatureInformation {
+		const result = new vscode.SignatureInformation(value.label, value.documentation);
+		result.parameters = value.parameters.map(asParameterInformation);
+		result.activeParameter = value.activeParameter;
+		return result;
+	}
+
+	export function asSignatureHelp(value: Types.SignatureHelp): vscode.SignatureHelp {
+		const result = new vscode.SignatureHelp();
+		result.signatures = value.signatures.map(asSignatureInformation);
+		result.activeSignature = value.activeSignature;
+		result.activeParameter = value.activeParameter;
+		return result;
+	}
+
+	export function asSignatureHelpProviderMetadata(value: Types.SignatureHelpProviderMetadata): vscode.SignatureHelpProviderMetadata {
+		return { triggerCharacters: value.triggerCharacters, retriggerCharacters: value.retriggerCharacters };
+	}
+
+	export function asCallHierarchyItem(value: Types.HierarchyItem): vscode.CallHierarchyItem {
+		const result = new vscode.CallHierarchyItem(asSymbolKind(value.kind), value.name, value.detail ?? '', asUri(value.uri), asRange(value.range), asRange(value.selectionRange));
+		result.tags = value.tags.map(asSymbolTag);
+		return result;
+	}
+
+	export function asTypeHierarchyItem(value: Types.HierarchyItem): vscode.TypeHierarchyItem {
+		const result = new vscode.TypeHierarchyItem(asSymbolKind(value.kind), value.name, value.detail ?? '', asUri(value.uri), asRange(value.range), asRange(value.selectionRange));
+		result.tags = value.tags.map(asSymbolTag);
+		return result;
+	}
+
+	export function asColor(value: Types.Color): vscode.Color {
+		return new vscode.Color(value.red, value.green, value.blue, value.alpha);
+	}
+
+	export function asColorInformation(value: Types.ColorInformation): vscode.ColorInformation {
+		return new vscode.ColorInformation(asRange(value.range), asColor(value.color));
+	}
+
+	export function asColorPresentation(value: Types.ColorPresentation): vscode.ColorPresentation {
+		const result = new vscode.ColorPresentation(value.label);
+		result.textEdit = value.textEdit !== undefined ? asTextEdit(value.textEdit) : undefined;
+		result.additionalTextEdits = value.additionalTextEdits.map(asTextEdit);
+		return result;
+	}
+
+	export function asDocumentLink(value: Types.DocumentLink): vscode.DocumentLink {
+		const result = new vscode.DocumentLink(asRange(value.range), value.target !== undefined ? asUri(value.target) : undefined);
+		result.tooltip = value.tooltip;
+		return result;
+	}
+
+	export function asLinkedEditingRanges(value: Types.LinkedEditingRanges): vscode.LinkedEditingRanges {
+		return new vscode.LinkedEditingRanges(value.ranges.map(asRange), value.wordPattern !== undefined ? new RegExp(value.wordPattern) : undefined);
+	}
+
+	export function asInlineCompletionItem(value: Types.InlineCompletionItem): vscode.InlineCompletionItem {
+		const result = new vscode.InlineCompletionItem(value.insertText, value.range !== undefined ? asRange(value.range) : undefined, value.command !== undefined ? asCommand(value.command) : undefined);
+		result.filterText = value.filterText;
+		return result;
+	}
+
+	export function asIndentAction(value: Types.IndentAction): vscode.IndentAction {
+		switch (value) {
+			case Types.IndentAction.none:
+				return vscode.IndentAction.None;
+			case Types.IndentAction.indent:
+				return vscode.IndentAction.Indent;
+			case Types.IndentAction.indentOutdent:
+				return vscode.IndentAction.IndentOutdent;
+			case Types.IndentAction.outdent:
+				return vscode.IndentAction.Outdent;
+		}
+	}
+
+	function asRegExp(value: string | undefined): RegExp | undefined {
+		return value !== undefined ? new RegExp(value) : undefined;
+	}
+
+	export function asOnEnterRule(value: Types.OnEnterRule): vscode.OnEnterRule {
+		return {
+			beforeText: new RegExp(value.beforeText),
+			afterText: asRegExp(value.afterText),
+			previousLineText: asRegExp(value.previousLineText),
+			action: {
+				indentAction: asIndentAction(value.action.indentAction),
+				appendText: value.action.appendText,
+				removeText: value.action.removeText
+			}
+		};
+	}
+
+	export function asLanguageConfiguration(value: Types.LanguageConfiguration): vscode.LanguageConfiguration {
+		const indentationRules = value.indentationRules;
+		return {
+			comments: value.comments !== undefined ? { lineComment: value.comments.lineComment, blockComment: value.comments.blockComment } : undefined,
+			brackets: value.brackets,
+			wordPattern: asRegExp(value.wordPattern),
+			indentationRules: indentationRules !== undefined ? {
+				decreaseIndentPattern: new RegExp(indentationRules.decreaseIndentPattern),
+				increaseIndentPattern: new RegExp(indentationRules.increaseIndentPattern),
+				indentNextLinePattern: asRegExp(indentationRules.indentNextLinePattern),
+				unIndentedLinePattern: asRegExp(indentationRules.unindentedLinePattern)
+			} : undefined,
+			onEnterRules: value.onEnterRules.map(asOnEnterRule),
+			// The only way to define auto closing pairs in the 1.87 API.
+			__characterPairSupport: value.autoClosingPairs.length > 0 ? {
+				autoClosingPairs: value.autoClosingPairs.map(pair => ({ open: pair.open, close: pair.close, notIn: pair.notIn.length > 0 ? pair.notIn : undefined }))
+			} : undefined
+		};
+	}
+
+	export function asLanguageStatusSeverity(value: Types.LanguageStatusSeverity): vscode.LanguageStatusSeverity {
+		switch (value) {
+			case Types.LanguageStatusSeverity.information:
+				return vscode.LanguageStatusSeverity.Information;
+			case Types.LanguageStatusSeverity.warning:
+				return vscode.LanguageStatusSeverity.Warning;
+			case Types.LanguageStatusSeverity.error:
+				return vscode.LanguageStatusSeverity.Error;
+		}
+	}
+
+	export function asAccessibilityInformation(value: Types.AccessibilityInformation | undefined): vscode.AccessibilityInformation | undefined {
+		return value !== undefined ? { label: value.label, role: value.role } : undefined;
+	}
+
+	export function asDiagnosticSeverity(value: Types.DiagnosticSeverity): vscode.DiagnosticSeverity {
+		switch (value) {
+			case Types.DiagnosticSeverity.error:
+				return vscode.DiagnosticSeverity.Error;
+			case Types.DiagnosticSeverity.warning:
+				return vscode.DiagnosticSeverity.Warning;
+			case Types.DiagnosticSeverity.information:
+				return vscode.DiagnosticSeverity.Information;
+			case Types.DiagnosticSeverity.hint:
+				return vscode.DiagnosticSeverity.Hint;
+		}
+	}
+
+	export function asDiagnosticTag(value: Types.DiagnosticTag): vscode.DiagnosticTag {
+		switch (value) {
+			case Types.DiagnosticTag.unnecessary:
+				return vscode.DiagnosticTag.Unnecessary;
+			case Types.DiagnosticTag.deprecated:
+				return vscode.DiagnosticTag.Deprecated;
+		}
+	}
+
+	export function asDiagnosticCode(value: Types.DiagnosticCode | undefined): vscode.Diagnostic['code'] {
+		if (value === undefined) {
+			return undefined;
+		}
+		const code = value.value.value;
+		return value.target !== undefined ? { value: code, target: asUri(value.target) } : code;
+	}
+
+	export function asDiagnostic(value: Types.Diagnostic): vscode.Diagnostic {
+		const result = new vscode.Diagnostic(asRange(value.range), value.message, asDiagnosticSeverity(value.severity));
+		result.source = value.source;
+		result.code = asDiagnosticCode(value.code);
+		if (value.relatedInformation.length > 0) {
+			result.relatedInformation = value.relatedInformation.map(info => new vscode.DiagnosticRelatedInformation(asLocation(info.location), info.message));
+		}
+		if (value.tags.length > 0) {
+			result.tags = value.tags.map(asDiagnosticTag);
+		}
+		return result;
+	}
+
+	export function asTextEdit(value: Types.TextEdit): vscode.TextEdit {
+		return new vscode.TextEdit(asRange(value.range), value.newText);
+	}
+
+	export function asWorkspaceEdit(value: Types.WorkspaceEdit): vscode.WorkspaceEdit {
+		const result = new vscode.WorkspaceEdit();
+		for (const entry of value.entries) {
+			result.set(asUri(entry.uri), entry.edits.map(asTextEdit));
+		}
+		return result;
+	}
+
+	export function asCommand(value: Types.Command): vscode.Command {
+		return { title: value.title, command: value.command, tooltip: value.tooltip, arguments: value.arguments !== undefined ? JSON.parse(value.arguments) : undefined };
+	}
+
+	export function asCodeActionKind(value: string | undefined): vscode.CodeActionKind | undefined {
+		return value !== undefined ? vscode.CodeActionKind.Empty.append(value) : undefined;
+	}
+
+	export function asCodeAction(value: Types.CodeAction): vscode.CodeAction {
+		const result = new vscode.CodeAction(value.title, asCodeActionKind(value.kind));
+		if (value.diagnostics.length > 0) {
+			result.diagnostics = value.diagnostics.map(asDiagnostic);
+		}
+		result.edit = value.edit !== undefined ? asWorkspaceEdit(value.edit) : undefined;
+		result.command = value.command !== undefined ? asCommand(value.command) : undefined;
+		result.isPreferred = value.isPreferred;
+		result.disabled = value.disabled !== undefined ? { reason: value.disabled } : undefined;
+		return result;
+	}
+
+	export function asCodeActionProviderMetadata(value: Types.CodeActionProviderMetadata | undefined): vscode.CodeActionProviderMetadata | undefined {
+		if (value === undefined) {
+			return undefined;
+		}
+		return { providedCodeActionKinds: value.providedCodeActionKinds.map(kind => vscode.CodeActionKind.Empty.append(kind)) };
+	}
+
+	export function asSemanticTokensLegend(value: Types.SemanticTokensLegend): vscode.SemanticTokensLegend {
+		return new vscode.SemanticTokensLegend(value.tokenTypes, value.tokenModifiers);
+	}
+
+	export function asSemanticTokens(value: Types.SemanticTokens): vscode.SemanticTokens {
+		return new vscode.SemanticTokens(value.data, value.resultId);
+	}
+
+	export function asSemanticTokensEdits(value: Types.SemanticTokensEdits): vscode.SemanticTokensEdits {
+		return new vscode.SemanticTokensEdits(value.edits.map(edit => new vscode.SemanticTokensEdit(edit.start, edit.deleteCount, edit.data)), value.resultId);
+	}
+
+	export function asSemanticTokensResult(value: Types.SemanticTokensResult): vscode.SemanticTokens | vscode.SemanticTokensEdits {
+		switch (value.tag) {
+			case Types.SemanticTokensResult.full:
+				return asSemanticTokens(value.value);
+			case Types.SemanticTokensResult.edits:
+				return asSemanticTokensEdits(value.value);
+		}
+	}
+
+	const symbolKinds: Map<Types.SymbolKind, vscode.SymbolKind> = new Map([
+		[Types.SymbolKind.file, vscode.SymbolKind.File],
+		[Types.SymbolKind.module, vscode.SymbolKind.Module],
+		[Types.SymbolKind.namespace, vscode.SymbolKind.Namespace],
+		[Types.SymbolKind.package, vscode.SymbolKind.Package],
+		[Types.SymbolKind.class, vscode.SymbolKind.Class],
+		[Types.SymbolKind.method, vscode.SymbolKind.Method],
+		[Types.SymbolKind.property, vscode.SymbolKind.Property],
+		[Types.SymbolKind.field, vscode.SymbolKind.Field],
+		[Types.SymbolKind.constructor, vscode.SymbolKind.Constructor],
+		[Types.SymbolKind.enum, vscode.SymbolKind.Enum],
+		[Types.SymbolKind.interface, vscode.SymbolKind.Interface],
+		[Types.SymbolKind.function, vscode.SymbolKind.Function],
+		[Types.SymbolKind.variable, vscode.SymbolKind.Variable],
+		[Types.SymbolKind.constant, vscode.SymbolKind.Constant],
+		[Types.SymbolKind.string, vscode.SymbolKind.String],
+		[Types.SymbolKind.number, vscode.SymbolKind.Number],
+		[Types.SymbolKind.boolean, vscode.SymbolKind.Boolean],
+		[Types.SymbolKind.array, vscode.SymbolKind.Array],
+		[Types.SymbolKind.object, vscode.SymbolKind.Object],
+		[Types.SymbolKind.key, vscode.SymbolKind.Key],
+		[Types.SymbolKind.null, vscode.SymbolKind.Null],
+		[Types.SymbolKind.enumMember, vscode.SymbolKind.EnumMember],
+		[Types.SymbolKind.struct, vscode.SymbolKind.Struct],
+		[Types.SymbolKind.event, vscode.SymbolKind.Event],
+		[Types.SymbolKind.operator, vscode.SymbolKind.Operator],
+		[Types.SymbolKind.typeParameter, vscode.SymbolKind.TypeParameter]
+	]);
+	const symbolKindsReverse: Map<vscode.SymbolKind, Types.SymbolKind> = new Map(Array.from(symbolKinds, ([key, value]): [vscode.SymbolKind, Types.SymbolKind] => [value, key]));
+
+	export function asSymbolKind(value: Types.SymbolKind): vscode.SymbolKind {
+		return symbolKinds.get(value) ?? vscode.SymbolKind.Null;
+	}
+
+	export function asSymbolTag(value: Types.SymbolTag): vscode.SymbolTag {
+		switch (value) {
+			case Types.SymbolTag.deprecated:
+				return vscode.SymbolTag.Deprecated;
+		}
+	}
+
+	export function asDocumentSymbols(value: Types.DocumentSymbolNode[]): vscode.DocumentSymbol[] {
+		const result: vscode.DocumentSymbol[] = [];
+		const symbols: vscode.DocumentSymbol[] = [];
+		for (const node of value) {
+			const symbol = new vscode.DocumentSymbol(node.name, node.detail, asSymbolKind(node.kind), asRange(node.range), asRange(node.selectionRange));
+			symbol.tags = node.tags.map(asSymbolTag);
+			symbols.push(symbol);
+			if (node.parent === undefined) {
+				result.push(symbol);
+			} else {
+				symbols[node.parent].children.push(symbol);
+			}
+		}
+		return result;
+	}
+
+	export function asSymbolInformation(value: Types.SymbolInformation): vscode.SymbolInformation {
+		const result = new vscode.SymbolInformation(value.name, asSymbolKind(value.kind), value.containerName ?? '', asLocation(value.location));
+		result.tags = value.tags.map(asSymbolTag);
+		return result;
+	}
+
+	export function fromUri(value: vscode.Uri): Types.Uri {
+		return { scheme: value.scheme, authority: value.authority, path: value.path, query: value.query, fragment: value.fragment };
+	}
+
+	export function fromPosition(value: vscode.Position): Types.Position {
+		return { line: value.line, character: value.character };
+	}
+
+	export function fromRange(value: vscode.Range): Types.Range {
+		return { start: fromPosition(value.start), end: fromPosition(value.end) };
+	}
+
+	export function fromSelection(value: vscode.Selection): Types.Selection {
+		return { anchor: fromPosition(value.anchor), active: fromPosition(value.active) };
+	}
+
+	export function fromLocation(value: vscode.Location): Types.Location {
+		return { uri: fromUri(value.uri), range: fromRange(value.range) };
+	}
+
+	export function fromDiagnosticSeverity(value: vscode.DiagnosticSeverity): Types.DiagnosticSeverity {
+		switch (value) {
+			case vscode.DiagnosticSeverity.Error:
+				return Types.DiagnosticSeverity.error;
+			case vscode.DiagnosticSeverity.Warning:
+				return Types.DiagnosticSeverity.warning;
+			case vscode.DiagnosticSeverity.Information:
+				return Types.DiagnosticSeverity.information;
+			case vscode.DiagnosticSeverity.Hint:
+				return Types.DiagnosticSeverity.hint;
+		}
+	}
+
+	export function fromDiagnosticTag(value: vscode.DiagnosticTag): Types.DiagnosticTag {
+		switch (value) {
+			case vscode.DiagnosticTag.Unnecessary:
+				return Types.DiagnosticTag.unnecessary;
+			case vscode.DiagnosticTag.Deprecated:
+				return Types.DiagnosticTag.deprecated;
+		}
+	}
+
+	function fromDiagnosticCodeValue(value: string | number): Types.DiagnosticCodeValue {
+		return typeof value === 'string' ? Types.DiagnosticCodeValue.Text(value) : Types.DiagnosticCodeValue.Number(value);
+	}
+
+	export function fromDiagnosticCode(value: vscode.Diagnostic['code']): Types.DiagnosticCode | undefined {
+		if (value === undefined) {
+			return undefined;
+		}
+		if (typeof value === 'string' || typeof value === 'number') {
+			return { value: fromDiagnosticCodeValue(value) };
+		}
+		return { value: fromDiagnosticCodeValue(value.value), target: fromUri(value.target) };
+	}
+
+	export function fromCodeActionTriggerKind(value: vscode.CodeActionTriggerKind): Types.CodeActionTriggerKind {
+		switch (value) {
+			case vscode.CodeActionTriggerKind.Invoke:
+				return Types.CodeActionTriggerKind.invoke;
+			case vscode.CodeActionTriggerKind.Automatic:
+				return Types.CodeActionTriggerKind.automatic;
+		}
+	}
+
+	export function fromCodeActionContext(value: vscode.CodeActionContext): Types.CodeActionContext {
+		return {
+			diagnostics: value.diagnostics.map(fromDiagnostic),
+			only: value.only?.value,
+			triggerKind: fromCodeActionTriggerKind(value.triggerKind)
+		};
+	}
+
+	export function fromSymbolKind(value: vscode.SymbolKind): Types.SymbolKind {
+		return symbolKindsReverse.get(value) ?? Types.SymbolKind.null;
+	}
+
+	export function fromSymbolTag(value: vscode.SymbolTag): Types.SymbolTag {
+		switch (value) {
+			case vscode.SymbolTag.Deprecated:
+				return Types.SymbolTag.deprecated;
+		}
+	}
+
+	export function fromSymbolInformation(value: vscode.SymbolInformation): Types.SymbolInformation {
+		return {
+			name: value.name,
+			containerName: value.containerName !== '' ? value.containerName : undefined,
+			kind: fromSymbolKind(value.kind),
+			tags: (value.tags ?? []).map(fromSymbolTag),
+			location: fromLocation(value.location)
+		};
+	}
+
+	function fromDocumentation(value: string | vscode.MarkdownString | undefined): string | undefined {
+		return typeof value === 'string' || value === undefined ? value : value.value;
+	}
+
+	export function fromParameterInformation(value: vscode.ParameterInformation): Types.ParameterInformation {
+		return {
+			label: typeof value.label === 'string' ? Types.ParameterLabel.Text(value.label) : Types.ParameterLabel.Offsets(value.label),
+			documentation: fromDocumentation(value.documentation)
+		};
+	}
+
+	export function fromSignatureInformation(value: vscode.SignatureInformation): Types.SignatureInformation {
+		return {
+			label: value.label,
+			documentation: fromDocumentation(value.documentation),
+			parameters: value.parameters.map(fromParameterInformation),
+			activeParameter: value.activeParameter
+		};
+	}
+
+	export function fromSignatureHelp(value: vscode.SignatureHelp): Types.SignatureHelp {
+		return {
+			signatures: value.signatures.map(fromSignatureInformation),
+			activeSignature: value.activeSignature,
+			activeParameter: value.activeParameter
+		};
+	}
+
+	export function fromSignatureHelpTriggerKind(value: vscode.SignatureHelpTriggerKind): Types.SignatureHelpTriggerKind {
+		switch (value) {
+			case vscode.SignatureHelpTriggerKind.Invoke:
+				return Types.SignatureHelpTriggerKind.invoke;
+			case vscode.SignatureHelpTriggerKind.TriggerCharacter:
+				return Types.SignatureHelpTriggerKind.triggerCharacter;
+			case vscode.SignatureHelpTriggerKind.ContentChange:
+				return Types.SignatureHelpTriggerKind.contentChange;
+		}
+	}
+
+	export function fromSignatureHelpContext(value: vscode.SignatureHelpContext): Types.SignatureHelpContext {
+		return {
+			triggerKind: fromSignatureHelpTriggerKind(value.triggerKind),
+			triggerCharacter: value.triggerCharacter,
+			isRetrigger: value.isRetrigger,
+			activeSignatureHelp: value.activeSignatureHelp !== undefined ? fromSignatureHelp(value.activeSignatureHelp) : undefined
+		};
+	}
+
+	export function fromColor(value: vscode.Color): Types.Color {
+		return { red: value.red, green: value.green, blue: value.blue, alpha: value.alpha };
+	}
+
+	export function fromInlineCompletionTriggerKind(value: vscode.InlineCompletionTriggerKind): Types.InlineCompletionTriggerKind {
+		switch (value) {
+			case vscode.InlineCompletionTriggerKind.Invoke:
+				return Types.InlineCompletionTriggerKind.invoke;
+			case vscode.InlineCompletionTriggerKind.Automatic:
+				return Types.InlineCompletionTriggerKind.automatic;
+		}
+	}
+
+	export function fromInlineCompletionContext(value: vscode.InlineCompletionContext): Types.InlineCompletionContext {
+		const info = value.selectedCompletionInfo;
+		return {
+			triggerKind: fromInlineCompletionTriggerKind(value.triggerKind),
+			selectedCompletionInfo: info !== undefined ? { range: fromRange(info.range), text: info.text } : undefined
+		};
+	}
+
+	export function fromCommand(value: vscode.Command | undefined): Types.Command | undefined {
+		return value !== undefined ? { title: value.title, command: value.command, tooltip: value.tooltip, arguments: value.arguments !== undefined ? JSON.stringify(value.arguments) : undefined } : undefined;
+	}
+
+	export function fromUIKind(value: vscode.UIKind): Types.UiKind {
+		switch (value) {
+			case vscode.UIKind.Desktop:
+				return Types.UiKind.desktop;
+			case vscode.UIKind.Web:
+				return Types.UiKind.web;
+		}
+	}
+
+	export function fromExtensionMode(value: vscode.ExtensionMode): Types.ExtensionMode {
+		switch (value) {
+			case vscode.ExtensionMode.Production:
+				return Types.ExtensionMode.production;
+			case vscode.ExtensionMode.Development:
+				return Types.ExtensionMode.development;
+			case vscode.ExtensionMode.Test:
+				return Types.ExtensionMode.test;
+		}
+	}
+
+	export function fromExtensionContext(value: vscode.ExtensionContext): Types.ExtensionContext {
+		return {
+			globalState: new MementoResource(value.globalState, true),
+			workspaceState: new MementoResource(value.workspaceState, false),
+			secrets: new SecretStorageResource(value.secrets),
+			extensionUri: fromUri(value.extensionUri),
+			extensionPath: value.extensionPath,
+			extensionMode: fromExtensionMode(value.extensionMode),
+			storageUri: value.storageUri !== undefined ? fromUri(value.storageUri) : undefined,
+			globalStorageUri: fromUri(value.globalStorageUri),
+			logUri: fromUri(value.logUri)
+		};
+	}
+
+	export function fromLanguageStatusSeverity(value: vscode.LanguageStatusSeverity): Types.LanguageStatusSeverity {
+		switch (value) {
+			case vscode.LanguageStatusSeverity.Information:
+				return Types.LanguageStatusSeverity.information;
+			case vscode.LanguageStatusSeverity.Warning:
+				return Types.LanguageStatusSeverity.warning;
+			case vscode.LanguageStatusSeverity.Error:
+				return Types.LanguageStatusSeverity.error;
+		}
+	}
+
+	export function fromAccessibilityInformation(value: vscode.AccessibilityInformation | undefined): Types.AccessibilityInformation | undefined {
+		return value !== undefined ? { label: value.label, role: value.role } : undefined;
+	}
+
+	export function fromDiagnostic(value: vscode.Diagnostic): Types.Diagnostic {
+		return {
+			range: fromRange(value.range),
+			message: value.message,
+			severity: fromDiagnosticSeverity(value.severity),
+			source: value.source,
+			code: fromDiagnosticCode(value.code),
+			relatedInformation: (value.relatedInformation ?? []).map(info => ({ location: fromLocation(info.location), message: info.message })),
+			tags: (value.tags ?? []).map(fromDiagnosticTag)
+		};
+	}
+
+	export function fromFormattingOptions(value: vscode.FormattingOptions): Types.FormattingOptions {
+		const properties: Types.FormattingOption[] = [];
+		for (const key of Object.keys(value)) {
+			if (key === 'tabSize' || key === 'insertSpaces') {
+				continue;
+			}
+			const property = value[key];
+			if (typeof property === 'boolean') {
+				properties.push({ key, value: Types.FormattingOptionValue.Boolean(property) });
+			} else if (typeof property === 'number') {
+				properties.push({ key, value: Types.FormattingOptionValue.Number(property) });
+			} else if (typeof property === 'string') {
+				properties.push({ key, value: Types.FormattingOptionValue.Text(property) });
+			}
+		}
+		return { tabSize: value.tabSize, insertSpaces: value.insertSpaces, properties };
+	}
 }
 
+class MementoResource extends Resource.Default implements Types.Memento {
+
+	public static readonly $resources: ResourceManager<Types.Memento> = new ResourceManager.Default();
+
+	private readonly memento: vscode.Memento;
+	private readonly global: boolean;
+
+	constructor(memento: vscode.Memento, global: boolean) {
+		super(MementoResource.$resources);
+		this.memento = memento;
+		this.global = global;
+	}
+
+	$drop(): void {
+	}
+
+	get(key: string): string | undefined {
+		const value = this.memento.get(key);
+		return value !== undefined ? JSON.stringify(value) : undefined;
+	}
+
+	keys(): string[] {
+		return this.memento.keys().slice();
+	}
+
+	update(operation: number, key: string, value: string | undefined): void {
+		completeOperation(operation, this.memento.update(key, value !== undefined ? JSON.parse(value) : undefined));
+	}
+
+	setKeysForSync(keys: string[]): void {
+		if (this.global) {
+			(this.memento as vscode.Memento & { setKeysForSync(keys: readonly string[]): void }).setKeysForSync(keys);
+		}
+	}
+}
+
+class SecretStorageResource extends Resource.Default implements Types.SecretStorage {
+
+	public static readonly $resources: ResourceManager<Types.SecretStorage> = new ResourceManager.Default();
+
+	private readonly secrets: vscode.SecretStorage;
+	private changeListener: vscode.Disposable | undefined;
+
+	constructor(secrets: vscode.SecretStorage) {
+		super(SecretStorageResource.$resources);
+		this.secrets = secrets;
+	}
+
+	$drop(): void {
+		this.unregisterOnDidChange();
+	}
+
+	get(operation: number, key: string): void {
+		completeOperation(operation, this.secrets.get(key));
+	}
+
+	store(operation: number, key: string, value: string): void {
+		completeOperation(operation, this.secrets.store(key, value));
+	}
+
+	delete(operation: number, key: string): void {
+		completeOperation(operation, this.secrets.delete(key));
+	}
+
+	registerOnDidChange(): void {
+		if (this.changeListener !== undefined) {
+			return;
+		}
+		this.changeListener = this.secrets.onDidChange((e) => {
+			extension?.callbacks.didChangeSecret({ key: e.key });
+		});
+	}
 
-interface Extension {
-	activate?(): void;
-	deactivate?(): void;
+	unregisterOnDidChange(): void {
+		if (this.changeListener !== undefined) {
+			this.changeListener.dispose();
+			this.changeListener = undefined;
+		}
+	}
 }
 
 // Channel implementation
@@ -74,6 +815,140 @@ class OutputChannelResource extends Resource.Default implements OutputChannel {
 	}
 }
 
+class CancellationTokenResource extends Resource.Default implements Types.CancellationToken {
+
+	public static $resources: ResourceManager<Types.CancellationToken> = new ResourceManager.Default();
+
+	private readonly token: vscode.CancellationToken;
+
+	constructor(token: vscode.CancellationToken) {
+		super(CancellationTokenResource.$resources);
+		this.token = token;
+	}
+
+	public $drop(): void {
+	}
+
+	isCancellationRequested(): boolean {
+		return this.token.isCancellationRequested;
+	}
+}
+
+class DiagnosticCollectionResource extends Resource.Default implements Types.DiagnosticCollection {
+
+	public static $resources: ResourceManager<Types.DiagnosticCollection> = new ResourceManager.Default();
+
+	private collection: vscode.DiagnosticCollection;
+
+	constructor(name?: string) {
+		super(DiagnosticCollectionResource.$resources);
+		this.collection = vscode.languages.createDiagnosticCollection(name);
+	}
+
+	public $drop(): void {
+		this.collection.dispose();
+	}
+
+	name(): string {
+		return this.collection.name;
+	}
+	set(uri: Types.Uri, diagnostics: Types.Diagnostic[]): void {
+		this.collection.set(Converter.asUri(uri), diagnostics.map(Converter.asDiagnostic));
+	}
+	delete(uri: Types.Uri): void {
+		this.collection.delete(Converter.asUri(uri));
+	}
+	clear(): void {
+		this.collection.clear();
+	}
+	get(uri: Types.Uri): Types.Diagnostic[] {
+		const diagnostics = this.collection.get(Converter.asUri(uri));
+		return diagnostics !== undefined ? diagnostics.map(Converter.fromDiagnostic) : [];
+	}
+	has(uri: Types.Uri): boolean {
+		return this.collection.has(Converter.asUri(uri));
+	}
+	entries(): Types.DiagnosticEntry[] {
+		const result: Types.DiagnosticEntry[] = [];
+		this.collection.forEach((uri, diagnostics) => {
+			result.push({ uri: Converter.fromUri(uri), diagnostics: diagnostics.map(Converter.fromDiagnostic) });
+		});
+		return result;
+	}
+}
+
+class LanguageStatusItemResource extends Resource.Default implements Types.LanguageStatusItem {
+
+	public static $resources: ResourceManager<Types.LanguageStatusItem> = new ResourceManager.Default();
+
+	private item: vscode.LanguageStatusItem;
+	// Converting a VS Code selector back is lossy, so keep the guest's one.
+	private documentSelector: Types.DocumentSelector;
+
+	constructor(id: string, selector: Types.DocumentSelector) {
+		super(LanguageStatusItemResource.$resources);
+		this.item = vscode.languages.createLanguageStatusItem(id, Converter.asDocumentSelector(selector));
+		this.documentSelector = selector;
+	}
+
+	public $drop(): void {
+		this.item.dispose();
+	}
+
+	id(): string {
+		return this.item.id;
+	}
+	name(): string | undefined {
+		return this.item.name;
+	}
+	setName(name: string | undefined): void {
+		this.item.name = name;
+	}
+	selector(): Types.DocumentSelector {
+		return this.documentSelector;
+	}
+	setSelector(selector: Types.DocumentSelector): void {
+		this.documentSelector = selector;
+		this.item.selector = Converter.asDocumentSelector(selector);
+	}
+	severity(): Types.LanguageStatusSeverity {
+		return Converter.fromLanguageStatusSeverity(this.item.severity);
+	}
+	setSeverity(severity: Types.LanguageStatusSeverity): void {
+		this.item.severity = Converter.asLanguageStatusSeverity(severity);
+	}
+	text(): string {
+		return this.item.text;
+	}
+	setText(text: string): void {
+		this.item.text = text;
+	}
+	detail(): string | undefined {
+		return this.item.detail;
+	}
+	setDetail(detail: string | undefined): void {
+		this.item.detail = detail;
+	}
+	busy(): boolean {
+		return this.item.busy;
+	}
+	setBusy(busy: boolean): void {
+		this.item.busy = busy;
+	}
+	command(): Types.Command | undefined {
+		return Converter.fromCommand(this.item.command);
+	}
+	setCommand(command: Types.Command | undefined): void {
+		this.item.command = command !== undefined ? Converter.asCommand(command) : undefined;
+	}
+	accessibilityInformation(): Types.AccessibilityInformation | undefined {
+		return Converter.fromAccessibilityInformation(this.item.accessibilityInformation);
+	}
+	setAccessibilityInformation(accessibilityInformation: Types.AccessibilityInformation | undefined): void {
+		this.item.accessibilityInformation = Converter.asAccessibilityInformation(accessibilityInformation);
+	}
+}
+
 class TextDocumentResourceManager extends ResourceManager.Default<TextDocumentResource> {
 
 	private readonly document2Handle: WeakMap<vscode.TextDocument, ResourceHandle<TextDocumentResource>> = new WeakMap();
@@ -115,8 +990,8 @@ class TextDocumentResource extends Resource.Default implements TextDocument {
 		this.textDocument = document;
 	}
 
-	public uri(): string {
-		return this.textDocument.uri.toString();
+	public uri(): Types.Uri {
+		return Converter.fromUri(this.textDocument.uri);
 	}
 
 	public languageId(): string {
@@ -132,6 +1007,37 @@ class TextDocumentResource extends Resource.Default implements TextDocument {
 	}
 }
 
+class TextEditorResource extends Resource.Default implements Types.TextEditor {
+
+	public static readonly $resources: ResourceManager<Types.TextEditor> = new ResourceManager.Default();
+
+	private readonly editor: vscode.TextEditor;
+
+	constructor(editor: vscode.TextEditor) {
+		super(TextEditorResource.$resources);
+		this.editor = editor;
+	}
+
+	$drop(): void {
+	}
+
+	document(): Types.TextDocument {
+		return TextDocumentResource.$resources.getOrCreate(this.editor.document);
+	}
+
+	selection(): Types.Selection {
+		return Converter.fromSelection(this.editor.selection);
+	}
+
+	selections(): Types.Selection[] {
+		return this.editor.selections.map(Converter.fromSelection);
+	}
+
+	setSelections(selections: Types.Selection[]): void {
+		this.editor.selections = selections.map(Converter.asSelection);
+	}
+}
+
 class TextDocumentChangeEventResource extends Resource.Default implements Types.TextDocumentChangeEvent {
 
 	public static readonly $resources: ResourceManager<Types.TextDocumentChangeEvent> = new ResourceManager.Default();
@@ -159,21 +1065,64 @@ class TextDocumentChangeEventResource extends Resource.Default implements Types.
 	}
 }
 
+/**
+ * Encodes the arguments a command is called with. Arguments that can't be
+ * encoded, e.g. because they are circular, fail the command.
+ */
+function stringifyArguments(command: string, args: any[]): string {
+	try {
+		return JSON.stringify(args);
+	} catch (error) {
+		throw new Error(`Can't pass the arguments of command ${command} to the extension: ${error instanceof Error ? error.message : String(error)}`);
+	}
+}
+
 class CommandRegistry {
 
 	private commands: Map<string, vscode.Disposable> = new Map();
 	private callback!: api.Callbacks.executeCommand;
+	private textEditorCallback!: api.Callbacks.executeTextEditorCommand;
 
 	constructor() {
 	}
 
-	initialize(callback: api.Callbacks.executeCommand): void {
+	initialize(callback: api.Callbacks.executeCommand, textEditorCallback: api.Callbacks.executeTextEditorCommand): void {
 		this.callback = callback;
+		this.textEditorCallback = textEditorCallback;
 	}
 
 	register(command: string): void {
-		const disposable = vscode.commands.registerCommand(command, () => {
-			this.callback(command);
+		const disposable = vscode.commands.registerCommand(command, (...args: any[]) => {
+			let result: string;
+			try {
+				result = this.callback(command, stringifyArguments(command, args));
+			} catch (error) {
+				if (error instanceof ResultError) {
+					throw new Error(error.cause);
+				}
+				throw error;
+			}
+			return JSON.parse(result);
+		});
+		this.commands.set(command, disposable);
+	}
+
+	registerTextEditor(command: string): void {
+		const disposable = vscode.commands.registerTextEditorCommand(command, (editor, edit, ...args: any[]) => {
+			let edits: Types.TextEdit[];
+			try {
+				edits = this.textEditorCallback(command, new TextEditorResource(editor), stringifyArguments(command, args));
+			} catch (error) {
+				if (error instanceof ResultError) {
+					throw new Error(error.cause);
+				}
+				throw error;
+			}
+			// The edit builder is only valid while the callback runs. All edits
+			// are applied together once it returns.
+			for (const textEdit of edits) {
+				edit.replace(Converter.asRange(textEdit.range), textEdit.newText);
+			}
 		});
 		this.commands.set(command, disposable);
 	}
@@ -194,9 +1143,98 @@ class CommandRegistry {
 
 }
 
+/**
+ * Converts the items a provider returns and remembers the guest's value of
+ * each, so that it can be passed back when VS Code resolves the item.
+ */
+class GuestItems<T extends object, V> {
+
+	private readonly convert: (value: V) => T;
+	private readonly values: WeakMap<T, V> = new WeakMap();
+
+	constructor(convert: (value: V) => T) {
+		this.convert = convert;
+	}
+
+	from(result: V[] | undefined): T[] | undefined {
+		if (result === undefined) {
+			return undefined;
+		}
+		return result.map(value => {
+			const item = this.convert(value);
+			this.values.set(item, value);
+			return item;
+		});
+	}
+
+	get(item: T): V | undefined {
+		return this.values.get(item);
+	}
+
+}
+
+class ProviderRegistry {
+
+	private providers: Map<number, vscode.Disposable> = new Map();
+	private emitters: Map<number, vscode.EventEmitter<void>> = new Map();
+
+	register(provider: number, disposable: vscode.Disposable): void {
+		this.providers.set(provider, disposable);
+	}
+
+	/**
+	 * Creates the change event of a provider. The guest fires it to make
+	 * VS Code request the provider's results again.
+	 */
+	createOnDidChange(provider: number): vscode.Event<void> {
+		const emitter = new vscode.EventEmitter<void>();
+		this.emitters.set(provider, emitter);
+		return emitter.event;
+	}
+
+	fireDidChange(provider: number): void {
+		this.emitters.get(provider)?.fire();
+	}
+
+	unregister(provider: number): void {
+		const disposable = this.providers.get(provider);
+		if (disposable !== undefined) {
+			this.providers.delete(provider);
+			disposable.dispose();
+		}
+		const emitter = this.emitters.get(provider);
+		if (emitter !== undefined) {
+			this.emitters.delete(provider);
+			emitter.dispose();
+		}
+	}
+
+	dispose(): void {
+		for (const disposable of this.providers.values()) {
+			disposable.dispose();
+		}
+		this.providers.clear();
+		for (const emitter of this.emitters.values()) {
+			emitter.dispose();
+		}
+		this.emitters.clear();
+	}
+}
+
 const commandRegistry = new CommandRegistry();
-let instance: WebAssembly_.Instance;
-export async function activate(_context: vscode.ExtensionContext, module: WebAssembly_.Module): Promise<void> {
+const providerRegistry = new ProviderRegistry();
+let extension: api.all.Exports | undefined;
+
+// Promises returned by VS Code are reported back to the guest once they settle.
+function completeOperation(operation: number, thenable: Thenable<unknown>): void {
+	thenable.then((value) => JSON.stringify(value ?? null)).then((value) => {
+		extension?.callbacks.completeOperation(operation, result.Ok(value));
+	}, (error) => {
+		extension?.callbacks.completeOperation(operation, result.Error(error instanceof Error ? error.message : String(error)));
+	});
+}
+
+export async function activate(context: vscode.ExtensionContext, module: WebAssembly_.Module): Promise<void> {
 	let memory: Memory | undefined;
 	const wasmContext: WasmContext = {
 		options: { encoding: 'utf-8' },
@@ -209,15 +1247,40 @@ export async function activate(_context: vscode.ExtensionContext, module: WebAss
 		}
 	};
 	let textDocumentChangeListener: vscode.Disposable | undefined;
+	let diagnosticsChangeListener: vscode.Disposable | undefined;
+	// Getting the languages is asynchronous in VS Code but synchronous for the
+	// guest. Keep a copy that is refreshed when extensions are added or removed.
+	let languages: string[] = await vscode.languages.getLanguages();
+	context.subscriptions.push(vscode.extensions.onDidChange(async () => {
+		languages = await vscode.languages.getLanguages();
+	}));
 	const service: api.all.Imports = {
 		types: {
 			OutputChannel: OutputChannelResource,
 			TextDocument: TextDocumentResource,
-			TextDocumentChangeEvent: TextDocumentChangeEventResource
+			TextEditor: TextEditorResource,
+			Memento: MementoResource,
+			SecretStorage: SecretStorageResource,
+			TextDocumentChangeEvent: TextDocumentChangeEventResource,
+			DiagnosticCollection: DiagnosticCollectionResource,
+			LanguageStatusItem: LanguageStatusItemResource,
+			CancellationToken: CancellationTokenResource
 		},
 		window: {
 			createOutputChannel: (name: string, languageId?: string) => {
 				return new OutputChannelResource(name, languageId);
+			},
+			showInformationMessage: (operation: number, message: string, items: string[]) => {
+				completeOperation(operation, vscode.window.showInformationMessage(message, ...items));
+			},
+			showWarningMessage: (operation: number, message: string, items: string[]) => {
+				completeOperation(operation, vscode.window.showWarningMessage(message, ...items));
+			},
+			showErrorMessage: (operation: number, message: string, items: string[]) => {
+				completeOperation(operation, vscode.window.showErrorMessage(message, ...items));
+			},
+			showQuickPick: (operation: number, items: string[]) => {
+				completeOperation(operation, vscode.window.showQuickPick(items));
 			}
 		},
 		workspace: {
@@ -251,12 +1314,71 @@ export async function activate(_context: vscode.ExtensionContext, module: WebAss
 				return vscode.workspace.textDocuments.map(document => TextDocumentResource.$resources.getOrCreate(document));
 			}
 		},
+		env: {
+			appName: () => {
+				return vscode.env.appName;
+			},
+			appRoot: () => {
+				return vscode.env.appRoot;
+			},
+			appHost: () => {
+				return vscode.env.appHost;
+			},
+			getUiKind: () => {
+				return Converter.fromUIKind(vscode.env.uiKind);
+			},
+			language: () => {
+				return vscode.env.language;
+			},
+			machineId: () => {
+				return vscode.env.machineId;
+			},
+			sessionId: () => {
+				return vscode.env.sessionId;
+			},
+			remoteName: () => {
+				return vscode.env.remoteName;
+			},
+			shell: () => {
+				return vscode.env.shell;
+			},
+			uriScheme: () => {
+				return vscode.env.uriScheme;
+			},
+			isTelemetryEnabled: () => {
+				return vscode.env.isTelemetryEnabled;
+			},
+			clipboardReadText: (operation: number) => {
+				completeOperation(operation, vscode.env.clipboard.readText());
+			},
+			clipboardWriteText: (operation: number, value: string) => {
+				completeOperation(operation, vscode.env.clipboard.writeText(value));
+			},
+			openExternal: (operation: number, uri: Types.Uri) => {
+				completeOperation(operation, vscode.env.openExternal(Converter.asUri(uri)));
+			},
+			asExternalUri: (operation: number, uri: Types.Uri) => {
+				completeOperation(operation, vscode.env.asExternalUri(Converter.asUri(uri)).then(Converter.fromUri));
+			}
+		},
 		commands: {
 			registerCommand: (command: string) => {
 				commandRegistry.register(command);
 			},
+			registerTextEditorCommand: (command: string) => {
+				commandRegistry.registerTextEditor(command);
+			},
 			unregisterCommand: (command: string) => {
 				commandRegistry.unregister(command);
+			},
+			executeCommand: (operation: number, command: string, args: string) => {
+				completeOperation(operation, vscode.commands.executeCommand(command, ...JSON.parse(args)));
+			},
+			getCommands: (operation: number, filterInternal: boolean) => {
+				completeOperation(operation, vscode.commands.getCommands(filterInternal));
+			},
+			setContext: (key: string, value: Types.ContextValue) => {
+				void vscode.commands.executeCommand('setContext', key, value.value);
 			}
 		},
 		languages: {
@@ -268,26 +1390,386 @@ export async function activate(_context: vscode.ExtensionContext, module: WebAss
 				} else {
 					return 0;
 				}
+			},
+			createDiagnosticCollection: (name: string | undefined) => {
+				return new DiagnosticCollectionResource(name);
+			},
+			getDiagnostics: (uri: Types.Uri) => {
+				return vscode.languages.getDiagnostics(Converter.asUri(uri)).map(Converter.fromDiagnostic);
+			},
+			registerOnDidChangeDiagnostics: () => {
+				if (diagnosticsChangeListener !== undefined) {
+					return;
+				}
+				diagnosticsChangeListener = vscode.languages.onDidChangeDiagnostics(e => {
+					$exports.callbacks.didChangeDiagnostics({ uris: e.uris.map(Converter.fromUri) });
+				});
+			},
+			unregisterOnDidChangeDiagnostics: () => {
+				if (diagnosticsChangeListener !== undefined) {
+					diagnosticsChangeListener.dispose();
+					diagnosticsChangeListener = undefined;
+				}
+			},
+			unregisterProvider: (provider: number) => {
+				providerRegistry.unregister(provider);
+			},
+			fireDidChangeProvider: (provider: number) => {
+				providerRegistry.fireDidChange(provider);
+			},
+			registerCodeActionsProvider: (provider: number, selector: Types.DocumentSelector, metadata: Types.CodeActionProviderMetadata | undefined) => {
+				const actions = new GuestItems<vscode.CodeAction, Types.CodeAction>(Converter.asCodeAction);
+				providerRegistry.register(provider, vscode.languages.registerCodeActionsProvider(Converter.asDocumentSelector(selector), {
+					provideCodeActions: (document, range, context, token) => {
+						return actions.from($exports.callbacks.provideCodeActions(provider, TextDocumentResource.$resources.getOrCreate(document), Converter.fromRange(range), Converter.fromCodeActionContext(context), new CancellationTokenResource(token)));
+					},
+					resolveCodeAction: (codeAction, token) => {
+						const value = actions.get(codeAction);
+						if (value === undefined) {
+							return codeAction;
+						}
+						const resolved = Converter.asCodeAction($exports.callbacks.resolveCodeAction(provider, value, new CancellationTokenResource(token)));
+						codeAction.edit = resolved.edit;
+						codeAction.command = resolved.command;
+						return codeAction;
+					}
+				}, Converter.asCodeActionProviderMetadata(metadata)));
+			},
+			registerDocumentFormattingEditProvider: (provider: number, selector: Types.DocumentSelector) => {
+				providerRegistry.register(provider, vscode.languages.registerDocumentFormattingEditProvider(Converter.asDocumentSelector(selector), {
+					provideDocumentFormattingEdits: (document, options, token) => {
+						return $exports.callbacks.provideDocumentFormattingEdits(provider, TextDocumentResource.$resources.getOrCreate(document), Converter.fromFormattingOptions(options), new CancellationTokenResource(token)).map(Converter.asTextEdit);
+					}
+				}));
+			},
+			registerDocumentRangeFormattingEditProvider: (provider: number, selector: Types.DocumentSelector) => {
+				providerRegistry.register(provider, vscode.languages.registerDocumentRangeFormattingEditProvider(Converter.asDocumentSelector(selector), {
+					provideDocumentRangeFormattingEdits: (document, range, options, token) => {
+						return $exports.callbacks.provideDocumentRangeFormattingEdits(provider, TextDocumentResource.$resources.getOrCreate(document), Converter.fromRange(range), Converter.fromFormattingOptions(options), new CancellationTokenResource(token)).map(Converter.asTextEdit);
+					}
+				}));
+			},
+			registerOnTypeFormattingEditProvider: (provider: number, selector: Types.DocumentSelector, firstTriggerCharacter: string, moreTriggerCharacter: string[]) => {
+				providerRegistry.register(provider, vscode.languages.registerOnTypeFormattingEditProvider(Converter.asDocumentSelector(selector), {
+					provideOnTypeFormattingEdits: (document, position, ch, options, token) => {
+						return $exports.callbacks.provideOnTypeFormattingEdits(provider, TextDocumentResource.$resources.getOrCreate(document), Converter.fromPosition(position), ch, Converter.fromFormattingOptions(options), new CancellationTokenResource(token)).map(Converter.asTextEdit);
+					}
+				}, firstTriggerCharacter, ...moreTriggerCharacter));
+			},
+			registerDocumentSemanticTokensProvider: (provider: number, selector: Types.DocumentSelector, legend: Types.SemanticTokensLegend) => {
+				providerRegistry.register(provider, vscode.languages.registerDocumentSemanticTokensProvider(Converter.asDocumentSelector(selector), {
+					provideDocumentSemanticTokens: (document, token) => {
+						const result = $exports.callbacks.provideDocumentSemanticTokens(provider, TextDocumentResource.$resources.getOrCreate(document), new CancellationTokenResource(token));
+						return result !== undefined ? Converter.asSemanticTokens(result) : undefined;
+					},
+					provideDocumentSemanticTokensEdits: (document, previousResultId, token) => {
+						const result = $exports.callbacks.provideDocumentSemanticTokensEdits(provider, TextDocumentResource.$resources.getOrCreate(document), previousResultId, new CancellationTokenResource(token));
+						return result !== undefined ? Converter.asSemanticTokensResult(result) : undefined;
+					}
+				}, Converter.asSemanticTokensLegend(legend)));
+			},
+			registerDocumentRangeSemanticTokensProvider: (provider: number, selector: Types.DocumentSelector, legend: Types.SemanticTokensLegend) => {
+				providerRegistry.register(provider, vscode.languages.registerDocumentRangeSemanticTokensProvider(Converter.asDocumentSelector(selector), {
+					provideDocumentRangeSemanticTokens: (document, range, token) => {
+						const result = $exports.callbacks.provideDocumentRangeSemanticTokens(provider, TextDocumentResource.$resources.getOrCreate(document), Converter.fromRange(range), new CancellationTokenResource(token));
+						return result !== undefined ? Converter.asSemanticTokens(result) : undefined;
+					}
+				}, Converter.asSemanticTokensLegend(legend)));
+			},
+			registerDocumentSymbolProvider: (provider: number, selector: Types.DocumentSelector) => {
+				providerRegistry.register(provider, vscode.languages.registerDocumentSymbolProvider(Converter.asDocumentSelector(selector), {
+					provideDocumentSymbols: (document, token) => {
+						const result = $exports.callbacks.provideDocumentSymbols(provider, TextDocumentResource.$resources.getOrCreate(document), new CancellationTokenResource(token));
+						return result !== undefined ? Converter.asDocumentSymbols(result) : undefined;
+					}
+				}));
+			},
+			registerWorkspaceSymbolProvider: (provider: number) => {
+				providerRegistry.register(provider, vscode.languages.registerWorkspaceSymbolProvider({
+					provideWorkspaceSymbols: (query, token) => {
+						const result = $exports.callbacks.provideWorkspaceSymbols(provider, query, new CancellationTokenResource(token));
+						return result !== undefined ? result.map(Converter.asSymbolInformation) : undefined;
+					},
+					resolveWorkspaceSymbol: (symbol, token) => {
+						return Converter.asSymbolInformation($exports.callbacks.resolveWorkspaceSymbol(provider, Converter.fromSymbolInformation(symbol), new CancellationTokenResource(token)));
+					}
+				}));
+			},
+			registerDefinitionProvider: (provider: number, selector: Types.DocumentSelector) => {
+				providerRegistry.register(provider, vscode.languages.registerDefinitionProvider(Converter.asDocumentSelector(selector), {
+					provideDefinition: (document, position, token) => {
+						const result = $exports.callbacks.provideDefinition(provider, TextDocumentResource.$resources.getOrCreate(document), Converter.fromPosition(position), new CancellationTokenResource(token));
+						return result !== undefined ? Converter.asDefinition(result) : undefined;
+					}
+				}));
+			},
+			registerDeclarationProvider: (provider: number, selector: Types.DocumentSelector) => {
+				providerRegistry.register(provider, vscode.languages.registerDeclarationProvider(Converter.asDocumentSelector(selector), {
+					provideDeclaration: (document, position, token) => {
+						const result = $exports.callbacks.provideDeclaration(provider, TextDocumentResource.$resources.getOrCreate(document), Converter.fromPosition(position), new CancellationTokenResource(token));
+						return result !== undefined ? Converter.asDefinition(result) : undefined;
+					}
+				}));
+			},
+			registerTypeDefinitionProvider: (provider: number, selector: Types.DocumentSelector) => {
+				providerRegistry.register(provider, vscode.languages.registerTypeDefinitionProvider(Converter.asDocumentSelector(selector), {
+					provideTypeDefinition: (document, position, token) => {
+						const result = $exports.callbacks.provideTypeDefinition(provider, TextDocumentResource.$resources.getOrCreate(document), Converter.fromPosition(position), new CancellationTokenResource(token));
+						return result !== undefined ? Converter.asDefinition(result) : undefined;
+					}
+				}));
+			},
+			registerImplementationProvider: (provider: number, selector: Types.DocumentSelector) => {
+				providerRegistry.register(provider, vscode.languages.registerImplementationProvider(Converter.asDocumentSelector(selector), {
+					provideImplementation: (document, position, token) => {
+						const result = $exports.callbacks.provideImplementation(provider, TextDocumentResource.$resources.getOrCreate(document), Converter.fromPosition(position), new CancellationTokenResource(token));
+						return result !== undefined ? Converter.asDefinition(result) : undefined;
+					}
+				}));
+			},
+			registerReferenceProvider: (provider: number, selector: Types.DocumentSelector) => {
+				providerRegistry.register(provider, vscode.languages.registerReferenceProvider(Converter.asDocumentSelector(selector), {
+					provideReferences: (document, position, context, token) => {
+						const result = $exports.callbacks.provideReferences(provider, TextDocumentResource.$resources.getOrCreate(document), Converter.fromPosition(position), { includeDeclaration: context.includeDeclaration }, new CancellationTokenResource(token));
+						return result !== undefined ? result.map(Converter.asLocation) : undefined;
+					}
+				}));
+			},
+			registerDocumentHighlightProvider: (provider: number, selector: Types.DocumentSelector) => {
+				providerRegistry.register(provider, vscode.languages.registerDocumentHighlightProvider(Converter.asDocumentSelector(selector), {
+					provideDocumentHighlights: (document, position, token) => {
+						const result = $exports.callbacks.provideDocumentHighlights(provider, TextDocumentResource.$resources.getOrCreate(document), Converter.fromPosition(position), new CancellationTokenResource(token));
+						return result !== undefined ? result.map(Converter.asDocumentHighlight) : undefined;
+					}
+				}));
+			},
+			registerRenameProvider: (provider: number, selector: Types.DocumentSelector) => {
+				providerRegistry.register(provider, vscode.languages.registerRenameProvider(Converter.asDocumentSelector(selector), {
+					prepareRename: (document, position, token) => {
+						const result = $exports.callbacks.prepareRename(provider, TextDocumentResource.$resources.getOrCreate(document), Converter.fromPosition(position), new CancellationTokenResource(token));
+						if (result === undefined) {
+							return undefined;
+						}
+						switch (result.tag) {
+							case Types.PrepareRenameResult.accepted: {
+								const range = Converter.asRange(result.value.range);
+								return result.value.placeholder !== undefined ? { range, placeholder: result.value.placeholder } : range;
+							}
+							case Types.PrepareRenameResult.rejected:
+								// VS Code shows the message of the thrown error to the user.
+								throw new Error(result.value);
+						}
+					},
+					provideRenameEdits: (document, position, newName, token) => {
+						const result = $exports.callbacks.provideRenameEdits(provider, TextDocumentResource.$resources.getOrCreate(document), Converter.fromPosition(position), newName, new CancellationTokenResource(token));
+						return result !== undefined ? Converter.asWorkspaceEdit(result) : undefined;
+					}
+				}));
+			},
+			registerFoldingRangeProvider: (provider: number, selector: Types.DocumentSelector) => {
+				providerRegistry.register(provider, vscode.languages.registerFoldingRangeProvider(Converter.asDocumentSelector(selector), {
+					onDidChangeFoldingRanges: providerRegistry.createOnDidChange(provider),
+					provideFoldingRanges: (document, _context, token) => {
+						const result = $exports.callbacks.provideFoldingRanges(provider, TextDocumentResource.$resources.getOrCreate(document), new CancellationTokenResource(token));
+						return result !== undefined ? result.map(Converter.asFoldingRange) : undefined;
+					}
+				}));
+			},
+			registerSelectionRangeProvider: (provider: number, selector: Types.DocumentSelector) => {
+				providerRegistry.register(provider, vscode.languages.registerSelectionRangeProvider(Converter.asDocumentSelector(selector), {
+					provideSelectionRanges: (document, positions, token) => {
+						const result = $exports.callbacks.provideSelectionRanges(provider, TextDocumentResource.$resources.getOrCreate(document), positions.map(Converter.fromPosition), new CancellationTokenResource(token));
+						return result !== undefined ? result.map(Converter.asSelectionRange) : undefined;
+					}
+				}));
+			},
+			registerInlayHintsProvider: (provider: number, selector: Types.DocumentSelector) => {
+				const hints = new GuestItems<vscode.InlayHint, Types.InlayHint>(Converter.asInlayHint);
+				providerRegistry.register(provider, vscode.languages.registerInlayHintsProvider(Converter.asDocumentSelector(selector), {
+					onDidChangeInlayHints: providerRegistry.createOnDidChange(provider),
+					provideInlayHints: (document, range, token) => {
+						return hints.from($exports.callbacks.provideInlayHints(provider, TextDocumentResource.$resources.getOrCreate(document), Converter.fromRange(range), new CancellationTokenResource(token)));
+					},
+					resolveInlayHint: (hint, token) => {
+						const value = hints.get(hint);
+						if (value === undefined) {
+							return hint;
+						}
+						return Converter.asInlayHint($exports.callbacks.resolveInlayHint(provider, value, new CancellationTokenResource(token)));
+					}
+				}));
+			},
+			registerCodeLensProvider: (provider: number, selector: Types.DocumentSelector) => {
+				const lenses = new GuestItems<vscode.CodeLens, Types.CodeLens>(Converter.asCodeLens);
+				providerRegistry.register(provider, vscode.languages.registerCodeLensProvider(Converter.asDocumentSelector(selector), {
+					onDidChangeCodeLenses: providerRegistry.createOnDidChange(provider),
+					provideCodeLenses: (document, token) => {
+						return lenses.from($exports.callbacks.provideCodeLenses(provider, TextDocumentResource.$resources.getOrCreate(document), new CancellationTokenResource(token)));
+					},
+					resolveCodeLens: (codeLens, token) => {
+						const value = lenses.get(codeLens);
+						if (value === undefined) {
+							return codeLens;
+						}
+						const resolved = $exports.callbacks.resolveCodeLens(provider, value, new CancellationTokenResource(token));
+						codeLens.command = resolved.command !== undefined ? Converter.asCommand(resolved.command) : undefined;
+						return codeLens;
+					}
+				}));
+			},
+			registerSignatureHelpProvider: (provider: number, selector: Types.DocumentSelector, metadata: Types.SignatureHelpProviderMetadata) => {
+				providerRegistry.register(provider, vscode.languages.registerSignatureHelpProvider(Converter.asDocumentSelector(selector), {
+					provideSignatureHelp: (document, position, token, context) => {
+						const result = $exports.callbacks.provideSignatureHelp(provider, TextDocumentResource.$resources.getOrCreate(document), Converter.fromPosition(position), Converter.fromSignatureHelpContext(context), new CancellationTokenResource(token));
+						return result !== undefined ? Converter.asSignatureHelp(result) : undefined;
+					}
+				}, Converter.asSignatureHelpProviderMetadata(metadata)));
+			},
+			registerCallHierarchyProvider: (provider: number, selector: Types.DocumentSelector) => {
+				// The guest keeps the item data. We only remember the handle of each item
+				// and release it once VS Code no longer references the item.
+				const handles: WeakMap<vscode.CallHierarchyItem, number> = new WeakMap();
+				const released = new FinalizationRegistry<number>(handle => $exports.callbacks.releaseHierarchyItem(provider, handle));
+				const asItem = (value: Types.HierarchyItem): vscode.CallHierarchyItem => {
+					const item = Converter.asCallHierarchyItem(value);
+					handles.set(item, value.handle);
+					released.register(item, value.handle);
+					return item;
+				};
+				providerRegistry.register(provider, vscode.languages.registerCallHierarchyProvider(Converter.asDocumentSelector(selector), {
+					prepareCallHierarchy: (document, position, token) => {
+						const result = $exports.callbacks.prepareCallHierarchy(provider, TextDocumentResource.$resources.getOrCreate(document), Converter.fromPosition(position), new CancellationTokenResource(token));
+						return result !== undefined ? result.map(asItem) : undefined;
+					},
+					provideCallHierarchyIncomingCalls: (item, token) => {
+						const handle = handles.get(item);
+						if (handle === undefined) {
+							return undefined;
+						}
+						const result = $exports.callbacks.provideCallHierarchyIncomingCalls(provider, handle, new CancellationTokenResource(token));
+						return result !== undefined ? result.map(call => new vscode.CallHierarchyIncomingCall(asItem(call.from), call.fromRanges.map(Converter.asRange))) : undefined;
+					},
+					provideCallHierarchyOutgoingCalls: (item, token) => {
+						const handle = handles.get(item);
+						if (handle === undefined) {
+							return undefined;
+						}
+						const result = $exports.callbacks.provideCallHierarchyOutgoingCalls(provider, handle, new CancellationTokenResource(token));
+						return result !== undefined ? result.map(call => new vscode.CallHierarchyOutgoingCall(asItem(call.to), call.fromRanges.map(Converter.asRange))) : undefined;
+					}
+				}));
+			},
+			registerTypeHierarchyProvider: (provider: number, selector: Types.DocumentSelector) => {
+				// The guest keeps the item data. We only remember the handle of each item
+				// and release it once VS Code no longer references the item.
+				const handles: WeakMap<vscode.TypeHierarchyItem, number> = new WeakMap();
+				const released = new FinalizationRegistry<number>(handle => $exports.callbacks.releaseHierarchyItem(provider, handle));
+				const asItem = (value: Types.HierarchyItem): vscode.TypeHierarchyItem => {
+					const item = Converter.asTypeHierarchyItem(value);
+					handles.set(item, value.handle);
+					released.register(item, value.handle);
+					return item;
+				};
+				providerRegistry.register(provider, vscode.languages.registerTypeHierarchyProvider(Converter.asDocumentSelector(selector), {
+					prepareTypeHierarchy: (document, position, token) => {
+						const result = $exports.callbacks.prepareTypeHierarchy(provider, TextDocumentResource.$resources.getOrCreate(document), Converter.fromPosition(position), new CancellationTokenResource(token));
+						return result !== undefined ? result.map(asItem) : undefined;
+					},
+					provideTypeHierarchySupertypes: (item, token) => {
+						const handle = handles.get(item);
+						if (handle === undefined) {
+							return undefined;
+						}
+						const result = $exports.callbacks.provideTypeHierarchySupertypes(provider, handle, new CancellationTokenResource(token));
+						return result !== undefined ? result.map(asItem) : undefined;
+					},
+					provideTypeHierarchySubtypes: (item, token) => {
+						const handle = handles.get(item);
+						if (handle === undefined) {
+							return undefined;
+						}
+						const result = $exports.callbacks.provideTypeHierarchySubtypes(provider, handle, new CancellationTokenResource(token));
+						return result !== undefined ? result.map(asItem) : undefined;
+					}
+				}));
+			},
+			registerColorProvider: (provider: number, selector: Types.DocumentSelector) => {
+				providerRegistry.register(provider, vscode.languages.registerColorProvider(Converter.asDocumentSelector(selector), {
+					provideDocumentColors: (document, token) => {
+						const result = $exports.callbacks.provideDocumentColors(provider, TextDocumentResource.$resources.getOrCreate(document), new CancellationTokenResource(token));
+						return result !== undefined ? result.map(Converter.asColorInformation) : undefined;
+					},
+					provideColorPresentations: (color, context, token) => {
+						const result = $exports.callbacks.provideColorPresentations(provider, Converter.fromColor(color), TextDocumentResource.$resources.getOrCreate(context.document), Converter.fromRange(context.range), new CancellationTokenResource(token));
+						return result !== undefined ? result.map(Converter.asColorPresentation) : undefined;
+					}
+				}));
+			},
+			registerDocumentLinkProvider: (provider: number, selector: Types.DocumentSelector) => {
+				const links = new GuestItems<vscode.DocumentLink, Types.DocumentLink>(Converter.asDocumentLink);
+				providerRegistry.register(provider, vscode.languages.registerDocumentLinkProvider(Converter.asDocumentSelector(selector), {
+					provideDocumentLinks: (document, token) => {
+						return links.from($exports.callbacks.provideDocumentLinks(provider, TextDocumentResource.$resources.getOrCreate(document), new CancellationTokenResource(token)));
+					},
+					resolveDocumentLink: (link, token) => {
+						const value = links.get(link);
+						if (value === undefined) {
+							return link;
+						}
+						const resolved = Converter.asDocumentLink($exports.callbacks.resolveDocumentLink(provider, value, new CancellationTokenResource(token)));
+						link.target = resolved.target;
+						link.tooltip = resolved.tooltip;
+						return link;
+					}
+				}));
+			},
+			registerLinkedEditingRangeProvider: (provider: number, selector: Types.DocumentSelector) => {
+				providerRegistry.register(provider, vscode.languages.registerLinkedEditingRangeProvider(Converter.asDocumentSelector(selector), {
+					provideLinkedEditingRanges: (document, position, token) => {
+						const result = $exports.callbacks.provideLinkedEditingRanges(provider, TextDocumentResource.$resources.getOrCreate(document), Converter.fromPosition(position), new CancellationTokenResource(token));
+						return result !== undefined ? Converter.asLinkedEditingRanges(result) : undefined;
+					}
+				}));
+			},
+			registerInlineCompletionItemProvider: (provider: number, selector: Types.DocumentSelector) => {
+				providerRegistry.register(provider, vscode.languages.registerInlineCompletionItemProvider(Converter.asDocumentSelector(selector), {
+					provideInlineCompletionItems: (document, position, context, token) => {
+						const result = $exports.callbacks.provideInlineCompletionItems(provider, TextDocumentResource.$resources.getOrCreate(document), Converter.fromPosition(position), Converter.fromInlineCompletionContext(context), new CancellationTokenResource(token));
+						return result !== undefined ? result.map(Converter.asInlineCompletionItem) : undefined;
+					}
+				}));
+			},
+			setLanguageConfiguration: (registration: number, languageId: string, configuration: Types.LanguageConfiguration) => {
+				providerRegistry.register(registration, vscode.languages.setLanguageConfiguration(languageId, Converter.asLanguageConfiguration(configuration)));
+			},
+			getLanguages: () => {
+				return languages;
+			},
+			setTextDocumentLanguage: (operation: number, document: Types.TextDocument, languageId: string) => {
+				// Documents can't be passed as JSON, the guest looks the reopened document up by its uri.
+				completeOperation(operation, vscode.languages.setTextDocumentLanguage(TextDocumentResource.textDocument(document), languageId).then(document => document.uri));
+			},
+			createLanguageStatusItem: (id: string, selector: Types.DocumentSelector) => {
+				return new LanguageStatusItemResource(id, selector);
 			}
 		}
 	};
 	const imports = api.all._.imports.create(service, wasmContext);
-	instance = await RAL().WebAssembly.instantiate(module, imports);
+	const instance = await RAL().WebAssembly.instantiate(module, imports);
 	memory = new Memory.Default(instance.exports);
 	const $exports = api.all._.exports.bind(instance.exports as api.all._.Exports, wasmContext);
-	commandRegistry.initialize($exports.callbacks.executeCommand);
-	const extension = instance.exports as Extension;
-	if (typeof extension.activate === 'function') {
-		extension.activate();
-	}
+	commandRegistry.initialize($exports.callbacks.executeCommand, $exports.callbacks.executeTextEditorCommand);
+	extension = $exports;
+	$exports.activate(Converter.fromExtensionContext(context));
 }
 
 export function deactivate(): void {
-	commandRegistry.dispose();
-	if (instance !== undefined) {
-		const extension = instance.exports as Extension;
-		if (typeof extension.deactivate === 'function') {
-			extension.deactivate();
-		}
+	// The guest disposes its subscriptions on deactivate, which unregisters
+	// them here. Dispose whatever is left afterwards.
+	if (extension !== undefined) {
+		extension.deactivate();
+		extension = undefined;
 	}
+	commandRegistry.dispose();
+	providerRegistry.dispose();
 }
\ No newline at end of file
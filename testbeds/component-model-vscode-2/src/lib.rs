@@ -4,22 +4,27 @@
  *--------------------------------------------------------------------------------------------*/
 use std::rc::Rc;
 
-#[export_name = "activate"]
-pub fn activate() -> vscode::Disposables {
-	let mut disposables: vscode::Disposables = vscode::Disposables::new();
+struct Extension;
 
-	// Create an output channel.
-	let channel: Rc<vscode::OutputChannel> = Rc::new(vscode::window::create_output_channel("Rust Extension", Some("plaintext")));
-	let channel_clone = channel.clone();
-	disposables.push(vscode::commands::register_command("testbed-component-model-vscode.run", move || {
-		channel_clone.append_line("Hello World!");
-		for document in vscode::workspace::text_documents() {
-			channel_clone.append_line(&format!("Document: {} {}", document.uri(), document.handle()));
-		}
-	}));
-	return disposables;
-}
+vscode::extension!(Extension);
 
-#[export_name = "deactivate"]
-pub fn deactivate() {
-}
\ No newline at end of file
+impl vscode::Extension for Extension {
+	fn activate(context: &vscode::ExtensionContext) {
+		// Create an output channel.
+		let channel: Rc<vscode::OutputChannel> = Rc::new(vscode::window::create_output_channel("Rust Extension", Some("plaintext")));
+		let channel_clone = channel.clone();
+		context.push_subscription(vscode::commands::register_command("testbed-component-model-vscode.run", move |()| {
+			channel_clone.append_line("Hello World!");
+			for document in vscode::workspace::text_documents() {
+				channel_clone.append_line(&format!("Document: {} {}", document.uri(), document.handle()));
+			}
+			let channel = channel_clone.clone();
+			vscode::spawn_local(async move {
+				if let Ok(Some(item)) = vscode::window::show_quick_pick(&["One", "Two", "Three"]).await {
+					channel.append_line(&format!("Picked: {}", item));
+				}
+			});
+			Ok(())
+		}));
+	}
+}
@@ -4120,8 +4120,9 @@ function CallableEmitter<C extends Callable, P extends Interface | ResourceType
 			} else {
 				imports.addBaseType('ptr');
 				const params: string[] = [];
+				const context = TypeScript.TypePrinterContext.create(TypeUsage.wasmFunction);
 				for (const param of this.callable.params) {
-					params.push(this.context.printers.metaModel.printTypeReference(param.type, TypeUsage.parameter));
+					params.push(this.context.printers.typeScript.printTypeReference(param.type, context));
 				}
 				return `(args: ptr<[${params.join(', ')}]>) => ${returnType}`;
 			}